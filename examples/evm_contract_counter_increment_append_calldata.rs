@@ -34,7 +34,7 @@ async fn main() -> io::Result<()> {
     );
 
     let k = key::secp256k1::private_key::Key::from_hex(private_key).unwrap();
-    let key_info = k.to_info(1).unwrap();
+    let key_info = k.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info);
     let signer: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
 
@@ -77,7 +77,7 @@ async fn main() -> io::Result<()> {
     // as if forwarder appends the original EIP712 signer
     // this does not work because the msg.sender is not a trusted forwarder
     let no_gas_key = key::secp256k1::private_key::Key::generate().unwrap();
-    let no_gas_key_info = no_gas_key.to_info(1).unwrap();
+    let no_gas_key_info = no_gas_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", no_gas_key_info);
     let encoded = abi_encode(&[Token::Bytes(
         no_gas_key_info.h160_address.to_fixed_bytes().to_vec(),
@@ -7,13 +7,11 @@ use avalanche_types::{
     jsonrpc::client::evm as json_client_evm,
     key, wallet,
 };
-use ethers::prelude::Eip1559TransactionRequest;
 use ethers_core::{
-    abi::{Function, Param, ParamType, StateMutability, Token},
-    types::transaction::eip2718::TypedTransaction,
+    abi::{Function, StateMutability},
     types::{H160, U256},
 };
-use ethers_providers::{Http, Middleware, Provider};
+use ethers_providers::{Http, Provider};
 
 /// Sends a request to the forwarder.
 ///
@@ -57,25 +55,10 @@ async fn main() -> io::Result<()> {
     );
 
     let no_gas_key = key::secp256k1::private_key::Key::generate().unwrap();
-    let no_gas_key_info = no_gas_key.to_info(1).unwrap();
+    let no_gas_key_info = no_gas_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", no_gas_key_info);
     let no_gas_signer: ethers_signers::LocalWallet = no_gas_key.to_ethers_core_signing_key().into();
 
-    let tx = Eip1559TransactionRequest::new()
-        .chain_id(chain_id.as_u64())
-        .to(ethers::prelude::H160::from(
-            forwarder_contract_addr.as_fixed_bytes(),
-        ))
-        .data(get_nonce_calldata(no_gas_key_info.h160_address));
-    let tx: TypedTransaction = tx.into();
-    let output = chain_rpc_provider.call(&tx, None).await.unwrap();
-    let forwarder_nonce_no_gas_key = U256::from_big_endian(&output);
-    log::info!(
-        "forwarder_nonce_no_gas_key: {} {}",
-        no_gas_key_info.h160_address,
-        forwarder_nonce_no_gas_key
-    );
-
     // parsed function of "increment()"
     let func = Function {
         name: "increment".to_string(),
@@ -91,7 +74,7 @@ async fn main() -> io::Result<()> {
         hex::encode(no_gas_recipient_contract_calldata.clone())
     );
 
-    let rr_tx = Tx::new()
+    let mut rr_tx = Tx::new()
         //
         // make sure this matches with "registerDomainSeparator" call
         .domain_name(&domain_name)
@@ -110,16 +93,9 @@ async fn main() -> io::Result<()> {
         // contract address that this gasless transaction will interact with
         .to(recipient_contract_addr)
         //
-        // fails if zero (e.g., "out of gas")
-        // TODO: better estimate gas based on "RelayHub"
-        .gas(U256::from(30000))
-        //
         // contract call needs no value
         .value(U256::zero())
         //
-        // initial nonce is zero
-        .nonce(forwarder_nonce_no_gas_key)
-        //
         .data(no_gas_recipient_contract_calldata)
         //
         .valid_until_time(U256::MAX)
@@ -128,6 +104,19 @@ async fn main() -> io::Result<()> {
         //
         .type_suffix_data(&type_suffix_data);
 
+    // must be re-fetched for every request
+    rr_tx
+        .fetch_nonce(
+            &chain_rpc_provider,
+            forwarder_contract_addr,
+            no_gas_key_info.h160_address,
+        )
+        .await?;
+
+    // fails if zero (e.g., "out of gas")
+    let gas = rr_tx.estimate_gas(&chain_rpc_provider).await?;
+    let rr_tx = rr_tx.gas(gas);
+
     let no_gas_sig = rr_tx.sign(no_gas_signer.clone()).await.unwrap();
     log::info!("gas payer sig: 0x{}", hex::encode(no_gas_sig.clone()));
 
@@ -139,12 +128,12 @@ async fn main() -> io::Result<()> {
     );
 
     let gas_payer_key = key::secp256k1::private_key::Key::from_hex(private_key).unwrap();
-    let gas_payer_key_info = gas_payer_key.to_info(1).unwrap();
+    let gas_payer_key_info = gas_payer_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", gas_payer_key_info);
     let gas_payer_signer: ethers_signers::LocalWallet =
         gas_payer_key.to_ethers_core_signing_key().into();
     let w = wallet::Builder::new(&gas_payer_key)
-        .base_http_url(chain_rpc_url.clone())
+        .provider(chain_rpc_provider.clone())
         .build()
         .await?;
     let gas_payer_evm_wallet = w.evm(
@@ -158,6 +147,7 @@ async fn main() -> io::Result<()> {
         .recipient(forwarder_contract_addr)
         .data(gas_payer_calldata)
         .urgent()
+        .simulate(true)
         .check_acceptance(true)
         .submit()
         .await?;
@@ -165,24 +155,3 @@ async fn main() -> io::Result<()> {
 
     Ok(())
 }
-
-fn get_nonce_calldata(addr: H160) -> Vec<u8> {
-    // parsed function of "getNonce(address from)"
-    let func = Function {
-        name: "getNonce".to_string(),
-        inputs: vec![Param {
-            name: "from".to_string(),
-            kind: ParamType::Address,
-            internal_type: None,
-        }],
-        outputs: vec![Param {
-            name: "nonce".to_string(),
-            kind: ParamType::Uint(256),
-            internal_type: None,
-        }],
-        constant: None,
-        state_mutability: StateMutability::NonPayable,
-    };
-    let arg_tokens = vec![Token::Address(addr)];
-    abi::encode_calldata(func, &arg_tokens).unwrap()
-}
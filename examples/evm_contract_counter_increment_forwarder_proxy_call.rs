@@ -38,7 +38,7 @@ async fn main() -> io::Result<()> {
     );
 
     let k = key::secp256k1::private_key::Key::from_hex(private_key).unwrap();
-    let key_info = k.to_info(1).unwrap();
+    let key_info = k.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info);
     let signer: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
 
@@ -60,7 +60,7 @@ async fn main() -> io::Result<()> {
         "running against {chain_rpc_url}, {chain_id} for forwarder contract {forwarder_contract_addr}, recipient contract {recipient_contract_addr}"
     );
 
-    let no_gas_key_info = no_gas_key.to_info(1).unwrap();
+    let no_gas_key_info = no_gas_key.to_info(1u32).unwrap();
     log::info!("loaded hot key:\n\n{}\n", no_gas_key_info);
     let no_gas_key_signer: ethers_signers::LocalWallet =
         no_gas_key.to_ethers_core_signing_key().into();
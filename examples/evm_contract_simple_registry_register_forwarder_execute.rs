@@ -11,6 +11,7 @@ use ethers_core::{
     abi::{Function, Param, ParamType, StateMutability, Token},
     types::{H160, U256},
 };
+use ethers_providers::{Http, Provider};
 
 /// Sends a request to the forwarder.
 ///
@@ -30,6 +31,10 @@ async fn main() -> io::Result<()> {
     );
 
     let chain_rpc_url = args().nth(1).expect("no chain RPC URL given");
+    let chain_rpc_provider = Provider::<Http>::try_from(chain_rpc_url.clone())
+        .expect("could not instantiate HTTP Provider");
+    log::info!("created chain rpc server provider for {chain_rpc_url}");
+
     let private_key = args().nth(2).expect("no private key given");
 
     let forwarder_contract_addr = args().nth(3).expect("no forwarder contract address given");
@@ -46,7 +51,7 @@ async fn main() -> io::Result<()> {
     );
 
     let no_gas_key = key::secp256k1::private_key::Key::generate().unwrap();
-    let no_gas_key_info = no_gas_key.to_info(1).unwrap();
+    let no_gas_key_info = no_gas_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", no_gas_key_info);
     let no_gas_signer: ethers_signers::LocalWallet = no_gas_key.to_ethers_core_signing_key().into();
 
@@ -71,7 +76,7 @@ async fn main() -> io::Result<()> {
         hex::encode(no_gas_recipient_contract_calldata.clone())
     );
 
-    let rr_tx = Tx::new()
+    let mut rr_tx = Tx::new()
         //
         // make sure this matches with "registerDomainSeparator" call
         .domain_name("my name")
@@ -90,16 +95,9 @@ async fn main() -> io::Result<()> {
         // contract address that this gasless transaction will interact with
         .to(recipient_contract_addr)
         //
-        // fails if zero (e.g., "out of gas")
-        // TODO: better estimate gas based on "RelayHub"
-        .gas(U256::from(90000))
-        //
         // contract call needs no value
         .value(U256::zero())
         //
-        // initial nonce is zero
-        .nonce(U256::from(0))
-        //
         .data(no_gas_recipient_contract_calldata)
         //
         .valid_until_time(U256::MAX)
@@ -108,6 +106,19 @@ async fn main() -> io::Result<()> {
         //
         .type_suffix_data("my suffix");
 
+    // must be re-fetched for every request
+    rr_tx
+        .fetch_nonce(
+            &chain_rpc_provider,
+            forwarder_contract_addr,
+            no_gas_key_info.h160_address,
+        )
+        .await?;
+
+    // fails if zero (e.g., "out of gas")
+    let gas = rr_tx.estimate_gas(&chain_rpc_provider).await?;
+    let rr_tx = rr_tx.gas(gas);
+
     let no_gas_sig = rr_tx.sign(no_gas_signer.clone()).await.unwrap();
     log::info!("gas payer sig: 0x{}", hex::encode(no_gas_sig.clone()));
 
@@ -118,12 +129,12 @@ async fn main() -> io::Result<()> {
     );
 
     let gas_payer_key = key::secp256k1::private_key::Key::from_hex(private_key).unwrap();
-    let gas_payer_key_info = gas_payer_key.to_info(1).unwrap();
+    let gas_payer_key_info = gas_payer_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", gas_payer_key_info);
     let gas_payer_signer: ethers_signers::LocalWallet =
         gas_payer_key.to_ethers_core_signing_key().into();
     let w = wallet::Builder::new(&gas_payer_key)
-        .base_http_url(chain_rpc_url.clone())
+        .provider(chain_rpc_provider.clone())
         .build()
         .await?;
     let gas_payer_evm_wallet = w.evm(
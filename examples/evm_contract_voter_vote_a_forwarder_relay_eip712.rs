@@ -54,7 +54,7 @@ async fn main() -> io::Result<()> {
     );
 
     let no_gas_key = key::secp256k1::private_key::Key::generate().unwrap();
-    let no_gas_key_info = no_gas_key.to_info(1).unwrap();
+    let no_gas_key_info = no_gas_key.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", no_gas_key_info);
     let no_gas_key_signer: ethers_signers::LocalWallet =
         no_gas_key.to_ethers_core_signing_key().into();
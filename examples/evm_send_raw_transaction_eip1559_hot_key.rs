@@ -24,12 +24,12 @@ async fn main() -> io::Result<()> {
     let value = primitive_types::U256::from(random_manager::u64() % 100000);
 
     let k1 = key::secp256k1::TEST_KEYS[0].clone();
-    let key_info1 = k1.to_info(1).unwrap();
+    let key_info1 = k1.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info1);
     let k1_signer: ethers_signers::LocalWallet = k1.to_ethers_core_signing_key().into();
 
     let k2 = key::secp256k1::private_key::Key::generate().unwrap();
-    let key_info2 = k2.to_info(1).unwrap();
+    let key_info2 = k2.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info2);
 
     let tx = eip1559::Transaction::new()
@@ -37,13 +37,13 @@ async fn main() -> io::Result<()> {
             .await
             .unwrap();
 
-    let key_info1 = k1.to_info(1).unwrap();
+    let key_info1 = k1.to_info(1u32).unwrap();
     log::info!("loaded CMK\n\n{}\n(network Id 1)\n", key_info1);
 
     let k1_signer = KmsAwsSigner::new(k1, U256::from(chain_id)).unwrap();
 
     let k2 = key::secp256k1::private_key::Key::generate().unwrap();
-    let key_info2 = k2.to_info(1).unwrap();
+    let key_info2 = k2.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info2);
 
     let tx = eip1559::Transaction::new()
@@ -0,0 +1,55 @@
+use std::{env::args, time::Instant};
+
+use avalanche_types::{formatting, ids::short};
+
+/// cargo run --release --example formatting_cb58_decode 1000000
+///
+/// Compares "decode_cb58_with_checksum_into" (buffer reuse) against
+/// "decode_cb58_with_checksum" (fresh allocation). No reference numbers
+/// have been measured yet, so compare the two timings on your machine.
+fn main() {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let count = args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("1000000"))
+        .parse::<usize>()
+        .expect("invalid count");
+
+    let mut encoded = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut d = [0u8; short::LEN];
+        d[..8].copy_from_slice(&(i as u64).to_be_bytes());
+        encoded.push(formatting::encode_cb58_with_checksum_string(&d));
+    }
+
+    let start = Instant::now();
+    let mut total = 0;
+    for s in encoded.iter() {
+        let decoded = formatting::decode_cb58_with_checksum(s).unwrap();
+        total += decoded.len();
+    }
+    log::info!(
+        "decoded {} short ids with fresh allocation in {:?}",
+        count,
+        start.elapsed()
+    );
+    assert_eq!(total, count * short::LEN);
+
+    let start = Instant::now();
+    let mut total = 0;
+    let mut buf = Vec::new();
+    for s in encoded.iter() {
+        formatting::decode_cb58_with_checksum_into(s, &mut buf).unwrap();
+        total += buf.len();
+    }
+    log::info!(
+        "decoded {} short ids with buffer reuse in {:?}",
+        count,
+        start.elapsed()
+    );
+    assert_eq!(total, count * short::LEN);
+}
@@ -0,0 +1,100 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    env::args,
+    io::{self, BufWriter, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use avalanche_types::{formatting, ids::short};
+
+/// Tracks the current and the peak heap usage.
+struct CountingAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Resets the peak to the current usage, and returns the current usage.
+fn reset_peak() -> usize {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+/// cargo run --release --example formatting_cb58_encode_writer 100000
+///
+/// Compares the peak allocation of "encode_cb58_with_checksum_to_writer"
+/// against joining the encoded strings. No reference numbers have been
+/// measured yet, so compare the two on your machine.
+fn main() {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let count = args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("100000"))
+        .parse::<usize>()
+        .expect("invalid count");
+
+    let mut ids = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut d = [0u8; short::LEN];
+        d[..8].copy_from_slice(&(i as u64).to_be_bytes());
+        ids.push(d);
+    }
+
+    // writes to the sink, to only measure the encoding side
+    let base = reset_peak();
+    let start = Instant::now();
+    let mut joined = String::new();
+    for d in ids.iter() {
+        joined.push_str(&formatting::encode_cb58_with_checksum_string(d));
+        joined.push('\n');
+    }
+    let mut w = BufWriter::new(io::sink());
+    w.write_all(joined.as_bytes()).unwrap();
+    w.flush().unwrap();
+    drop(joined);
+    log::info!(
+        "encoded {} short ids to a joined string in {:?} (peak allocation {} bytes)",
+        count,
+        start.elapsed(),
+        PEAK.load(Ordering::Relaxed) - base
+    );
+
+    let base = reset_peak();
+    let start = Instant::now();
+    let mut w = BufWriter::new(io::sink());
+    for d in ids.iter() {
+        formatting::encode_cb58_with_checksum_to_writer(d, &mut w).unwrap();
+        w.write_all(b"\n").unwrap();
+    }
+    w.flush().unwrap();
+    log::info!(
+        "encoded {} short ids to a buffered writer in {:?} (peak allocation {} bytes)",
+        count,
+        start.elapsed(),
+        PEAK.load(Ordering::Relaxed) - base
+    );
+}
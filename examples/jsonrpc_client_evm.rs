@@ -26,6 +26,7 @@ fn main() {
         .block_on(evm::get_balance(
             format!("{http_rpc}/ext/bc/C/rpc").as_str(),
             primitive_types::H160::from_str(caddr.trim_start_matches("0x")).unwrap(),
+            None,
         ))
         .expect("failed to get balance");
     log::info!("balance: {:?}", balance);
@@ -20,10 +20,10 @@ fn main() {
         .expect("failed get_network_name");
     log::info!("get_network_name response: {:?}", resp);
 
-    let resp = rt
+    let network_id = rt
         .block_on(info::get_network_id(&url))
         .expect("failed get_network_id");
-    log::info!("get_network_id response: {:?}", resp);
+    log::info!("network_id: {}", network_id);
 
     let resp = rt
         .block_on(info::get_blockchain_id(&url, "X"))
@@ -52,10 +52,10 @@ fn main() {
         resp.result.unwrap().blockchain_id
     );
 
-    let resp = rt
+    let (node_id, node_pop) = rt
         .block_on(info::get_node_id(&url))
         .expect("failed get_node_id");
-    log::info!("get_node_id response: {:?}", resp);
+    log::info!("node_id: {}, node_pop: {:?}", node_id, node_pop);
 
     let resp = rt
         .block_on(info::get_node_version(&url))
@@ -0,0 +1,35 @@
+use std::{env::args, time::Instant};
+
+/// cargo run --release --example key_secp256k1_generate_n 100000
+/// cargo run --release --example key_secp256k1_generate_n --features="parallel" 100000
+fn main() {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let count = args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("100000"))
+        .parse::<usize>()
+        .expect("invalid count");
+
+    let start = Instant::now();
+    for _ in 0..count {
+        avalanche_types::key::secp256k1::private_key::Key::generate().unwrap();
+    }
+    let sequential = start.elapsed();
+    log::info!("generated {} keys one by one in {:?}", count, sequential);
+
+    let start = Instant::now();
+    let keys = avalanche_types::key::secp256k1::private_key::generate_n(count).unwrap();
+    let elapsed = start.elapsed();
+    log::info!(
+        "generated {} keys with generate_n in {:?} (parallel {}, {} cores, speedup {:.2}x)",
+        keys.len(),
+        elapsed,
+        cfg!(feature = "parallel"),
+        std::thread::available_parallelism().map_or(1, |n| n.get()),
+        sequential.as_secs_f64() / elapsed.as_secs_f64()
+    );
+}
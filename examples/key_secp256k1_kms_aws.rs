@@ -31,7 +31,7 @@ fn main() {
     ))
     .unwrap();
 
-    let cmk_info = cmk.to_info(1).unwrap();
+    let cmk_info = cmk.to_info(1u32).unwrap();
     println!("cmk_info:\n{}", cmk_info);
 
     let cmk2 = ab!(key::secp256k1::kms::aws::Cmk::from_arn(
@@ -39,7 +39,7 @@ fn main() {
         &cmk.arn,
     ))
     .unwrap();
-    let cmk_info2 = cmk2.to_info(1).unwrap();
+    let cmk_info2 = cmk2.to_info(1u32).unwrap();
     println!("cmk_info2:\n{}", cmk_info2);
 
     let digest = [0u8; ring::digest::SHA256_OUTPUT_LEN];
@@ -17,9 +17,9 @@ fn main() {
         avalanche_types::key::secp256k1::private_key::Key::from_mnemonic_phrase(phrase, deriv_path)
             .unwrap();
 
-    let entry = key.to_info(1).expect("failed to_info");
+    let entry = key.to_info(1u32).expect("failed to_info");
     log::info!("network ID 1:\n{}", entry);
 
-    let entry = key.to_info(9999).expect("failed to_info");
+    let entry = key.to_info(9999u32).expect("failed to_info");
     log::info!("network ID 9999:\n{}", entry);
 }
@@ -0,0 +1,47 @@
+use std::{env::args, time::Instant};
+
+use avalanche_types::key::secp256k1::private_key;
+
+/// cargo run --release --example key_secp256k1_public_key_bytes 10000
+///
+/// Compares "public_key_compressed_bytes" against
+/// "to_public_key().to_compressed_bytes()". No reference numbers have been
+/// measured yet, so compare the two timings on your machine.
+fn main() {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let count = args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("10000"))
+        .parse::<usize>()
+        .expect("invalid count");
+
+    let keys = private_key::generate_n(count).unwrap();
+
+    let start = Instant::now();
+    let mut via_public_key = Vec::with_capacity(count);
+    for k in keys.iter() {
+        via_public_key.push(k.to_public_key().to_compressed_bytes());
+    }
+    log::info!(
+        "derived {} compressed public keys via to_public_key in {:?}",
+        count,
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut direct = Vec::with_capacity(count);
+    for k in keys.iter() {
+        direct.push(k.public_key_compressed_bytes());
+    }
+    log::info!(
+        "derived {} compressed public keys via public_key_compressed_bytes in {:?}",
+        count,
+        start.elapsed()
+    );
+
+    assert_eq!(via_public_key, direct);
+}
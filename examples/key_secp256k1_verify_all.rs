@@ -0,0 +1,48 @@
+use std::{env::args, time::Instant};
+
+use avalanche_types::key::secp256k1::{private_key, signature};
+
+/// cargo run --release --example key_secp256k1_verify_all 1000
+/// cargo run --release --example key_secp256k1_verify_all --features="parallel" 1000
+fn main() {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let count = args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("1000"))
+        .parse::<usize>()
+        .expect("invalid count");
+
+    let keys = private_key::generate_n(count).unwrap();
+    let mut items = Vec::with_capacity(count);
+    for (i, k) in keys.iter().enumerate() {
+        let digest = <[u8; 32]>::try_from(
+            avalanche_types::hash::sha256(format!("message {}", i)).as_slice(),
+        )
+        .unwrap();
+        let sig = k.sign_digest(&digest).unwrap();
+        items.push((k.to_public_key(), digest, sig));
+    }
+
+    let start = Instant::now();
+    for (pubkey, digest, sig) in items.iter() {
+        assert!(pubkey.verify_digest(digest, sig));
+    }
+    let sequential = start.elapsed();
+    log::info!("verified {} items one by one in {:?}", count, sequential);
+
+    let start = Instant::now();
+    assert!(signature::batch_verify_all(&items));
+    let elapsed = start.elapsed();
+    log::info!(
+        "verified {} items with batch_verify_all in {:?} (parallel {}, {} cores, speedup {:.2}x)",
+        count,
+        elapsed,
+        cfg!(feature = "parallel"),
+        std::thread::available_parallelism().map_or(1, |n| n.get()),
+        sequential.as_secs_f64() / elapsed.as_secs_f64()
+    );
+}
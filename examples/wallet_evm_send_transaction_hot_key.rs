@@ -22,13 +22,13 @@ async fn main() -> io::Result<()> {
     log::info!("running against {chain_rpc_url}, {chain_id}");
 
     let k1 = key::secp256k1::private_key::Key::from_hex(private_key).unwrap();
-    let key_info1 = k1.to_info(1).unwrap();
+    let key_info1 = k1.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info1);
 
     let k1_signer: ethers_signers::LocalWallet = k1.to_ethers_core_signing_key().into();
 
     let k2 = key::secp256k1::private_key::Key::generate().unwrap();
-    let key_info2 = k2.to_info(1).unwrap();
+    let key_info2 = k2.to_info(1u32).unwrap();
     log::info!("created hot key:\n\n{}\n", key_info2);
 
     let w = wallet::Builder::new(&k1)
@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+set -xue
+
+if ! [[ "$0" =~ scripts/tests.no_std.sh ]]; then
+  echo "must be run from repository root"
+  exit 255
+fi
+
+# builds the core "key::secp256k1" types on the bare-metal target
+# that has no "std", to catch the "std" use outside the "std" feature
+# rustup target add thumbv7em-none-eabihf
+cargo build --lib --no-default-features --target thumbv7em-none-eabihf
+
+echo "ALL SUCCESS!"
@@ -65,6 +65,31 @@ lazy_static! {
         m.insert("platformvm.UnsignedRewardValidatorTx".to_string(), 20);
         m.insert("platformvm.StakeableLockIn".to_string(), 21);
         m.insert("platformvm.StakeableLockOut".to_string(), 22);
+        // Banff types
+        // ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/codec.go> "RegisterBanffUnsignedTxsTypes"
+        m.insert("platformvm.UnsignedRemoveSubnetValidatorTx".to_string(), 23);
+        m.insert("platformvm.UnsignedTransformSubnetTx".to_string(), 24);
+        m.insert("platformvm.UnsignedAddPermissionlessValidatorTx".to_string(), 25);
+        m.insert("platformvm.UnsignedAddPermissionlessDelegatorTx".to_string(), 26);
+        m.insert("signer.Empty".to_string(), 27);
+        m.insert("signer.ProofOfPossession".to_string(), 28);
+        m
+    };
+
+    /// Atomic transaction types of C-chain (coreth).
+    /// ref. <https://github.com/ava-labs/coreth/blob/v0.11.5/plugin/evm/codec.go>
+    pub static ref C_TYPES: HashMap<String, usize> = {
+        let mut m = HashMap::new();
+        m.insert("evm.UnsignedImportTx".to_string(), 0);
+        m.insert("evm.UnsignedExportTx".to_string(), 1);
+        // skips 3 registrations
+        m.insert("secp256k1fx.TransferInput".to_string(), 5);
+        m.insert("secp256k1fx.MintOutput".to_string(), 6);
+        m.insert("secp256k1fx.TransferOutput".to_string(), 7);
+        m.insert("secp256k1fx.MintOperation".to_string(), 8);
+        m.insert("secp256k1fx.Credential".to_string(), 9);
+        m.insert("secp256k1fx.Input".to_string(), 10);
+        m.insert("secp256k1fx.OutputOwners".to_string(), 11);
         m
     };
 }
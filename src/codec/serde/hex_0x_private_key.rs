@@ -0,0 +1,78 @@
+use crate::key::secp256k1::private_key::Key;
+use serde::{self, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use zeroize::Zeroizing;
+
+/// Serializes the private key to the hex-encoded string with "0x" prefix.
+pub fn serialize<S>(x: &Key, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let s = Zeroizing::new(x.to_hex());
+    serializer.serialize_str(&s)
+}
+
+/// Deserializes the private key from either the hex-encoded
+/// or the CB58-encoded string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Key, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Zeroizing::new(String::deserialize(deserializer)?);
+    Key::from_encoded_str(&s).map_err(serde::de::Error::custom)
+}
+
+pub struct Hex0xPrivateKey(Key);
+
+impl SerializeAs<Key> for Hex0xPrivateKey {
+    fn serialize_as<S>(x: &Key, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(x, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Key> for Hex0xPrivateKey {
+    fn deserialize_as<D>(deserializer: D) -> Result<Key, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="private_key_serde" -- codec::serde::hex_0x_private_key::test_custom_de_serializer --exact --show-output
+#[test]
+fn test_custom_de_serializer() {
+    use serde::Serialize;
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+    struct Data {
+        #[serde_as(as = "Vec<Hex0xPrivateKey>")]
+        data: Vec<Key>,
+    }
+
+    let d = Data {
+        data: vec![Key::generate().unwrap(), Key::generate().unwrap()],
+    };
+
+    let yaml_encoded = serde_yaml::to_string(&d).unwrap();
+    let yaml_decoded = serde_yaml::from_str(&yaml_encoded).unwrap();
+    assert_eq!(d, yaml_decoded);
+
+    let json_encoded = serde_json::to_string(&d).unwrap();
+    assert!(json_encoded.contains(&d.data[0].to_hex()));
+    let json_decoded = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(d, json_decoded);
+
+    let json_decoded_2: Data = serde_json::from_str(&format!(
+        "{{\"data\":[\"{}\", \"{}\"]}}",
+        d.data[0].to_cb58(),
+        d.data[1].to_hex()
+    ))
+    .unwrap();
+    assert_eq!(d, json_decoded_2);
+}
@@ -11,3 +11,6 @@ pub mod base64_bytes;
 
 #[cfg(feature = "codec_big_int")]
 pub mod hex_0x_big_int;
+
+#[cfg(feature = "private_key_serde")]
+pub mod hex_0x_private_key;
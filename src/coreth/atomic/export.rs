@@ -0,0 +1,215 @@
+use std::io;
+
+use crate::{codec, coreth::atomic, hash, ids, key, packer, txs};
+use serde::{Deserialize, Serialize};
+
+/// Exports the AVAX from the C-chain accounts to X/P-chain
+/// as the atomic UTXOs, to be imported on the destination chain.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedExportTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// Updated after signing.
+    #[serde(skip)]
+    pub metadata: Option<txs::Metadata>,
+
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    /// C-chain blockchain Id.
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "destinationChain")]
+    pub destination_chain_id: ids::Id,
+
+    /// Must be sorted by the address and asset Id, and each input
+    /// is signed by the key of its address.
+    pub inputs: Vec<atomic::EvmInput>,
+    /// Must be sorted.
+    #[serde(rename = "exportedOutputs")]
+    pub exported_outputs: Vec<txs::transferable::Output>,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            metadata: None,
+            network_id: 0,
+            blockchain_id: ids::Id::empty(),
+            destination_chain_id: ids::Id::empty(),
+            inputs: Vec::new(),
+            exported_outputs: Vec::new(),
+            creds: Vec::new(),
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        match &self.metadata {
+            Some(m) => m.id,
+            None => ids::Id::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "evm.UnsignedExportTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::C_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Returns the gas of the signed transaction: the signed bytes,
+    /// one signature per input, and the fixed base cost.
+    /// Zero if not signed yet.
+    /// ref. "coreth/plugin/evm.UnsignedExportTx.GasUsed"
+    pub fn gas_used(&self) -> u64 {
+        match &self.metadata {
+            Some(m) => atomic::gas_used(m.tx_bytes_with_signatures.len(), self.inputs.len()),
+            None => 0,
+        }
+    }
+
+    /// ref. "coreth/plugin/evm.Tx.Sign"
+    pub async fn sign<T: key::secp256k1::SignOnly>(
+        &mut self,
+        signers: Vec<Vec<T>>,
+    ) -> io::Result<()> {
+        // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+
+        // marshal "unsigned tx" as the "UnsignedAtomicTx" interface
+        // with the codec version and its type ID
+        packer.pack_u16(codec::VERSION)?;
+        packer.pack_u32(Self::type_id())?;
+
+        packer.pack_u32(self.network_id)?;
+        packer.pack_bytes(self.blockchain_id.as_ref())?;
+        packer.pack_bytes(self.destination_chain_id.as_ref())?;
+
+        // "EVMInput" is a struct, so no type ID
+        packer.pack_u32(self.inputs.len() as u32)?;
+        for input in self.inputs.iter() {
+            packer.pack_bytes(input.address.as_bytes())?;
+            packer.pack_u64(input.amount)?;
+            packer.pack_bytes(input.asset_id.as_ref())?;
+            packer.pack_u64(input.nonce)?;
+        }
+
+        packer.pack_u32(self.exported_outputs.len() as u32)?;
+        for transferable_output in self.exported_outputs.iter() {
+            atomic::pack_transferable_output(&packer, transferable_output)?;
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        self.creds = atomic::sign_and_pack_creds(&packer, &tx_bytes_hash, signers).await?;
+
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        self.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="wallet" -- coreth::atomic::export::test_export_tx_serialization --exact --show-output
+#[test]
+fn test_export_tx_serialization() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .unwrap();
+    let asset_id = ids::Id::from_slice(&[0x79; 32]);
+    let eth_addr = test_key.to_public_key().to_h160();
+    let short_addr = test_key.to_public_key().to_short_id().unwrap();
+
+    let mut tx = Tx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[0x01; 32]),
+        destination_chain_id: ids::Id::from_slice(&[0x02; 32]),
+        inputs: vec![atomic::EvmInput {
+            address: eth_addr,
+            amount: 110,
+            asset_id,
+            nonce: 3,
+        }],
+        exported_outputs: vec![txs::transferable::Output {
+            asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 100,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![short_addr.clone()],
+                },
+            }),
+            ..Default::default()
+        }],
+        ..Tx::default()
+    };
+    ab!(tx.sign(vec![vec![test_key.clone()]])).unwrap();
+
+    let metadata = tx.metadata.clone().unwrap();
+    let mut expected_unsigned_bytes: Vec<u8> = vec![
+        0x00, 0x00, // codec version
+        0x00, 0x00, 0x00, 0x01, // evm.UnsignedExportTx type ID
+        0x00, 0x00, 0x00, 0x0a, // network id
+    ];
+    expected_unsigned_bytes.extend_from_slice(&[0x01; 32]); // blockchain id
+    expected_unsigned_bytes.extend_from_slice(&[0x02; 32]); // destination chain
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // inputs
+    expected_unsigned_bytes.extend_from_slice(eth_addr.as_bytes()); // address
+    expected_unsigned_bytes.extend_from_slice(&110_u64.to_be_bytes()); // amount
+    expected_unsigned_bytes.extend_from_slice(&[0x79; 32]); // asset id
+    expected_unsigned_bytes.extend_from_slice(&3_u64.to_be_bytes()); // nonce
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // exported outputs
+    expected_unsigned_bytes.extend_from_slice(&[0x79; 32]); // asset id
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x07]); // secp256k1fx.TransferOutput
+    expected_unsigned_bytes.extend_from_slice(&100_u64.to_be_bytes()); // amount
+    expected_unsigned_bytes.extend_from_slice(&0_u64.to_be_bytes()); // locktime
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // threshold
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // addresses
+    expected_unsigned_bytes.extend_from_slice(short_addr.as_ref()); // address
+    assert_eq!(metadata.tx_bytes_with_no_signature, expected_unsigned_bytes);
+
+    let signed = &metadata.tx_bytes_with_signatures;
+    assert_eq!(signed.len(), expected_unsigned_bytes.len() + 4 + 4 + 4 + 65);
+    assert_eq!(tx.tx_id(), ids::Id::from_slice(&hash::sha256(signed)));
+
+    // coreth checks the signer derives the input address
+    let sig = key::secp256k1::signature::Sig::from_bytes(&tx.creds[0].signatures[0]).unwrap();
+    let digest: [u8; 32] = hash::sha256(&expected_unsigned_bytes).try_into().unwrap();
+    assert!(sig.recovers_to(&digest, eth_addr));
+
+    assert_eq!(tx.gas_used(), signed.len() as u64 + 1000 + 10_000);
+}
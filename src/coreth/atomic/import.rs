@@ -0,0 +1,228 @@
+use std::io;
+
+use crate::{codec, coreth::atomic, hash, ids, key, packer, txs};
+use serde::{Deserialize, Serialize};
+
+/// Imports the atomic UTXOs exported from X/P-chain to the C-chain accounts.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedImportTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// Updated after signing.
+    #[serde(skip)]
+    pub metadata: Option<txs::Metadata>,
+
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    /// C-chain blockchain Id.
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "sourceChain")]
+    pub source_chain_id: ids::Id,
+
+    /// Must be sorted by the UTXO Id.
+    #[serde(rename = "importedInputs")]
+    pub imported_inputs: Vec<txs::transferable::Input>,
+    /// Must be sorted by the address and asset Id.
+    pub outputs: Vec<atomic::EvmOutput>,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            metadata: None,
+            network_id: 0,
+            blockchain_id: ids::Id::empty(),
+            source_chain_id: ids::Id::empty(),
+            imported_inputs: Vec::new(),
+            outputs: Vec::new(),
+            creds: Vec::new(),
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        match &self.metadata {
+            Some(m) => m.id,
+            None => ids::Id::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "evm.UnsignedImportTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::C_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Returns the gas of the signed transaction: the signed bytes,
+    /// the signatures of the imported inputs, and the fixed base cost.
+    /// Zero if not signed yet.
+    /// ref. "coreth/plugin/evm.UnsignedImportTx.GasUsed"
+    pub fn gas_used(&self) -> u64 {
+        let num_sigs = self
+            .imported_inputs
+            .iter()
+            .map(|input| {
+                input
+                    .transfer_input
+                    .as_ref()
+                    .map_or(0, |transfer_input| transfer_input.sig_indices.len())
+            })
+            .sum();
+        match &self.metadata {
+            Some(m) => atomic::gas_used(m.tx_bytes_with_signatures.len(), num_sigs),
+            None => 0,
+        }
+    }
+
+    /// ref. "coreth/plugin/evm.Tx.Sign"
+    pub async fn sign<T: key::secp256k1::SignOnly>(
+        &mut self,
+        signers: Vec<Vec<T>>,
+    ) -> io::Result<()> {
+        // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+
+        // marshal "unsigned tx" as the "UnsignedAtomicTx" interface
+        // with the codec version and its type ID
+        packer.pack_u16(codec::VERSION)?;
+        packer.pack_u32(Self::type_id())?;
+
+        packer.pack_u32(self.network_id)?;
+        packer.pack_bytes(self.blockchain_id.as_ref())?;
+        packer.pack_bytes(self.source_chain_id.as_ref())?;
+
+        packer.pack_u32(self.imported_inputs.len() as u32)?;
+        for transferable_input in self.imported_inputs.iter() {
+            atomic::pack_transferable_input(&packer, transferable_input)?;
+        }
+
+        // "EVMOutput" is a struct, so no type ID
+        packer.pack_u32(self.outputs.len() as u32)?;
+        for output in self.outputs.iter() {
+            packer.pack_bytes(output.address.as_bytes())?;
+            packer.pack_u64(output.amount)?;
+            packer.pack_bytes(output.asset_id.as_ref())?;
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        self.creds = atomic::sign_and_pack_creds(&packer, &tx_bytes_hash, signers).await?;
+
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        self.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="wallet" -- coreth::atomic::import::test_import_tx_serialization --exact --show-output
+#[test]
+fn test_import_tx_serialization() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .unwrap();
+    let asset_id = ids::Id::from_slice(&[0x79; 32]);
+
+    let mut tx = Tx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[0x01; 32]),
+        source_chain_id: ids::Id::from_slice(&[0x02; 32]),
+        imported_inputs: vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0x03; 32]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 100,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }],
+        outputs: vec![atomic::EvmOutput {
+            address: primitive_types::H160::repeat_byte(0x04),
+            amount: 90,
+            asset_id,
+        }],
+        ..Tx::default()
+    };
+    assert_eq!(tx.gas_used(), 0);
+    ab!(tx.sign(vec![vec![test_key.clone()]])).unwrap();
+
+    let metadata = tx.metadata.clone().unwrap();
+    let mut expected_unsigned_bytes: Vec<u8> = vec![
+        0x00, 0x00, // codec version
+        0x00, 0x00, 0x00, 0x00, // evm.UnsignedImportTx type ID
+        0x00, 0x00, 0x00, 0x0a, // network id
+    ];
+    expected_unsigned_bytes.extend_from_slice(&[0x01; 32]); // blockchain id
+    expected_unsigned_bytes.extend_from_slice(&[0x02; 32]); // source chain
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // imported inputs
+    expected_unsigned_bytes.extend_from_slice(&[0x03; 32]); // utxo tx id
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // utxo output index
+    expected_unsigned_bytes.extend_from_slice(&[0x79; 32]); // asset id
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x05]); // secp256k1fx.TransferInput
+    expected_unsigned_bytes.extend_from_slice(&100_u64.to_be_bytes()); // amount
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // sig indices
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // sig index 0
+    expected_unsigned_bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x01]); // outputs
+    expected_unsigned_bytes.extend_from_slice(&[0x04; 20]); // address
+    expected_unsigned_bytes.extend_from_slice(&90_u64.to_be_bytes()); // amount
+    expected_unsigned_bytes.extend_from_slice(&[0x79; 32]); // asset id
+    assert_eq!(metadata.tx_bytes_with_no_signature, expected_unsigned_bytes);
+
+    // credentials follow the unsigned bytes
+    let signed = &metadata.tx_bytes_with_signatures;
+    assert_eq!(signed.len(), expected_unsigned_bytes.len() + 4 + 4 + 4 + 65);
+    assert_eq!(
+        &signed[..expected_unsigned_bytes.len()],
+        &expected_unsigned_bytes[..]
+    );
+    assert_eq!(
+        &signed[expected_unsigned_bytes.len()..expected_unsigned_bytes.len() + 12],
+        &[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x01]
+    );
+    assert_eq!(tx.tx_id(), ids::Id::from_slice(&hash::sha256(signed)));
+
+    let sig = key::secp256k1::signature::Sig::from_bytes(&tx.creds[0].signatures[0]).unwrap();
+    let digest: [u8; 32] = hash::sha256(&expected_unsigned_bytes).try_into().unwrap();
+    assert!(sig.recovers_to(&digest, test_key.to_public_key().to_h160()));
+
+    assert_eq!(tx.gas_used(), signed.len() as u64 + 1000 + 10_000);
+}
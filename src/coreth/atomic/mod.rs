@@ -0,0 +1,229 @@
+//! C-chain atomic transactions that move AVAX between C-chain and X/P-chain
+//! through the shared memory.
+//! ref. <https://github.com/ava-labs/coreth/tree/v0.11.5/plugin/evm>
+pub mod export;
+pub mod import;
+
+use std::{
+    cmp::Ordering,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{ids, key, packer, txs};
+use primitive_types::{H160, U256};
+use serde::{Deserialize, Serialize};
+
+/// Gas per byte of the signed atomic transaction.
+/// ref. "coreth/params.TxBytesGas"
+pub const TX_BYTES_GAS: u64 = 1;
+
+/// Gas per signature.
+/// ref. "avalanchego/vms/secp256k1fx.CostPerSignature"
+pub const COST_PER_SIGNATURE: u64 = 1000;
+
+/// Fixed gas of every atomic transaction since Apricot Phase 5.
+/// ref. "coreth/params.AtomicTxBaseCost"
+pub const ATOMIC_TX_BASE_COST: u64 = 10_000;
+
+/// Conversion rate from nAVAX (9 decimals, X/P-chain and atomic amounts)
+/// to wei (18 decimals, C-chain balances and base fee).
+/// ref. "coreth/plugin/evm.x2cRate"
+pub const X2C_RATE: u64 = 1_000_000_000;
+
+/// Output of the import transaction that credits the C-chain account.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMOutput>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct EvmOutput {
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+}
+
+/// Sorts by the address, and then by the asset Id.
+/// ref. "coreth/plugin/evm.EVMOutput.Compare"
+impl Ord for EvmOutput {
+    fn cmp(&self, other: &EvmOutput) -> Ordering {
+        self.address
+            .cmp(&other.address)
+            .then_with(|| self.asset_id.cmp(&other.asset_id))
+    }
+}
+
+impl PartialOrd for EvmOutput {
+    fn partial_cmp(&self, other: &EvmOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Input of the export transaction that debits the C-chain account.
+/// "nonce" must match the account nonce when the transaction is accepted.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMInput>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct EvmInput {
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    pub nonce: u64,
+}
+
+/// Sorts by the address, and then by the asset Id.
+/// ref. "coreth/plugin/evm.EVMInput.Compare"
+impl Ord for EvmInput {
+    fn cmp(&self, other: &EvmInput) -> Ordering {
+        self.address
+            .cmp(&other.address)
+            .then_with(|| self.asset_id.cmp(&other.asset_id))
+    }
+}
+
+impl PartialOrd for EvmInput {
+    fn partial_cmp(&self, other: &EvmInput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns the gas of the signed atomic transaction of "tx_bytes_len" bytes
+/// with "num_sigs" signatures in total.
+/// ref. "coreth/plugin/evm.UnsignedImportTx.GasUsed"
+/// ref. "coreth/plugin/evm.UnsignedExportTx.GasUsed"
+pub fn gas_used(tx_bytes_len: usize, num_sigs: usize) -> u64 {
+    (tx_bytes_len as u64) * TX_BYTES_GAS
+        + (num_sigs as u64) * COST_PER_SIGNATURE
+        + ATOMIC_TX_BASE_COST
+}
+
+/// Returns the fee in nAVAX for the "gas" at the "base_fee" in wei, rounded up.
+/// ref. "coreth/plugin/evm.CalculateDynamicFee"
+pub fn calculate_dynamic_fee(gas: u64, base_fee: U256) -> io::Result<u64> {
+    let fee = U256::from(gas)
+        .checked_mul(base_fee)
+        .and_then(|fee| fee.checked_add(U256::from(X2C_RATE - 1)))
+        .map(|fee| fee / U256::from(X2C_RATE))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("fee overflows for gas {} at base fee {}", gas, base_fee),
+            )
+        })?;
+    if fee > U256::from(u64::MAX) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("fee {} exceeds u64 for gas {}", fee, gas),
+        ));
+    }
+    Ok(fee.as_u64())
+}
+
+/// Packs the "secp256k1fx.TransferInput" of the transferable input.
+fn pack_transferable_input(
+    packer: &packer::Packer,
+    transferable_input: &txs::transferable::Input,
+) -> io::Result<()> {
+    // "TransferableInput.UTXOID" and "TransferableInput.Asset" are embedded inline
+    // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
+    packer.pack_bytes(transferable_input.utxo_id.tx_id.as_ref())?;
+    packer.pack_u32(transferable_input.utxo_id.output_index)?;
+    packer.pack_bytes(transferable_input.asset_id.as_ref())?;
+
+    // C-chain only accepts "secp256k1fx.TransferInput" (no stakeable lock)
+    let transfer_input = transferable_input.transfer_input.as_ref().ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "unexpected None TransferableInput transfer_input",
+        )
+    })?;
+    packer.pack_u32(key::secp256k1::txs::transfer::Input::type_id())?;
+    packer.pack_u64(transfer_input.amount)?;
+    packer.pack_u32(transfer_input.sig_indices.len() as u32)?;
+    for idx in transfer_input.sig_indices.iter() {
+        packer.pack_u32(*idx)?;
+    }
+    Ok(())
+}
+
+/// Packs the "secp256k1fx.TransferOutput" of the transferable output.
+fn pack_transferable_output(
+    packer: &packer::Packer,
+    transferable_output: &txs::transferable::Output,
+) -> io::Result<()> {
+    // "TransferableOutput.Asset" is embedded inline
+    // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+    packer.pack_bytes(transferable_output.asset_id.as_ref())?;
+
+    // C-chain only accepts "secp256k1fx.TransferOutput" (no stakeable lock)
+    let transfer_output = transferable_output
+        .transfer_output
+        .as_ref()
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "unexpected None TransferableOutput transfer_output",
+            )
+        })?;
+    packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
+    packer.pack_u64(transfer_output.amount)?;
+    packer.pack_u64(transfer_output.output_owners.locktime)?;
+    packer.pack_u32(transfer_output.output_owners.threshold)?;
+    packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
+    for addr in transfer_output.output_owners.addresses.iter() {
+        packer.pack_bytes(addr.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Signs the hash of the unsigned transaction bytes with each group of signers,
+/// and packs the credentials after the unsigned bytes in the packer.
+/// ref. "coreth/plugin/evm.Tx.Sign"
+async fn sign_and_pack_creds<T: key::secp256k1::SignOnly>(
+    packer: &packer::Packer,
+    tx_bytes_hash: &[u8],
+    signers: Vec<Vec<T>>,
+) -> io::Result<Vec<key::secp256k1::txs::Credential>> {
+    let mut creds = Vec::new();
+    for keys in signers.iter() {
+        let mut sigs: Vec<Vec<u8>> = Vec::new();
+        for k in keys.iter() {
+            let sig = k
+                .sign_digest(tx_bytes_hash)
+                .await
+                .map_err(|e| Error::new(ErrorKind::Other, format!("failed sign_digest {}", e)))?;
+            sigs.push(Vec::from(sig));
+        }
+        creds.push(key::secp256k1::txs::Credential::new(sigs));
+    }
+
+    packer.pack_u32(creds.len() as u32)?;
+    let cred_type_id = key::secp256k1::txs::Credential::type_id();
+    for cred in creds.iter() {
+        packer.pack_u32(cred_type_id)?;
+        packer.pack_u32(cred.signatures.len() as u32)?;
+        for sig in cred.signatures.iter() {
+            packer.pack_bytes(sig)?;
+        }
+    }
+    Ok(creds)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="wallet" -- coreth::atomic::test_calculate_dynamic_fee --exact --show-output
+#[test]
+fn test_calculate_dynamic_fee() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // ref. "coreth/plugin/evm.TestCalculateDynamicFee"
+    assert_eq!(
+        calculate_dynamic_fee(1, U256::from(25_000_000_000_u64)).unwrap(),
+        25
+    );
+    // rounds up to 1 nAVAX
+    assert_eq!(calculate_dynamic_fee(100, U256::from(1)).unwrap(), 1);
+    assert_eq!(calculate_dynamic_fee(0, U256::from(1)).unwrap(), 0);
+    assert!(calculate_dynamic_fee(u64::MAX, U256::MAX).is_err());
+
+    // one input with a single signature
+    assert_eq!(gas_used(100, 1), 100 + 1000 + 10_000);
+}
@@ -1,2 +1,5 @@
+pub mod atomic;
+#[cfg(feature = "avalanchego")]
 pub mod chain_config;
+#[cfg(feature = "avalanchego")]
 pub mod genesis;
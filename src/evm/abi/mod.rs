@@ -2,7 +2,10 @@
 
 use std::io::{self, Error, ErrorKind};
 
-use ethers_core::abi::{Function, Token};
+use ethers_core::{
+    abi::{Contract, Event, Function, ParamType, RawLog, Token},
+    types::Log,
+};
 
 /// ref. <https://github.com/foundry-rs/foundry/blob/master/common/src/abi.rs> "encode_args"
 pub fn encode_calldata(func: Function, arg_tokens: &[Token]) -> io::Result<Vec<u8>> {
@@ -11,6 +14,113 @@ pub fn encode_calldata(func: Function, arg_tokens: &[Token]) -> io::Result<Vec<u
         .map_err(|e| Error::new(ErrorKind::Other, format!("failed to encode_input {}", e)))
 }
 
+/// Same as "encode_calldata" but first validates the number of arguments
+/// and each token against the declared input types, since "encode_calldata"
+/// silently produces wrong calldata on mismatch (e.g., for overloaded functions).
+pub fn encode_calldata_checked(func: Function, arg_tokens: &[Token]) -> io::Result<Vec<u8>> {
+    if arg_tokens.len() != func.inputs.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "'{}' expected {} args, got {}",
+                func.name,
+                func.inputs.len(),
+                arg_tokens.len()
+            ),
+        ));
+    }
+    for (i, (param, token)) in func.inputs.iter().zip(arg_tokens.iter()).enumerate() {
+        if !token.type_check(&param.kind) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "arg {} expected {}, got {}",
+                    i,
+                    param.kind,
+                    token_type(token)
+                ),
+            ));
+        }
+        if let (ParamType::Uint(bits), Token::Uint(v)) = (&param.kind, token) {
+            if v.bits() > *bits {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("arg {} value {} overflows {}", i, v, param.kind),
+                ));
+            }
+        }
+    }
+    encode_calldata(func, arg_tokens)
+}
+
+/// Returns the best-effort ABI type name of the token for error messages.
+/// Integer sizes are unknown from the token itself.
+fn token_type(token: &Token) -> String {
+    match token {
+        Token::Address(_) => "address".to_string(),
+        Token::FixedBytes(b) => format!("bytes{}", b.len()),
+        Token::Bytes(_) => "bytes".to_string(),
+        Token::Int(_) => "int".to_string(),
+        Token::Uint(_) => "uint".to_string(),
+        Token::Bool(_) => "bool".to_string(),
+        Token::String(_) => "string".to_string(),
+        Token::FixedArray(v) => match v.first() {
+            Some(t) => format!("{}[{}]", token_type(t), v.len()),
+            None => "[0]".to_string(),
+        },
+        Token::Array(v) => match v.first() {
+            Some(t) => format!("{}[]", token_type(t)),
+            None => "[]".to_string(),
+        },
+        Token::Tuple(v) => {
+            let types: Vec<String> = v.iter().map(token_type).collect();
+            format!("({})", types.join(","))
+        }
+    }
+}
+
+/// Returns the 4-byte function selector, the first 4 bytes of
+/// the keccak256 hash of its canonical signature (e.g., "transfer(address,uint256)").
+pub fn selector(func: &Function) -> [u8; 4] {
+    func.short_signature()
+}
+
+/// Splits the calldata into its 4-byte selector and the encoded arguments.
+pub fn strip_selector(data: &[u8]) -> io::Result<(&[u8; 4], &[u8])> {
+    if data.len() < 4 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "calldata too short: expected at least 4 bytes, got {}",
+                data.len()
+            ),
+        ));
+    }
+    let (sel, args) = data.split_at(4);
+    let sel: &[u8; 4] = sel
+        .try_into()
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid selector {}", e)))?;
+    Ok((sel, args))
+}
+
+/// Selector of the solidity "Error(string)" revert.
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the solidity "Panic(uint256)" revert (e.g., assert failure, overflow).
+pub const PANIC_UINT256_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Decodes the revert data of "eth_call" or "eth_estimateGas" into the reason,
+/// for the "Error(string)" and "Panic(uint256)" reverts.
+/// Returns "None" for custom errors or empty data (revert without reason).
+/// Use "evm::revert::decode" for the structured reason.
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    match crate::evm::revert::decode(data) {
+        crate::evm::revert::RevertReason::String(reason) => Some(reason),
+        crate::evm::revert::RevertReason::Panic(code) => Some(format!("panic code 0x{:x}", code)),
+        _ => None,
+    }
+}
+
 /// TODO: implement this with "foundry 4-byte decode"
 /// ref. <https://github.com/foundry-rs/foundry/blob/master/common/src/selectors.rs> "decode_calldata"
 /// ref. <sig.eth.samczsun.com>
@@ -31,6 +141,129 @@ pub fn decode_calldata(calldata: &str) -> io::Result<(Function, Vec<Token>)> {
     unimplemented!("not yet")
 }
 
+/// Loads the contract functions and events from the ABI JSON string
+/// (e.g., the "abi" field of the solc or forge build output).
+pub fn load_contract(json: &str) -> io::Result<Contract> {
+    Contract::load(json.as_bytes())
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("failed to load ABI {}", e)))
+}
+
+/// Looks up the function by its signature (e.g., "balanceOf(address)"),
+/// its 4-byte selector (e.g., "0x70a08231"), or its name if not overloaded
+/// (e.g., "balanceOf").
+pub fn function_by_signature<'a>(contract: &'a Contract, sig: &str) -> io::Result<&'a Function> {
+    let sig = sig.trim();
+
+    if let Some(selector) = sig.strip_prefix("0x") {
+        let selector = hex::decode(selector).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid selector '{}' ({})", sig, e),
+            )
+        })?;
+        return contract
+            .functions()
+            .find(|f| f.short_signature().as_slice() == selector.as_slice())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("no function with selector '{}'", sig),
+                )
+            });
+    }
+
+    let name = sig.split('(').next().unwrap_or(sig);
+    let funcs = contract.functions_by_name(name).map_err(|e| {
+        Error::new(
+            ErrorKind::NotFound,
+            format!("no function '{}' ({})", sig, e),
+        )
+    })?;
+
+    if !sig.contains('(') {
+        if funcs.len() > 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "function '{}' is overloaded {} times, use the full signature",
+                    name,
+                    funcs.len()
+                ),
+            ));
+        }
+        return Ok(&funcs[0]);
+    }
+
+    let sig: String = sig.chars().filter(|c| !c.is_whitespace()).collect();
+    funcs
+        .iter()
+        .find(|f| input_signature(f) == sig)
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, format!("no function '{}'", sig)))
+}
+
+/// Returns the canonical signature without outputs (e.g., "balanceOf(address)").
+fn input_signature(func: &Function) -> String {
+    let inputs: Vec<String> = func.inputs.iter().map(|p| p.kind.to_string()).collect();
+    format!("{}({})", func.name, inputs.join(","))
+}
+
+/// Decodes the return data of "eth_call" with the function's declared outputs.
+/// Empty return data for a function with outputs means the call reverted
+/// without a reason (or the target has no code), and returns an error.
+pub fn decode_output(func: &Function, data: &[u8]) -> io::Result<Vec<Token>> {
+    if data.is_empty() && !func.outputs.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "empty return data for '{}' (reverted without reason or no contract code?)",
+                func.name
+            ),
+        ));
+    }
+    func.decode_output(data).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to decode_output {}", e),
+        )
+    })
+}
+
+/// Decodes the event log (e.g., from the transaction receipt), where indexed
+/// parameters are read from the topics and the rest from the data section.
+/// Returns the tokens in the order of the event's declared inputs.
+/// For non-anonymous events, the first topic must be the event signature hash.
+pub fn decode_event(event: &Event, log: &Log) -> io::Result<Vec<Token>> {
+    if !event.anonymous {
+        let signature = event.signature();
+        match log.topics.first() {
+            Some(topic) if *topic == signature => {}
+            Some(topic) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "log topic 0x{:x} does not match '{}' signature 0x{:x}",
+                        topic, event.name, signature
+                    ),
+                ));
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("log has no topic for '{}' signature", event.name),
+                ));
+            }
+        }
+    }
+
+    let parsed = event
+        .parse_log(RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        })
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("failed to parse_log {}", e)))?;
+    Ok(parsed.params.into_iter().map(|p| p.value).collect())
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_encode_calldata_register_name --exact --show-output
 #[test]
 fn test_encode_calldata_register_name() {
@@ -232,3 +465,397 @@ fn test_encode_calldata_forward_request() {
     let calldata = encode_calldata(func, &arg_tokens).unwrap();
     log::info!("calldata: 0x{}", hex::encode(calldata));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_decode_output --exact --show-output
+#[test]
+fn test_decode_output() {
+    use ethers_core::{
+        abi::{self, Function, Param, ParamType, StateMutability, Token},
+        types::{H160, U256},
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // parsed function of "getNumber() (uint256)"
+    let func = Function {
+        name: "getNumber".to_string(),
+        inputs: Vec::new(),
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Uint(256),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: StateMutability::View,
+    };
+    let data = abi::encode(&[Token::Uint(U256::from(7))]);
+    assert_eq!(
+        decode_output(&func, &data).unwrap(),
+        vec![Token::Uint(U256::from(7))]
+    );
+
+    // revert without reason
+    let err = decode_output(&func, &[]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("empty return data"));
+
+    // truncated return data
+    assert!(decode_output(&func, &data[..16]).is_err());
+
+    // parsed function of "get() ((address,bool) info, uint256[] amounts)"
+    let func = Function {
+        name: "get".to_string(),
+        inputs: Vec::new(),
+        outputs: vec![
+            Param {
+                name: "info".to_string(),
+                kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Bool]),
+                internal_type: None,
+            },
+            Param {
+                name: "amounts".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Uint(256))),
+                internal_type: None,
+            },
+        ],
+        constant: None,
+        state_mutability: StateMutability::View,
+    };
+    let expected = vec![
+        Token::Tuple(vec![Token::Address(H160::random()), Token::Bool(true)]),
+        Token::Array(vec![
+            Token::Uint(U256::from(1)),
+            Token::Uint(U256::from(2)),
+            Token::Uint(U256::MAX),
+        ]),
+    ];
+    let data = abi::encode(&expected);
+    assert_eq!(decode_output(&func, &data).unwrap(), expected);
+
+    // no declared outputs
+    let func = Function {
+        name: "increment".to_string(),
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    };
+    assert!(decode_output(&func, &[]).unwrap().is_empty());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_decode_event_transfer --exact --show-output
+#[test]
+fn test_decode_event_transfer() {
+    use ethers_core::{
+        abi::{self, Event, EventParam, ParamType, Token},
+        types::{Log, H160, H256, U256},
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // parsed event of "Transfer(address indexed from, address indexed to, uint256 value)"
+    let event = Event {
+        name: "Transfer".to_string(),
+        inputs: vec![
+            EventParam {
+                name: "from".to_string(),
+                kind: ParamType::Address,
+                indexed: true,
+            },
+            EventParam {
+                name: "to".to_string(),
+                kind: ParamType::Address,
+                indexed: true,
+            },
+            EventParam {
+                name: "value".to_string(),
+                kind: ParamType::Uint(256),
+                indexed: false,
+            },
+        ],
+        anonymous: false,
+    };
+    // keccak256("Transfer(address,address,uint256)")
+    assert_eq!(
+        hex::encode(event.signature()),
+        "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+    );
+
+    let from = H160::random();
+    let to = H160::random();
+    let mut log = Log {
+        topics: vec![event.signature(), H256::from(from), H256::from(to)],
+        data: abi::encode(&[Token::Uint(U256::from(12345))]).into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        decode_event(&event, &log).unwrap(),
+        vec![
+            Token::Address(from),
+            Token::Address(to),
+            Token::Uint(U256::from(12345))
+        ]
+    );
+
+    // signature mismatch
+    log.topics[0] = H256::random();
+    let err = decode_event(&event, &log).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    // no topic
+    log.topics.clear();
+    assert!(decode_event(&event, &log).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_load_contract_erc20 --exact --show-output
+#[test]
+fn test_load_contract_erc20() {
+    use ethers_core::abi::ParamType;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    let json = r#"[
+    {
+        "type": "function",
+        "name": "balanceOf",
+        "stateMutability": "view",
+        "inputs": [{ "name": "account", "type": "address" }],
+        "outputs": [{ "name": "", "type": "uint256" }]
+    },
+    {
+        "type": "function",
+        "name": "transfer",
+        "stateMutability": "nonpayable",
+        "inputs": [
+            { "name": "to", "type": "address" },
+            { "name": "amount", "type": "uint256" }
+        ],
+        "outputs": [{ "name": "", "type": "bool" }]
+    },
+    {
+        "type": "function",
+        "name": "transfer",
+        "stateMutability": "nonpayable",
+        "inputs": [
+            { "name": "to", "type": "address" },
+            { "name": "amount", "type": "uint256" },
+            { "name": "data", "type": "bytes" }
+        ],
+        "outputs": [{ "name": "", "type": "bool" }]
+    },
+    {
+        "type": "event",
+        "name": "Transfer",
+        "anonymous": false,
+        "inputs": [
+            { "name": "from", "type": "address", "indexed": true },
+            { "name": "to", "type": "address", "indexed": true },
+            { "name": "value", "type": "uint256", "indexed": false }
+        ]
+    }
+]"#;
+    let contract = load_contract(json).unwrap();
+    assert!(contract.event("Transfer").is_ok());
+
+    let func = function_by_signature(&contract, "balanceOf(address)").unwrap();
+    assert_eq!(func.name, "balanceOf");
+    assert_eq!(func.inputs[0].kind, ParamType::Address);
+    assert_eq!(func.outputs[0].kind, ParamType::Uint(256));
+
+    // keccak256("balanceOf(address)")[..4]
+    let by_selector = function_by_signature(&contract, "0x70a08231").unwrap();
+    assert_eq!(by_selector, func);
+    let by_name = function_by_signature(&contract, "balanceOf").unwrap();
+    assert_eq!(by_name, func);
+
+    // overloaded
+    assert!(function_by_signature(&contract, "transfer").is_err());
+    let func = function_by_signature(&contract, "transfer(address, uint256, bytes)").unwrap();
+    assert_eq!(func.inputs.len(), 3);
+
+    assert!(function_by_signature(&contract, "approve(address,uint256)").is_err());
+    assert!(function_by_signature(&contract, "0xdeadbeef").is_err());
+    assert!(load_contract("{").is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_selector --exact --show-output
+#[test]
+fn test_selector() {
+    use ethers_core::{
+        abi::{Function, Param, ParamType, StateMutability, Token},
+        types::{H160, U256},
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // parsed function of "transfer(address to, uint256 amount)"
+    let func = Function {
+        name: "transfer".to_string(),
+        inputs: vec![
+            Param {
+                name: "to".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "amount".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+        ],
+        outputs: Vec::new(),
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    };
+    // keccak256("transfer(address,uint256)")[..4]
+    assert_eq!(selector(&func), [0xa9, 0x05, 0x9c, 0xbb]);
+
+    let arg_tokens = vec![Token::Address(H160::random()), Token::Uint(U256::from(100))];
+    let calldata = encode_calldata(func.clone(), &arg_tokens).unwrap();
+    assert!(calldata.starts_with(&selector(&func)));
+
+    let (sel, args) = strip_selector(&calldata).unwrap();
+    assert_eq!(*sel, selector(&func));
+    assert_eq!(args.len(), 64);
+    assert_eq!(func.decode_input(args).unwrap(), arg_tokens);
+
+    // selector only
+    let (sel, args) = strip_selector(&calldata[..4]).unwrap();
+    assert_eq!(*sel, selector(&func));
+    assert!(args.is_empty());
+
+    assert!(strip_selector(&calldata[..3]).is_err());
+    assert!(strip_selector(&[]).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_encode_calldata_checked --exact --show-output
+#[test]
+fn test_encode_calldata_checked() {
+    use ethers_core::{
+        abi::{Function, Param, ParamType, StateMutability, Token},
+        types::{H160, U256},
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // parsed function of "mint(address receiver, uint8 amount, bytes data)"
+    let func = Function {
+        name: "mint".to_string(),
+        inputs: vec![
+            Param {
+                name: "receiver".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "amount".to_string(),
+                kind: ParamType::Uint(8),
+                internal_type: None,
+            },
+            Param {
+                name: "data".to_string(),
+                kind: ParamType::Bytes,
+                internal_type: None,
+            },
+        ],
+        outputs: Vec::new(),
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    };
+    let receiver = H160::random();
+
+    let arg_tokens = vec![
+        Token::Address(receiver),
+        Token::Uint(U256::from(255)),
+        Token::Bytes(vec![1, 2, 3]),
+    ];
+    assert_eq!(
+        encode_calldata_checked(func.clone(), &arg_tokens).unwrap(),
+        encode_calldata(func.clone(), &arg_tokens).unwrap()
+    );
+
+    // arity mismatch
+    let err = encode_calldata_checked(func.clone(), &arg_tokens[..2]).unwrap_err();
+    assert_eq!(err.to_string(), "'mint' expected 3 args, got 2");
+
+    // type mismatch
+    let err = encode_calldata_checked(
+        func.clone(),
+        &[
+            Token::Address(receiver),
+            Token::Address(receiver),
+            Token::Bytes(vec![1, 2, 3]),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "arg 1 expected uint8, got address");
+
+    let err = encode_calldata_checked(
+        func.clone(),
+        &[
+            Token::Address(receiver),
+            Token::Uint(U256::from(1)),
+            Token::Array(vec![Token::Bool(true)]),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "arg 2 expected bytes, got bool[]");
+
+    // out of range for the declared integer size
+    let err = encode_calldata_checked(
+        func,
+        &[
+            Token::Address(receiver),
+            Token::Uint(U256::from(256)),
+            Token::Bytes(vec![1, 2, 3]),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "arg 1 value 256 overflows uint8");
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_decode_revert_reason --exact --show-output
+#[test]
+fn test_decode_revert_reason() {
+    use ethers_core::{abi::Token, types::U256};
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // revert("Ownable: caller is not the owner")
+    let data = hex::decode("08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000204f776e61626c653a2063616c6c6572206973206e6f7420746865206f776e6572").unwrap();
+    assert_eq!(
+        decode_revert_reason(&data).unwrap(),
+        "Ownable: caller is not the owner"
+    );
+
+    // arithmetic overflow
+    let data = [
+        &PANIC_UINT256_SELECTOR[..],
+        &ethers_core::abi::encode(&[Token::Uint(U256::from(0x11))]),
+    ]
+    .concat();
+    assert_eq!(decode_revert_reason(&data).unwrap(), "panic code 0x11");
+
+    // custom error "Unauthorized()"
+    assert!(decode_revert_reason(&[0x82, 0xb4, 0x29, 0x00]).is_none());
+    assert!(decode_revert_reason(&[]).is_none());
+    assert!(decode_revert_reason(&ERROR_STRING_SELECTOR).is_none());
+}
@@ -214,12 +214,12 @@ fn test_transaction() {
     }
 
     let k1 = crate::key::secp256k1::private_key::Key::generate().unwrap();
-    let key_info1 = k1.to_info(1234).unwrap();
+    let key_info1 = k1.to_info(1234u32).unwrap();
     log::info!("created {}", key_info1.h160_address);
     let k1_signer: ethers_signers::LocalWallet = k1.to_ethers_core_signing_key().into();
 
     let k2 = crate::key::secp256k1::private_key::Key::generate().unwrap();
-    let key_info2 = k2.to_info(1234).unwrap();
+    let key_info2 = k2.to_info(1234u32).unwrap();
     log::info!("created {}", key_info2.h160_address);
 
     let chain_id = random_manager::u64() % 3000;
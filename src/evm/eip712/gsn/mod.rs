@@ -2,7 +2,10 @@
 
 pub mod relay;
 
-use std::{collections::BTreeMap, io};
+use std::{
+    collections::BTreeMap,
+    io::{self, Error, ErrorKind},
+};
 
 use crate::evm::abi as evm_abi;
 use ethers_core::{
@@ -210,6 +213,34 @@ impl Tx {
         H256(self.eip712_domain().separator())
     }
 
+    /// Returns the EIP-712 domain separator that the forwarder contract expects,
+    /// to compare against the one registered on-chain via "registerDomainSeparator".
+    /// Fails if the domain name, chain Id, or verifying contract is not set.
+    /// Validating counterpart of "Eip712::domain_separator", which never fails.
+    /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "registerDomainSeparator"
+    pub fn domain_separator_hash(&self) -> io::Result<[u8; 32]> {
+        if self.domain_name.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "empty domain name"));
+        }
+        if self.domain_chain_id.is_zero() {
+            return Err(Error::new(ErrorKind::InvalidInput, "zero domain chain Id"));
+        }
+        if self.domain_verifying_contract.is_zero() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "zero domain verifying contract",
+            ));
+        }
+        Ok(self.compute_domain_separator().to_fixed_bytes())
+    }
+
+    /// Returns the request type hash that the forwarder contract expects,
+    /// to compare against the one registered on-chain via "registerRequestType".
+    /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "registerRequestType"
+    pub fn type_hash(&self) -> [u8; 32] {
+        compute_request_type_hash(&self.type_name, &self.type_suffix_data).to_fixed_bytes()
+    }
+
     /// Hash of the struct, according to EIP-712 definition of `hashStruct`.
     /// Implements "_getEncoded" and "_verifySig" in GSN Forwarder.sol.
     /// This method is used for "encode_eip712".
@@ -547,3 +578,46 @@ fn foward_request_types() -> Types {
     );
     return types;
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::eip712::gsn::test_domain_separator --exact --show-output
+#[test]
+fn test_domain_separator() {
+    use std::str::FromStr;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    let tx = Tx::new()
+        .domain_name("my domain name")
+        .domain_version("1")
+        .domain_chain_id(U256::from(1234567))
+        .domain_verifying_contract(
+            H160::from_str("0x17aB05351fC94a1a67Bf3f56DdbB941aE6c63E25").unwrap(),
+        )
+        .type_name("my name")
+        .type_suffix_data("bytes32 ABCDEFGHIJKLMNOPQRSTGSN)");
+
+    // keccak256(abi.encode(
+    //   keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"),
+    //   keccak256("my domain name"), keccak256("1"), 1234567, 0x17aB05351fC94a1a67Bf3f56DdbB941aE6c63E25))
+    let domain_separator = tx.domain_separator_hash().unwrap();
+    assert_eq!(
+        hex::encode(domain_separator),
+        "b42bccaac384bc0f04e092cbb9459e1bc3a319a036e2854fb239a1097518e60d"
+    );
+    assert_eq!(Eip712::domain_separator(&tx).unwrap(), domain_separator);
+
+    // keccak256("my name(address from,...,uint256 validUntilTime,bytes32 ABCDEFGHIJKLMNOPQRSTGSN)")
+    assert_eq!(
+        hex::encode(tx.type_hash()),
+        "98249594c224b5268d14141436a56cdacada4ee99042e15d5d75e09f958ac996"
+    );
+
+    assert!(Tx::new().domain_separator_hash().is_err());
+    assert!(tx
+        .domain_verifying_contract(H160::zero())
+        .domain_separator_hash()
+        .is_err());
+}
@@ -6,13 +6,19 @@ use std::{
     str::FromStr,
 };
 
+use crate::evm::{
+    abi as evm_abi, add_gas_margin, revert as evm_revert, DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT,
+};
 use ethers::prelude::Eip1559TransactionRequest;
-use ethers_core::types::{
-    transaction::{
-        eip2718::TypedTransaction,
-        eip712::{Eip712, TypedData},
+use ethers_core::{
+    abi::{Function, Param, ParamType, StateMutability, Token},
+    types::{
+        transaction::{
+            eip2718::TypedTransaction,
+            eip712::{Eip712, TypedData},
+        },
+        RecoveryMessage, Signature, H160, H256, U256,
     },
-    RecoveryMessage, Signature, H160, H256, U256,
 };
 use ethers_providers::{Http, Middleware, Provider};
 use serde::{Deserialize, Serialize};
@@ -39,6 +45,136 @@ impl super::Tx {
         Request::sign_to_request(self, eth_signer).await
     }
 
+    /// Fetches the current nonce of the "from" address from the forwarder
+    /// contract "getNonce(address)", and sets the "nonce" field.
+    /// Nothing is cached: the forwarder increments the nonce on every executed request,
+    /// so callers must re-fetch between submissions.
+    /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "getNonce"
+    pub async fn fetch_nonce(
+        &mut self,
+        chain_rpc_provider: &Provider<Http>,
+        forwarder_addr: H160,
+        from: H160,
+    ) -> io::Result<U256> {
+        // parsed function of "getNonce(address from) (uint256)"
+        let func = Function {
+            name: "getNonce".to_string(),
+            inputs: vec![Param {
+                name: "from".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            }],
+            outputs: vec![Param {
+                name: "".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            }],
+            constant: None,
+            state_mutability: StateMutability::View,
+        };
+        let calldata = evm_abi::encode_calldata(func.clone(), &[Token::Address(from)])?;
+
+        let eip1559_tx = Eip1559TransactionRequest::new()
+            .chain_id(self.domain_chain_id.as_u64())
+            .to(forwarder_addr)
+            .data(calldata);
+        let typed_tx: TypedTransaction = eip1559_tx.into();
+        let output = chain_rpc_provider
+            .call(&typed_tx, None)
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed getNonce call '{}'", e)))?;
+
+        let nonce = match evm_abi::decode_output(&func, &output)?.pop() {
+            Some(Token::Uint(nonce)) => nonce,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "unexpected getNonce output",
+                ))
+            }
+        };
+        log::info!("fetched forwarder nonce {nonce} for {from}");
+
+        self.nonce = nonce;
+        Ok(nonce)
+    }
+
+    /// Recomputes the EIP-712 digest of the forward request
+    /// and recovers the signer address from the 65-byte signature.
+    pub fn recover_signer(&self, sig: &[u8]) -> io::Result<H160> {
+        let sig = Signature::try_from(sig).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed Signature::try_from '{}'", e),
+            )
+        })?;
+
+        let fwd_req_hash = self
+            .encode_eip712()
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed encode_eip712 '{}'", e)))?;
+        sig.recover(RecoveryMessage::Hash(H256(fwd_req_hash)))
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("failed to recover signer address '{}'", e),
+                )
+            })
+    }
+
+    /// Returns true if the signature was signed by the "from" address of the forward request.
+    /// Relay servers must check this before spending gas on the request,
+    /// since the forwarder would revert the forged request anyway.
+    pub fn verify(&self, sig: &[u8]) -> io::Result<bool> {
+        let signer_addr = self.recover_signer(sig)?;
+        Ok(signer_addr == self.from)
+    }
+
+    /// Estimates the "gas" field of the forward request with the default safety margin.
+    /// See "estimate_gas_with_margin".
+    pub async fn estimate_gas(&self, chain_rpc_provider: &Provider<Http>) -> io::Result<U256> {
+        self.estimate_gas_with_margin(chain_rpc_provider, DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT)
+            .await
+    }
+
+    /// Estimates the "gas" field of the forward request, by running "eth_estimateGas"
+    /// on the inner call that the forwarder "execute" makes to the recipient contract
+    /// (from the forwarder, with the request sender appended to the calldata),
+    /// and adds the safety margin in percent.
+    /// The estimate includes the intrinsic transaction gas, which is not charged
+    /// to the inner call, so it slightly over-estimates.
+    /// If the inner call reverts, the error includes the revert reason.
+    /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "execute"
+    pub async fn estimate_gas_with_margin(
+        &self,
+        chain_rpc_provider: &Provider<Http>,
+        margin_percent: u64,
+    ) -> io::Result<U256> {
+        // "req.to.call{gas : req.gas, value : req.value}(abi.encodePacked(req.data, req.from))"
+        let calldata = [&self.data[..], self.from.as_bytes()].concat();
+        let eip1559_tx = Eip1559TransactionRequest::new()
+            .chain_id(self.domain_chain_id.as_u64())
+            .from(self.domain_verifying_contract)
+            .to(self.to)
+            .value(self.value)
+            .data(calldata);
+        let typed_tx: TypedTransaction = eip1559_tx.into();
+
+        let estimated_gas = chain_rpc_provider
+            .estimate_gas(&typed_tx, None)
+            .await
+            .map_err(|e| match evm_revert::from_provider_error(&e) {
+                Some(reason) => Error::new(
+                    ErrorKind::Other,
+                    format!("estimate_gas reverted '{}'", reason),
+                ),
+                None => Error::new(ErrorKind::Other, format!("failed estimate_gas '{}'", e)),
+            })?;
+
+        let gas = add_gas_margin(estimated_gas, margin_percent)?;
+        log::info!("estimated gas {estimated_gas}, {gas} with {margin_percent}% margin");
+        Ok(gas)
+    }
+
     /// "sign_to_request" but with estimated gas via RPC endpoints.
     pub async fn sign_to_request_with_estimated_gas(
         &mut self,
@@ -496,3 +632,56 @@ fn test_build_relay_transaction_request() {
     let d = tx.encode_execute_call(sig1.to_vec()).unwrap();
     log::info!("encode_execute_call: {}", hex::encode(d));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::eip712::gsn::relay::test_verify --exact --show-output
+#[test]
+fn test_verify() {
+    use ethers_core::types::U256;
+    use ethers_signers::LocalWallet;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let k = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let signer: LocalWallet = k.to_ethers_core_signing_key().into();
+
+    let tx = super::Tx::new()
+        .domain_name("my domain name")
+        .domain_version("1")
+        .domain_chain_id(U256::from(43112))
+        .domain_verifying_contract(H160::random())
+        .from(k.to_public_key().to_h160())
+        .to(H160::random())
+        .value(U256::zero())
+        .gas(U256::from(30000))
+        .nonce(U256::from(1))
+        .data(vec![1, 2, 3])
+        .valid_until_time(U256::MAX)
+        .type_name("my name")
+        .type_suffix_data("bytes8 typeSuffixDatadatadatada)");
+
+    let sig = ab!(tx.sign(signer)).unwrap();
+    assert_eq!(
+        tx.recover_signer(&sig).unwrap(),
+        k.to_public_key().to_h160()
+    );
+    assert!(tx.verify(&sig).unwrap());
+
+    // tampered nonce recovers a different signer
+    let tampered = tx.nonce(U256::from(2));
+    assert_ne!(
+        tampered.recover_signer(&sig).unwrap(),
+        k.to_public_key().to_h160()
+    );
+    assert!(!tampered.verify(&sig).unwrap());
+
+    assert!(tampered.verify(&sig[..64]).is_err());
+}
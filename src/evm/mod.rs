@@ -2,3 +2,53 @@ pub mod abi;
 pub mod eip1559;
 pub mod eip712;
 pub mod foundry;
+pub mod revert;
+
+use std::io::{self, Error, ErrorKind};
+
+use primitive_types::U256;
+
+/// Default safety margin in percent added to the "eth_estimateGas" result,
+/// since the actual gas used may differ from the estimate when the state changes
+/// between the estimation and the execution.
+pub const DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT: u64 = 20;
+
+/// Adds the safety margin in percent to the estimated gas.
+/// Shared by the EVM wallet and the GSN forward request gas estimation.
+pub fn add_gas_margin(estimated: U256, margin_percent: u64) -> io::Result<U256> {
+    estimated
+        .checked_mul(U256::from(100) + U256::from(margin_percent))
+        .map(|v| v / U256::from(100))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "estimated gas {} overflows with {}% margin",
+                    estimated, margin_percent
+                ),
+            )
+        })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::test_add_gas_margin --exact --show-output
+#[test]
+fn test_add_gas_margin() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    assert_eq!(
+        add_gas_margin(U256::from(21000), 0).unwrap(),
+        U256::from(21000)
+    );
+    assert_eq!(
+        add_gas_margin(U256::from(21000), DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT).unwrap(),
+        U256::from(25200)
+    );
+    assert_eq!(
+        add_gas_margin(U256::from(50000), 50).unwrap(),
+        U256::from(75000)
+    );
+    assert!(add_gas_margin(U256::MAX, 10).is_err());
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::evm::abi;
+use ethers_core::abi::ParamType;
+use ethers_providers::{HttpClientError, ProviderError};
+
+/// Represents the decoded revert data of "eth_call", "eth_estimateGas",
+/// or the failed transaction.
+/// ref. <https://docs.soliditylang.org/en/latest/control-structures.html#error-handling-assert-require-revert-and-exceptions>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RevertReason {
+    /// "Error(string)" from "require(cond, reason)" or "revert(reason)".
+    String(String),
+    /// "Panic(uint256)" from the failed assertion or runtime error (e.g., 0x11 for overflow).
+    Panic(u64),
+    /// Custom error (e.g., "error Unauthorized()") with its selector
+    /// and the ABI-encoded arguments.
+    Custom([u8; 4], Vec<u8>),
+    /// Revert without data (e.g., "revert()" or "require(cond)").
+    Empty,
+}
+
+/// Decodes the revert data.
+/// The data that does not match "Error(string)" or "Panic(uint256)" is returned
+/// as "Custom", and the data shorter than the 4-byte selector as "Empty".
+pub fn decode(data: &[u8]) -> RevertReason {
+    let Ok((sel, args)) = abi::strip_selector(data) else {
+        return RevertReason::Empty;
+    };
+
+    if *sel == abi::ERROR_STRING_SELECTOR {
+        if let Some(reason) = ethers_core::abi::decode(&[ParamType::String], args)
+            .ok()
+            .and_then(|mut tokens| tokens.pop())
+            .and_then(|t| t.into_string())
+        {
+            return RevertReason::String(reason);
+        }
+    }
+    if *sel == abi::PANIC_UINT256_SELECTOR {
+        if let Some(code) = ethers_core::abi::decode(&[ParamType::Uint(256)], args)
+            .ok()
+            .and_then(|mut tokens| tokens.pop())
+            .and_then(|t| t.into_uint())
+        {
+            if code.bits() <= 64 {
+                return RevertReason::Panic(code.as_u64());
+            }
+        }
+    }
+
+    RevertReason::Custom(*sel, args.to_vec())
+}
+
+/// Returns the revert reason from the JSON-RPC error data (or message),
+/// or "None" if the error is not a revert (e.g., connection failure).
+pub fn from_provider_error(e: &ProviderError) -> Option<RevertReason> {
+    let ProviderError::JsonRpcClientError(err) = e else {
+        return None;
+    };
+    let HttpClientError::JsonRpcError(rpc_err) = err.downcast_ref::<HttpClientError>()? else {
+        return None;
+    };
+
+    // e.g., "0x08c379a0..."
+    if let Some(data) = rpc_err.data.as_ref().and_then(|d| d.as_str()) {
+        if let Ok(b) = hex::decode(data.trim_start_matches("0x")) {
+            if !b.is_empty() {
+                return Some(decode(&b));
+            }
+        }
+    }
+
+    // e.g., "execution reverted: Ownable: caller is not the owner"
+    let reason = rpc_err.message.strip_prefix("execution reverted")?;
+    let reason = reason.trim_start_matches(':').trim();
+    if reason.is_empty() {
+        Some(RevertReason::Empty)
+    } else {
+        Some(RevertReason::String(reason.to_string()))
+    }
+}
+
+/// Returns the description of the "Panic(uint256)" code.
+/// ref. <https://docs.soliditylang.org/en/latest/control-structures.html#panic-via-assert-and-error-via-require>
+pub fn panic_description(code: u64) -> &'static str {
+    match code {
+        0x00 => "generic compiler inserted panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array encoding",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to zero-initialized function",
+        _ => "unknown panic",
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
+/// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
+/// Use "Self.to_string()" to directly invoke this
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::String(reason) => write!(f, "{}", reason),
+            RevertReason::Panic(code) => {
+                write!(f, "panic code 0x{:x} ({})", code, panic_description(*code))
+            }
+            RevertReason::Custom(sel, args) => write!(
+                f,
+                "custom error 0x{} (args 0x{})",
+                hex::encode(sel),
+                hex::encode(args)
+            ),
+            RevertReason::Empty => write!(f, "reverted without reason"),
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::revert::test_decode --exact --show-output
+#[test]
+fn test_decode() {
+    use ethers_core::{abi::Token, types::U256};
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // require(msg.sender == owner, "Ownable: caller is not the owner")
+    let data = hex::decode("08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000204f776e61626c653a2063616c6c6572206973206e6f7420746865206f776e6572").unwrap();
+    let reason = decode(&data);
+    assert_eq!(
+        reason,
+        RevertReason::String("Ownable: caller is not the owner".to_string())
+    );
+    assert_eq!(reason.to_string(), "Ownable: caller is not the owner");
+
+    // "uint8 x = 255; x + 1;" with checked arithmetic
+    let data = [
+        &abi::PANIC_UINT256_SELECTOR[..],
+        &ethers_core::abi::encode(&[Token::Uint(U256::from(0x11))]),
+    ]
+    .concat();
+    let reason = decode(&data);
+    assert_eq!(reason, RevertReason::Panic(0x11));
+    assert_eq!(
+        reason.to_string(),
+        "panic code 0x11 (arithmetic overflow or underflow)"
+    );
+
+    // custom error "InsufficientBalance(uint256)"
+    let args = ethers_core::abi::encode(&[Token::Uint(U256::from(100))]);
+    let data = [&[0xcf, 0x47, 0x91, 0x81][..], &args].concat();
+    assert_eq!(
+        decode(&data),
+        RevertReason::Custom([0xcf, 0x47, 0x91, 0x81], args)
+    );
+
+    // malformed "Error(string)" without the argument
+    assert_eq!(
+        decode(&abi::ERROR_STRING_SELECTOR),
+        RevertReason::Custom(abi::ERROR_STRING_SELECTOR, Vec::new())
+    );
+
+    assert_eq!(decode(&[]), RevertReason::Empty);
+    assert_eq!(decode(&[]).to_string(), "reverted without reason");
+}
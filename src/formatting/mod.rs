@@ -2,26 +2,63 @@ use std::io::{self, Error, ErrorKind};
 
 use crate::hash;
 use bech32::{ToBase32, Variant};
+use sha2::Digest;
+use zeroize::Zeroizing;
 
 const CHECKSUM_LENGTH: usize = 4;
 
-/// Implements "formatting.EncodeWithChecksum" with "formatting.CB58".
-/// "ids.ShortID.String" appends checksum to the digest bytes.
-/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum>
+/// Encodes the bytes in plain base58 (Bitcoin alphabet) WITHOUT the checksum.
+/// Only for interop with legacy payloads (e.g., raw base58 ids).
+/// Do NOT use for key material or ids, which must be checksummed
+/// (see "encode_cb58_with_checksum_string").
+/// ref. "base58.Encode"
+pub fn encode_cb58(d: &[u8]) -> String {
+    bs58::encode(d).into_string()
+}
+
+/// Decodes the plain base58 string WITHOUT verifying any checksum.
+/// Only for interop with legacy payloads (e.g., raw base58 ids).
+/// Do NOT use for key material or ids, which must be checksummed
+/// (see "decode_cb58_with_checksum").
+/// ref. "base58.Decode"
+pub fn decode_cb58(d: &str) -> io::Result<Vec<u8>> {
+    bs58::decode(d).into_vec().map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("failed to decode base58 ({})", e),
+        )
+    })
+}
+
+/// Appends the 4-byte "hashing.Checksum" of "sha256.Sum256" to the bytes.
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum>
-pub fn encode_cb58_with_checksum_string(d: &[u8]) -> String {
-    // "hashing.Checksum" of "sha256.Sum256"
+fn append_checksum(d: &[u8]) -> Vec<u8> {
     let checksum = hash::sha256(d);
     let checksum_length = checksum.len();
-    let checksum = &checksum[checksum_length - CHECKSUM_LENGTH..];
 
-    let mut checked = d.to_vec();
-    let mut checksum = checksum.to_vec();
-    checked.append(&mut checksum);
+    // sized up front so that the bytes are never copied to a reallocated buffer,
+    // which matters for the key material (see "encode_cb58_with_checksum_zeroizing")
+    let mut checked = Vec::with_capacity(d.len() + CHECKSUM_LENGTH);
+    checked.extend_from_slice(d);
+    checked.extend_from_slice(&checksum[checksum_length - CHECKSUM_LENGTH..]);
+    checked
+}
 
+/// Implements "formatting.EncodeWithChecksum" with "formatting.CB58".
+/// "ids.ShortID.String" appends checksum to the digest bytes.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum>
+pub fn encode_cb58_with_checksum_string(d: &[u8]) -> String {
     // ref. "utils/formatting encode.CB58"
-    // ref. "base58.Encode"
-    bs58::encode(&checked).into_string()
+    encode_cb58(&append_checksum(d))
+}
+
+/// Same as "encode_cb58_with_checksum_string" but for the key material
+/// (e.g., "PrivateKey-" CB58): the checksummed bytes are wiped before returning,
+/// and the returned string is zeroed out on drop.
+pub fn encode_cb58_with_checksum_zeroizing(d: &[u8]) -> Zeroizing<String> {
+    let checked = Zeroizing::new(append_checksum(d));
+    Zeroizing::new(encode_cb58(&checked))
 }
 
 /// Implements "formatting.EncodeWithChecksum" with "formatting.CB58".
@@ -29,42 +66,111 @@ pub fn encode_cb58_with_checksum_string(d: &[u8]) -> String {
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum>
 pub fn encode_cb58_with_checksum_vec(d: &[u8]) -> Vec<u8> {
-    // "hashing.Checksum" of "sha256.Sum256"
-    let checksum = hash::sha256(d);
-    let checksum_length = checksum.len();
-    let checksum = &checksum[checksum_length - CHECKSUM_LENGTH..];
-
-    let mut checked = d.to_vec();
-    let mut checksum = checksum.to_vec();
-    checked.append(&mut checksum);
+    encode_cb58_with_checksum_string(d).into_bytes()
+}
 
-    // ref. "utils/formatting encode.CB58"
-    // ref. "base58.Encode"
-    bs58::encode(&checked).into_vec()
+/// Same as "encode_cb58_with_checksum_string" but writes the encoded string
+/// to the writer, so that encoding many ids (e.g., to a file) streams them
+/// instead of building one large string. Only the small per-call buffers
+/// for the checksummed bytes and its encoding are allocated.
+/// The peak allocation has not been measured yet: run
+/// "examples/formatting_cb58_encode_writer.rs" to compare it against
+/// joining the strings.
+pub fn encode_cb58_with_checksum_to_writer<W: io::Write>(d: &[u8], w: &mut W) -> io::Result<()> {
+    let encoded = bs58::encode(append_checksum(d)).into_vec();
+    w.write_all(&encoded)
 }
 
 /// Implements "formatting.Decode" with "formatting.CB58".
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#Decode>
 pub fn decode_cb58_with_checksum(d: &str) -> io::Result<Vec<u8>> {
-    let decoded = match bs58::decode(d).into_vec() {
-        Ok(v) => v,
+    let mut out = Vec::new();
+    decode_cb58_with_checksum_into(d, &mut out)?;
+    Ok(out)
+}
+
+/// Same as "decode_cb58_with_checksum" but decodes into the caller-provided
+/// buffer, which is cleared first and holds the checksum-verified bytes on
+/// success (empty on error). Reusing the same buffer across calls avoids the
+/// per-call allocation when decoding many ids in a loop (the buffer only grows
+/// to the longest input).
+/// The improvement has not been measured yet, so no numbers are recorded here:
+/// run "examples/formatting_cb58_decode.rs" to compare against
+/// "decode_cb58_with_checksum" on the target machine.
+pub fn decode_cb58_with_checksum_into(d: &str, out: &mut Vec<u8>) -> io::Result<()> {
+    out.clear();
+
+    // base58 decoded output is never longer than its input
+    out.resize(d.len(), 0);
+    let decoded_length = match bs58::decode(d).into(out.as_mut_slice()) {
+        Ok(n) => n,
         Err(e) => {
+            out.clear();
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 format!("failed to decode base58 ({})", e),
             ));
         }
     };
+    out.truncate(decoded_length);
+
+    match verify_checksum(out) {
+        Ok(orig_length) => {
+            out.truncate(orig_length);
+            Ok(())
+        }
+        Err(e) => {
+            out.clear();
+            Err(e)
+        }
+    }
+}
+
+/// Returns true if the string is valid base58 with the matching trailing
+/// 4-byte checksum, discarding the payload (e.g., to validate the ids before
+/// the deeper processing). Cheaper than "decode_cb58_with_checksum" since
+/// the ids (up to 96-byte encoded) are decoded on the stack, without allocation.
+pub fn verify_cb58_checksum(d: &str) -> bool {
+    const STACK_LEN: usize = 96;
+
+    // base58 decoded output is never longer than its input
+    if d.len() <= STACK_LEN {
+        let mut buf = [0u8; STACK_LEN];
+        match bs58::decode(d).into(&mut buf[..]) {
+            Ok(n) => verify_checksum(&buf[..n]).is_ok(),
+            Err(_) => false,
+        }
+    } else {
+        match bs58::decode(d).into_vec() {
+            Ok(decoded) => verify_checksum(&decoded).is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Verifies the trailing 4-byte "hashing.Checksum" of the decoded bytes,
+/// and returns the length of the original bytes without the checksum.
+fn verify_checksum(decoded: &[u8]) -> io::Result<usize> {
     let decoded_length = decoded.len();
+    if decoded_length < CHECKSUM_LENGTH {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "decoded length {} is shorter than the checksum length {}",
+                decoded_length, CHECKSUM_LENGTH
+            ),
+        ));
+    }
 
     // verify checksum
-    let checksum = &decoded[decoded_length - CHECKSUM_LENGTH..];
-    let orig = &decoded[..decoded_length - CHECKSUM_LENGTH];
+    let orig_length = decoded_length - CHECKSUM_LENGTH;
+    let checksum = &decoded[orig_length..];
+    let orig = &decoded[..orig_length];
 
     // "hashing.Checksum" of "sha256.Sum256"
-    let orig_checksum = hash::sha256(orig);
-    let orig_checksum_length = orig_checksum.len();
-    let orig_checksum = &orig_checksum[orig_checksum_length - CHECKSUM_LENGTH..];
+    // (digest on the stack, to not allocate per call)
+    let orig_checksum = sha2::Sha256::digest(orig);
+    let orig_checksum = &orig_checksum[orig_checksum.len() - CHECKSUM_LENGTH..];
     if !cmp_manager::eq_vectors(checksum, orig_checksum) {
         return Err(Error::new(
             ErrorKind::InvalidInput,
@@ -72,7 +178,134 @@ pub fn decode_cb58_with_checksum(d: &str) -> io::Result<Vec<u8>> {
         ));
     }
 
-    Ok(orig.to_vec())
+    Ok(orig_length)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_encode_cb58 --exact --show-output
+#[test]
+fn test_encode_cb58() {
+    let d: Vec<u8> = vec![0, 0, 1, 2, 3, 255];
+    let encoded = encode_cb58(&d);
+    assert_eq!(decode_cb58(&encoded).unwrap(), d);
+
+    // leading zero bytes are encoded as "1"
+    assert!(encoded.starts_with("11"));
+    assert_eq!(encode_cb58(&[]), "");
+    assert!(decode_cb58("").unwrap().is_empty());
+    assert!(decode_cb58("0OIl").is_err());
+
+    // the checksum variant is the plain encoding of the checksummed bytes
+    let checked = encode_cb58_with_checksum_string(&d);
+    let raw = decode_cb58(&checked).unwrap();
+    assert_eq!(raw.len(), d.len() + CHECKSUM_LENGTH);
+    assert_eq!(raw[..d.len()], d[..]);
+    assert_eq!(decode_cb58_with_checksum(&checked).unwrap(), d);
+    assert_eq!(encode_cb58_with_checksum_vec(&d), checked.as_bytes());
+
+    // plain base58 does not verify the checksum
+    assert!(decode_cb58_with_checksum(&encoded).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_encode_cb58_with_checksum_to_writer --exact --show-output
+#[test]
+fn test_encode_cb58_with_checksum_to_writer() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let mut expected = String::new();
+    let mut buf = Vec::new();
+    for i in 0..100_u64 {
+        let d = [&i.to_be_bytes()[..], &[0u8; 12]].concat();
+        expected.push_str(&encode_cb58_with_checksum_string(&d));
+        expected.push('\n');
+
+        encode_cb58_with_checksum_to_writer(&d, &mut buf).unwrap();
+        buf.push(b'\n');
+    }
+    assert_eq!(String::from_utf8(buf).unwrap(), expected);
+
+    let mut buf = Vec::new();
+    encode_cb58_with_checksum_to_writer(&[], &mut buf).unwrap();
+    assert_eq!(buf, encode_cb58_with_checksum_vec(&[]));
+
+    // propagates the writer error
+    let mut full = [0u8; 4];
+    assert!(encode_cb58_with_checksum_to_writer(&[1, 2, 3], &mut &mut full[..]).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_verify_cb58_checksum --exact --show-output
+#[test]
+fn test_verify_cb58_checksum() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // short id and 32-byte id
+    for d in [vec![1u8; 20], vec![0xab; 32], vec![7u8; 200], Vec::new()] {
+        let encoded = encode_cb58_with_checksum_string(&d);
+        assert!(verify_cb58_checksum(&encoded));
+        assert_eq!(
+            verify_cb58_checksum(&encoded),
+            decode_cb58_with_checksum(&encoded).is_ok()
+        );
+
+        // one bit flipped in the checksum
+        let mut checked = bs58::decode(&encoded).into_vec().unwrap();
+        let last = checked.len() - 1;
+        checked[last] ^= 0x01;
+        let flipped = encode_cb58(&checked);
+        assert!(!verify_cb58_checksum(&flipped));
+        assert!(decode_cb58_with_checksum(&flipped).is_err());
+
+        // one bit flipped in the payload
+        if !d.is_empty() {
+            checked[last] ^= 0x01;
+            checked[0] ^= 0x80;
+            assert!(!verify_cb58_checksum(&encode_cb58(&checked)));
+        }
+    }
+
+    // "ids.ShortID" of "avalanchego"
+    assert!(verify_cb58_checksum("6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV"));
+
+    assert!(!verify_cb58_checksum(""));
+    assert!(!verify_cb58_checksum("1"));
+    assert!(!verify_cb58_checksum("0OIl"));
+    assert!(!verify_cb58_checksum("6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeW"));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_decode_cb58_with_checksum_into --exact --show-output
+#[test]
+fn test_decode_cb58_with_checksum_into() {
+    let mut buf = Vec::new();
+
+    decode_cb58_with_checksum_into("1NVSVezva3bAtJesnUj", &mut buf).unwrap();
+    assert_eq!(buf, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255]);
+    let capacity = buf.capacity();
+
+    // shorter inputs reuse the same buffer without stale bytes
+    decode_cb58_with_checksum_into("1c7hwa", &mut buf).unwrap();
+    assert_eq!(buf, vec![0]);
+    decode_cb58_with_checksum_into("45PJLL", &mut buf).unwrap();
+    assert!(buf.is_empty());
+    assert_eq!(buf.capacity(), capacity);
+
+    let d: Vec<u8> = (1..=20).collect();
+    let encoded = encode_cb58_with_checksum_string(&d);
+    decode_cb58_with_checksum_into(&encoded, &mut buf).unwrap();
+    assert_eq!(buf, d);
+    assert_eq!(decode_cb58_with_checksum(&encoded).unwrap(), buf);
+
+    // the buffer is left empty on errors
+    assert!(decode_cb58_with_checksum_into("1c7hwb", &mut buf).is_err());
+    assert!(buf.is_empty());
+    assert!(decode_cb58_with_checksum_into("0OIl", &mut buf).is_err());
+    assert!(buf.is_empty());
+    assert!(decode_cb58_with_checksum_into("1", &mut buf).is_err());
+    assert!(buf.is_empty());
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_encode_c58_with_checksum --exact --show-output
@@ -112,48 +345,27 @@ fn test_encode_c58_with_checksum() {
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum>
 pub fn encode_hex_with_checksum(d: &[u8]) -> String {
-    // "hashing.Checksum" of "sha256.Sum256"
-    let checksum = hash::sha256(d);
-    let checksum_length = checksum.len();
-    let checksum = &checksum[checksum_length - CHECKSUM_LENGTH..];
-
-    let mut checked = d.to_vec();
-    let mut checksum = checksum.to_vec();
-    checked.append(&mut checksum);
-
-    hex::encode(&checked)
+    hex::encode(append_checksum(d))
 }
 
 /// Implements "formatting.Decode" with "formatting.Hex".
+/// The "0x" prefix that "formatting.Encode" prepends (e.g., "getUTXOs" API
+/// responses) is optional. Fails if the trailing 4-byte checksum does not match.
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#Decode>
 pub fn decode_hex_with_checksum(d: &[u8]) -> io::Result<Vec<u8>> {
-    let decoded = match hex::decode(d) {
+    let d = d.strip_prefix(b"0x").unwrap_or(d);
+    let mut decoded = match hex::decode(d) {
         Ok(v) => v,
         Err(e) => {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                format!("failed to decode base58 ({})", e),
+                format!("failed to decode hex ({})", e),
             ));
         }
     };
-    let decoded_length = decoded.len();
-
-    // verify checksum
-    let checksum = &decoded[decoded_length - CHECKSUM_LENGTH..];
-    let orig = &decoded[..decoded_length - CHECKSUM_LENGTH];
-
-    // "hashing.Checksum" of "sha256.Sum256"
-    let orig_checksum = hash::sha256(orig);
-    let orig_checksum_length = orig_checksum.len();
-    let orig_checksum = &orig_checksum[orig_checksum_length - CHECKSUM_LENGTH..];
-    if !cmp_manager::eq_vectors(checksum, orig_checksum) {
-        return Err(Error::new(
-            ErrorKind::InvalidInput,
-            format!("invalid checksum {:?} != {:?}", checksum, orig_checksum),
-        ));
-    }
-
-    Ok(orig.to_vec())
+    let orig_length = verify_checksum(&decoded)?;
+    decoded.truncate(orig_length);
+    Ok(decoded)
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_encode_hex_with_checksum --exact --show-output
@@ -191,6 +403,60 @@ fn test_encode_hex_with_checksum() {
     assert_eq!(d, decoded);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_decode_hex_with_checksum --exact --show-output
+#[test]
+fn test_decode_hex_with_checksum() {
+    // "0x"-prefixed UTXO from the avalanchego "platform.getUTXOs" API
+    let utxo = "0x000000000000000000000000000000000000000000000000000000000000000000000000000088eec2e099c6a528e689618e8721e04ae85ea574c7a15a7968644d14d54780140000000702c68af0bb1400000000000000000000000000010000000165844a05405f3662c1928142c6c2a783ef871de939b564db";
+    let decoded = decode_hex_with_checksum(utxo.as_bytes()).unwrap();
+    assert_eq!(decoded.len(), (utxo.len() - 2) / 2 - CHECKSUM_LENGTH);
+    assert_eq!(format!("0x{}", encode_hex_with_checksum(&decoded)), utxo);
+    assert_eq!(
+        decode_hex_with_checksum(utxo.trim_start_matches("0x").as_bytes()).unwrap(),
+        decoded
+    );
+
+    // checksum mismatch
+    let mut tampered = utxo.to_string();
+    tampered.replace_range(tampered.len() - 2.., "dc");
+    assert!(decode_hex_with_checksum(tampered.as_bytes()).is_err());
+    let mut tampered = utxo.to_string();
+    tampered.replace_range(4..6, "01");
+    assert!(decode_hex_with_checksum(tampered.as_bytes()).is_err());
+
+    // malformed hex or too short for the checksum
+    assert!(decode_hex_with_checksum(b"0x7852b85").is_err());
+    assert!(decode_hex_with_checksum(b"0xzz52b855").is_err());
+    assert!(decode_hex_with_checksum(b"0x7852b8").is_err());
+    assert!(decode_hex_with_checksum(b"0x7852b855").unwrap().is_empty());
+}
+
+/// Fails if the bech32 "human readable part" (HRP) is not 1 to 83 lowercase
+/// characters in the US-ASCII range [33, 126] (e.g., "custom" or "local").
+/// Uppercase HRPs are rejected, since avalanchego always uses the lowercase form.
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki#bech32>
+pub fn check_hrp(hrp: &str) -> io::Result<()> {
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid HRP length {} (expected 1 to 83)", hrp.len()),
+        ));
+    }
+    if let Some(c) = hrp
+        .chars()
+        .find(|c| !(33..=126).contains(&(*c as u32)) || c.is_ascii_uppercase())
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "invalid HRP character {:?} in '{}' (expected lowercase US-ASCII)",
+                c, hrp
+            ),
+        ));
+    }
+    Ok(())
+}
+
 /// Implements "formatting.FormatAddress/FormatBech32".
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#FormatAddress>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#FormatBech32>
@@ -210,3 +476,102 @@ pub fn address(chain_id_alias: &str, hrp: &str, d: &[u8]) -> io::Result<String>
     };
     Ok(format!("{}-{}", chain_id_alias, encoded))
 }
+
+/// Converts the hex-encoded 20-byte address (with or without "0x") to the
+/// EIP-55 mixed-case checksummed form, with the "0x" prefix.
+/// Fails if the input is not 20-byte hex.
+/// ref. <https://eips.ethereum.org/EIPS/eip-55>
+/// ref. "key::secp256k1::address::h160_to_eth_address"
+pub fn eip55_checksum(addr: &str) -> io::Result<String> {
+    let b = hex::decode(addr.strip_prefix("0x").unwrap_or(addr)).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("failed to decode hex address ({})", e),
+        )
+    })?;
+    if b.len() != 20 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("wrong address length {} (expected 20)", b.len()),
+        ));
+    }
+    Ok(crate::key::secp256k1::address::h160_to_eth_address(
+        &primitive_types::H160::from_slice(&b),
+        None,
+    ))
+}
+
+/// Returns true if the address is 20-byte hex (with or without "0x") and its
+/// mixed-case letters match the EIP-55 checksum (e.g., copied from Etherscan).
+/// All lower-cased or all upper-cased addresses carry no checksum, and are
+/// only valid if that is their checksummed form.
+/// ref. <https://eips.ethereum.org/EIPS/eip-55>
+pub fn is_valid_eip55(addr: &str) -> bool {
+    let hex_addr = addr.strip_prefix("0x").unwrap_or(addr);
+    match eip55_checksum(hex_addr) {
+        Ok(checksummed) => checksummed[2..] == *hex_addr,
+        Err(_) => false,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_eip55 --exact --show-output
+#[test]
+fn test_eip55() {
+    // ref. <https://eips.ethereum.org/EIPS/eip-55#test-cases>
+    for addr in [
+        // all caps
+        "0x52908400098527886E0F7030069857D2E4169EE7",
+        "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+        // all lower
+        "0xde709f2102306220921060314715629080e2fb77",
+        "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        // normal
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        assert_eq!(eip55_checksum(addr).unwrap(), addr);
+        assert_eq!(eip55_checksum(&addr.to_lowercase()).unwrap(), addr);
+        assert_eq!(eip55_checksum(&addr[2..].to_uppercase()).unwrap(), addr);
+        assert!(is_valid_eip55(addr));
+        assert!(is_valid_eip55(&addr[2..]));
+    }
+
+    // wrong case in a single letter
+    assert!(!is_valid_eip55(
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+    ));
+    assert!(!is_valid_eip55(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+    ));
+    // wrong length or not hex
+    assert!(!is_valid_eip55("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"));
+    assert!(!is_valid_eip55(
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAedAA"
+    ));
+    assert!(!is_valid_eip55(
+        "0xgaAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    ));
+    assert!(!is_valid_eip55(""));
+    assert!(eip55_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").is_err());
+    assert!(eip55_checksum("0xgaAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_check_hrp --exact --show-output
+#[test]
+fn test_check_hrp() {
+    for hrp in ["avax", "fuji", "custom", "local", "a", "my-subnet_1!"] {
+        assert!(check_hrp(hrp).is_ok(), "{}", hrp);
+    }
+    for hrp in [
+        "",
+        "Custom",
+        "LOCAL",
+        "has space",
+        "caf\u{e9}",
+        &"a".repeat(84),
+    ] {
+        assert!(check_hrp(hrp).is_err(), "{}", hrp);
+    }
+}
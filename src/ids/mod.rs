@@ -21,10 +21,10 @@ use std::{
 };
 
 use crate::{formatting, hash, packer};
+use ic_stable_memory::derive::{AsFixedSizeBytes, StableType};
 use lazy_static::lazy_static;
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use zerocopy::{AsBytes, FromBytes, Unaligned};
-use ic_stable_memory::derive::{AsFixedSizeBytes, StableType};
 
 pub const LEN: usize = 32;
 
@@ -144,6 +144,12 @@ impl FromStr for Id {
                 format!("failed decode_cb58_with_checksum '{}'", e),
             )
         })?;
+        if decoded.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid id length {} (expected {})", decoded.len(), LEN),
+            ));
+        }
         Ok(Self::from_slice(&decoded))
     }
 }
@@ -371,6 +377,12 @@ fn test_id() {
     let id_from_str = Id::from_str("TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES").unwrap();
     assert_eq!(id, id_from_str);
 
+    let parsed: Id = id.to_string().parse().unwrap();
+    assert_eq!(id, parsed);
+
+    // 20-byte short id is not an ids::Id
+    assert!("6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx".parse::<Id>().is_err());
+
     let id = Id::from_slice(&<Vec<u8>>::from([
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
@@ -118,6 +118,12 @@ impl FromStr for Id {
                 format!("failed decode_cb58_with_checksum '{}'", e),
             )
         })?;
+        if decoded.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid id length {} (expected {})", decoded.len(), LEN),
+            ));
+        }
         Ok(Self::from_slice(&decoded))
     }
 }
@@ -262,6 +268,28 @@ fn test_serialize() {
     assert_eq!(d, json_decoded);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ids::node::test_from_str --exact --show-output
+#[test]
+fn test_from_str() {
+    let id = Id::from_slice(&<Vec<u8>>::from([
+        0x3d, 0x0a, 0xd1, 0x2b, 0x8e, 0xe8, 0x92, 0x8e, 0xdf, 0x24, //
+        0x8c, 0xa9, 0x1c, 0xa5, 0x56, 0x00, 0xfb, 0x38, 0x3f, 0x07, //
+    ]));
+    let parsed: Id = "NodeID-6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx".parse().unwrap();
+    assert_eq!(id, parsed);
+    assert_eq!(id, id.to_string().parse().unwrap());
+
+    // the prefix is optional
+    let parsed: Id = "6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx".parse().unwrap();
+    assert_eq!(id, parsed);
+
+    // 32-byte ids::Id is not a node id
+    assert!("NodeID-TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES"
+        .parse::<Id>()
+        .is_err());
+    assert!("NodeID-".parse::<Id>().is_err());
+}
+
 /// Set is a set of NodeIds
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#NewNodeIDSet>
 pub type Set = HashSet<Id>;
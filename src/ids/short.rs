@@ -6,7 +6,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::{formatting, hash, key::secp256k1};
+use crate::{constants, formatting, hash, key::secp256k1};
 use lazy_static::lazy_static;
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use zerocopy::{AsBytes, FromBytes, Unaligned};
@@ -65,6 +65,78 @@ impl Id {
         let encoded = formatting::encode_cb58_with_checksum_string(&hashed);
         Self::from_str(&encoded)
     }
+
+    /// Parses the bech32 chain address (e.g., "X-avax1...") back to the short id,
+    /// and returns the network Id, the chain alias, and the short id.
+    /// Rejects the mixed-case or unknown HRP addresses.
+    /// The "custom" HRP maps to "constants::DEFAULT_CUSTOM_NETWORK_ID",
+    /// since the address does not encode the actual custom network Id.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting/address#Parse>
+    pub fn from_hrp_address(addr: &str) -> io::Result<(u32, String, Self)> {
+        let addr = addr.trim();
+        let (chain_alias, encoded) = addr.split_once('-').ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("no chain alias separator '-' in address '{}'", addr),
+            )
+        })?;
+        if chain_alias.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("empty chain alias in address '{}'", addr),
+            ));
+        }
+
+        let (network_id, id) = Self::from_bech32(encoded)?;
+        Ok((network_id, chain_alias.to_string(), id))
+    }
+
+    /// Same as "from_hrp_address" but for the bech32 address without
+    /// the chain alias (e.g., "avax1..."), and returns the network Id
+    /// and the short id.
+    pub fn from_bech32(addr: &str) -> io::Result<(u32, Self)> {
+        let addr = addr.trim();
+
+        // fails on invalid checksum or mixed-case
+        let (hrp, data, variant) = bech32::decode(addr).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed bech32::decode '{}' ({})", addr, e),
+            )
+        })?;
+        if variant != bech32::Variant::Bech32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unexpected bech32 variant {:?} in '{}'", variant, addr),
+            ));
+        }
+
+        let network_id = match constants::HRP_TO_NETWORK_ID.get(hrp.as_str()) {
+            Some(network_id) => *network_id,
+            None if hrp == constants::FALLBACK_HRP => constants::DEFAULT_CUSTOM_NETWORK_ID,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("unknown HRP '{}' in address '{}'", hrp, addr),
+                ))
+            }
+        };
+
+        let d = bech32::convert_bits(&data, 5, 8, false).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed bech32::convert_bits '{}'", e),
+            )
+        })?;
+        if d.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid short id length {} (expected {})", d.len(), LEN),
+            ));
+        }
+
+        Ok((network_id, Self::from_slice(&d)))
+    }
 }
 
 impl AsRef<[u8]> for Id {
@@ -94,6 +166,12 @@ impl FromStr for Id {
                 format!("failed decode_cb58_with_checksum '{}'", e),
             )
         })?;
+        if decoded.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid id length {} (expected {})", decoded.len(), LEN),
+            ));
+        }
         Ok(Self::from_slice(&decoded))
     }
 }
@@ -248,7 +326,7 @@ fn test_serialize() {
     let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
     let pubkey = pk.to_public_key();
     let short_addr = pubkey.to_short_id().unwrap();
-    let p_addr = pubkey.to_hrp_address(1, "P").unwrap();
+    let p_addr = pubkey.to_hrp_address(1u32, "P").unwrap();
 
     let d1: Data = serde_json::from_str(
         format!(
@@ -283,6 +361,15 @@ fn test_id() {
     assert_eq!(id.to_string(), "6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx");
     let id_from_str = Id::from_str("6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx").unwrap();
     assert_eq!(id, id_from_str);
+
+    let parsed: Id = id.to_string().parse().unwrap();
+    assert_eq!(id, parsed);
+
+    // 32-byte ids::Id is not a short id
+    assert!("TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES"
+        .parse::<Id>()
+        .is_err());
+    assert!("6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwy".parse::<Id>().is_err());
 }
 
 impl Ord for Id {
@@ -350,6 +437,60 @@ impl PartialEq for Ids {
     }
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ids::short::test_from_hrp_address --exact --show-output
+#[test]
+fn test_from_hrp_address() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+    let short_id = pubkey.to_short_id().unwrap();
+
+    for (network_id, chain_alias) in [(1, "X"), (5, "P"), (12345, "C")] {
+        let addr = pubkey.to_hrp_address(network_id, chain_alias).unwrap();
+        let (parsed_network_id, parsed_alias, parsed) = Id::from_hrp_address(&addr).unwrap();
+        assert_eq!(parsed_network_id, network_id);
+        assert_eq!(parsed_alias, chain_alias);
+        assert_eq!(parsed, short_id);
+    }
+
+    let addr = pubkey
+        .to_hrp_address(constants::DEFAULT_CUSTOM_NETWORK_ID, "P")
+        .unwrap();
+    let (network_id, _, parsed) = Id::from_hrp_address(&addr).unwrap();
+    assert_eq!(network_id, constants::DEFAULT_CUSTOM_NETWORK_ID);
+    assert_eq!(parsed, short_id);
+
+    // mixed-case
+    let addr = pubkey.to_hrp_address(1u32, "X").unwrap();
+    let (alias, encoded) = addr.split_once('-').unwrap();
+    let mixed = format!("{}{}", encoded[..1].to_uppercase(), &encoded[1..]);
+    assert!(Id::from_hrp_address(&format!("{alias}-{mixed}")).is_err());
+
+    // upper-case is valid bech32
+    let upper = format!("{alias}-{}", encoded.to_uppercase());
+    assert_eq!(Id::from_hrp_address(&upper).unwrap().2, short_id);
+
+    // bad checksum
+    let mut bad = addr.clone();
+    let last = if bad.ends_with('q') { "p" } else { "q" };
+    bad.replace_range(bad.len() - 1.., last);
+    assert!(Id::from_hrp_address(&bad).is_err());
+
+    // unknown HRP
+    let unknown = formatting::address("X", "avaxx", short_id.as_ref()).unwrap();
+    assert!(Id::from_hrp_address(&unknown).is_err());
+
+    // no chain alias
+    assert!(Id::from_hrp_address(encoded).is_err());
+    assert_eq!(Id::from_bech32(encoded).unwrap(), (1, short_id));
+    assert!(Id::from_bech32(&addr).is_err());
+    assert!(Id::from_bech32(unknown.trim_start_matches("X-")).is_err());
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- ids::short::test_sort --exact --show-output
 #[test]
 fn test_sort() {
@@ -250,6 +250,14 @@ pub struct GetUtxosParams {
     pub addresses: Vec<String>,
     pub limit: u32,
     pub encoding: String,
+
+    /// Pagination cursor, the "endIndex" of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<super::EndIndex>,
+    /// Set to fetch the atomic UTXOs exported from the source chain
+    /// (e.g., "P"), which are consumed by the import transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_chain: Option<String>,
 }
 
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
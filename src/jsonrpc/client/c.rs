@@ -0,0 +1,150 @@
+//! C-chain "avax" API for the atomic transactions (e.g., import and export),
+//! served on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+//! Use "jsonrpc::client::evm" for the "eth" API.
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+    time::Duration,
+};
+
+use crate::jsonrpc::{self, avm};
+use reqwest::{header::CONTENT_TYPE, ClientBuilder};
+
+/// e.g., "avax.issueTx" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+pub async fn issue_tx(http_rpc: &str, tx: &str) -> io::Result<avm::IssueTxResponse> {
+    log::debug!("issuing an atomic transaction via {http_rpc}/ext/bc/C/avax");
+
+    let mut data = avm::IssueTxRequest::default();
+    data.method = String::from("avax.issueTx");
+    let params = avm::IssueTxParams {
+        tx: prefix_manager::prepend_0x(tx),
+        encoding: String::from("hex"), // don't use "cb58"
+    };
+    data.params = Some(params);
+    let d = data.encode_json()?;
+
+    let out = post(http_rpc, d).await?;
+    serde_json::from_slice(&out)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed avax.issueTx '{}'", e)))
+}
+
+/// e.g., "avax.getAtomicTxStatus" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictxstatus>
+pub async fn get_atomic_tx_status(
+    http_rpc: &str,
+    tx_id: &str,
+) -> io::Result<avm::GetTxStatusResponse> {
+    log::debug!("getting atomic tx status via {http_rpc}/ext/bc/C/avax");
+
+    let mut data = jsonrpc::Request::default();
+    data.method = String::from("avax.getAtomicTxStatus");
+    let mut params = HashMap::new();
+    params.insert(String::from("txID"), String::from(tx_id));
+    data.params = Some(params);
+    let d = data.encode_json()?;
+
+    let out = post(http_rpc, d).await?;
+    serde_json::from_slice(&out).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed avax.getAtomicTxStatus '{}'", e),
+        )
+    })
+}
+
+/// e.g., "avax.getUTXOs" with "sourceChain" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+/// Fetches all pages of the atomic UTXOs exported from the source chain (e.g., "P")
+/// to the "C-" addresses, which are consumed by the C-chain import transaction.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+pub async fn get_atomic_utxos(
+    http_rpc: &str,
+    addresses: &[String],
+    source_chain: &str,
+) -> io::Result<avm::GetUtxosResponse> {
+    let limit = super::x::MAX_UTXOS_LIMIT;
+    let (mut utxos, mut start_index) = (Vec::new(), None);
+    loop {
+        log::debug!(
+            "getting atomic UTXOs for {:?} from {:?} (source chain {}) via {http_rpc}/ext/bc/C/avax",
+            addresses,
+            start_index,
+            source_chain
+        );
+
+        let mut data = avm::GetUtxosRequest::default();
+        data.method = String::from("avax.getUTXOs");
+        data.params = Some(avm::GetUtxosParams {
+            addresses: addresses.to_vec(),
+            limit,
+            encoding: String::from("hex"), // don't use "cb58"
+            start_index: start_index.clone(),
+            source_chain: Some(source_chain.to_string()),
+        });
+        let d = data.encode_json()?;
+
+        let out = post(http_rpc, d).await?;
+        let resp: avm::GetUtxosResponse = serde_json::from_slice(&out)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed avax.getUTXOs '{}'", e)))?;
+        if let Some(e) = resp.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("failed avax.getUTXOs '{}'", e.message),
+            ));
+        }
+        let result = resp
+            .result
+            .ok_or_else(|| Error::new(ErrorKind::Other, "unexpected None GetUtxosResult"))?;
+
+        utxos.extend(result.utxos.unwrap_or_default());
+        // the last page has fewer UTXOs than the limit
+        let done = result.num_fetched < limit || result.end_index.is_none();
+        if result.end_index.is_some() {
+            start_index = result.end_index;
+        }
+        if done {
+            break;
+        }
+    }
+
+    Ok(avm::GetUtxosResponse {
+        jsonrpc: String::from(jsonrpc::DEFAULT_VERSION),
+        id: jsonrpc::DEFAULT_ID,
+        result: Some(avm::GetUtxosResult {
+            num_fetched: utxos.len() as u32,
+            utxos: Some(utxos),
+            end_index: start_index,
+            encoding: Some(String::from("hex")),
+        }),
+        error: None,
+    })
+}
+
+async fn post(http_rpc: &str, d: String) -> io::Result<Vec<u8>> {
+    let req_cli_builder = ClientBuilder::new()
+        .user_agent(env!("CARGO_PKG_NAME"))
+        .danger_accept_invalid_certs(true)
+        .timeout(Duration::from_secs(15))
+        .connection_verbose(true)
+        .build()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed ClientBuilder build {}", e),
+            )
+        })?;
+    let resp = req_cli_builder
+        .post(format!("{http_rpc}/ext/bc/C/avax").as_str())
+        .header(CONTENT_TYPE, "application/json")
+        .body(d)
+        .send()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed ClientBuilder send {}", e)))?;
+    let out = resp.bytes().await.map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed ClientBuilder bytes {}", e),
+        )
+    })?;
+    Ok(out.into())
+}
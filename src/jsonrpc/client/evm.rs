@@ -3,8 +3,15 @@ use std::{
     time::Duration,
 };
 
-use ethers_providers::{Http, Middleware, Provider};
-use primitive_types::{H160, U256};
+use ethers_core::types::{
+    transaction::eip2718::TypedTransaction, BlockId, BlockNumber, FeeHistory, Filter, Log,
+    TransactionReceipt, TransactionRequest,
+};
+use ethers_providers::{Http, Middleware, Provider, ProviderError};
+use primitive_types::{H160, H256, U256};
+use reqwest::{header::CONTENT_TYPE, ClientBuilder};
+
+use crate::jsonrpc::common::{Request, Response};
 
 /// Fetches the chain Id from "{http_rpc}/ext/bc/{chain_id_alias}/rpc".
 /// "chain_id_alias" is "C" for C-chain, and blockchain Id for subnet-evm.
@@ -25,10 +32,14 @@ pub async fn chain_id(rpc_ep: &str) -> io::Result<U256> {
         .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_chainid '{}'", e)))
 }
 
-/// Fetches the balance from "{http_rpc}/ext/bc/{chain_id_alias}/rpc".
+/// Fetches the balance from "{http_rpc}/ext/bc/{chain_id_alias}/rpc" via "eth_getBalance".
 /// "chain_id_alias" is "C" for C-chain, and blockchain Id for subnet-evm.
+/// The "block" defaults to "latest" if "None", and can be "pending"
+/// (e.g., "BlockNumber::Pending.into()") or an explicit block number
+/// (e.g., "BlockNumber::Number(100.into()).into()").
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_getbalance>
 /// ref. <https://docs.avax.network/build/avalanchego-apis/c-chain#eth_getassetbalance>
-pub async fn get_balance(rpc_ep: &str, eth_addr: H160) -> io::Result<U256> {
+pub async fn get_balance(rpc_ep: &str, eth_addr: H160, block: Option<BlockId>) -> io::Result<U256> {
     let provider = Provider::<Http>::try_from(rpc_ep)
         .map_err(|e| {
             Error::new(
@@ -38,9 +49,759 @@ pub async fn get_balance(rpc_ep: &str, eth_addr: H160) -> io::Result<U256> {
         })?
         .interval(Duration::from_millis(2000u64));
 
-    log::info!("getting balances for {} via {rpc_ep}", eth_addr);
+    log::info!(
+        "getting balances for {} at {:?} via {rpc_ep}",
+        eth_addr,
+        block
+    );
     provider
-        .get_balance(eth_addr, None)
+        .get_balance(eth_addr, block)
         .await
         .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_balance '{}'", e)))
 }
+
+/// Fetches the balances of the addresses at the "latest" block, in the same order
+/// as "addresses", with one JSON-RPC batch of "eth_getBalance" requests
+/// (an array of requests in one HTTP call) instead of one call per address.
+/// Falls back to "get_balance" for each address if the node rejects the batch
+/// (e.g., the batch larger than "api-max-batch-size" of coreth).
+/// ref. <https://www.jsonrpc.org/specification#batch>
+pub async fn get_balances(rpc_ep: &str, addresses: &[H160]) -> io::Result<Vec<U256>> {
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+    log::info!(
+        "getting balances for {} addresses in batch via {rpc_ep}",
+        addresses.len()
+    );
+
+    let reqs: Vec<Request<(H160, &str)>> = addresses
+        .iter()
+        .enumerate()
+        .map(|(i, addr)| Request::new(i as u64, "eth_getBalance", (*addr, "latest")))
+        .collect();
+    let d = serde_json::to_vec(&reqs).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("failed serde_json::to_vec '{}'", e),
+        )
+    })?;
+
+    let req_cli_builder = ClientBuilder::new()
+        .user_agent(env!("CARGO_PKG_NAME"))
+        .timeout(Duration::from_secs(15))
+        .build()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed ClientBuilder build {}", e),
+            )
+        })?;
+    let resp = req_cli_builder
+        .post(rpc_ep)
+        .header(CONTENT_TYPE, "application/json")
+        .body(d)
+        .send()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed ClientBuilder send {}", e)))?;
+    let status = resp.status();
+    let out = resp.bytes().await.map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed ClientBuilder bytes {}", e),
+        )
+    })?;
+
+    let decoded = if status.is_success() {
+        decode_balances_batch(&out, addresses.len())
+    } else {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("batch request failed with status {}", status),
+        ))
+    };
+    match decoded {
+        Err(e) if e.kind() == ErrorKind::Unsupported => {
+            log::warn!("batch rejected ({}), getting balances one by one", e);
+            let mut balances = Vec::with_capacity(addresses.len());
+            for addr in addresses.iter() {
+                balances.push(get_balance(rpc_ep, *addr, None).await?);
+            }
+            Ok(balances)
+        }
+        res => res,
+    }
+}
+
+/// Decodes the batch response of "get_balances" into the balances ordered
+/// by the request Id, since the node may return the responses in any order.
+/// Fails with "ErrorKind::Unsupported" if the response is not an array
+/// (e.g., a single error object when the node rejects the batch).
+fn decode_balances_batch(out: &[u8], count: usize) -> io::Result<Vec<U256>> {
+    let value: serde_json::Value = serde_json::from_slice(out).map_err(|e| {
+        Error::new(
+            ErrorKind::Unsupported,
+            format!("failed to parse batch response '{}'", e),
+        )
+    })?;
+    if !value.is_array() {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("batch response is not an array: {}", value),
+        ));
+    }
+    let resps: Vec<Response<U256>> = serde_json::from_value(value).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to decode batch response '{}'", e),
+        )
+    })?;
+    if resps.len() != count {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("expected {} responses, got {}", count, resps.len()),
+        ));
+    }
+
+    let mut balances: Vec<Option<U256>> = vec![None; count];
+    for resp in resps {
+        let id = resp.id;
+        let slot = balances.get_mut(id as usize).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("unexpected response id {}", id),
+            )
+        })?;
+        if slot.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("duplicate response id {}", id),
+            ));
+        }
+        let balance = resp.data.into_result().map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed eth_getBalance for request {} '{}'", id, e),
+            )
+        })?;
+        *slot = Some(balance);
+    }
+
+    // all slots are filled, given the same count without duplicates
+    Ok(balances.into_iter().flatten().collect())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::evm::test_decode_balances_batch --exact --show-output
+#[test]
+fn test_decode_balances_batch() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let addr = H160::from_low_u64_be(1);
+    let reqs = vec![
+        Request::new(0, "eth_getBalance", (addr, "latest")),
+        Request::new(1, "eth_getBalance", (addr, "latest")),
+    ];
+    assert_eq!(
+        serde_json::to_string(&reqs).unwrap(),
+        r#"[{"id":0,"jsonrpc":"2.0","method":"eth_getBalance","params":["0x0000000000000000000000000000000000000001","latest"]},{"id":1,"jsonrpc":"2.0","method":"eth_getBalance","params":["0x0000000000000000000000000000000000000001","latest"]}]"#
+    );
+
+    // out of order
+    let out = br#"[
+    {"jsonrpc":"2.0","id":2,"result":"0x3"},
+    {"jsonrpc":"2.0","id":0,"result":"0x1"},
+    {"jsonrpc":"2.0","id":1,"result":"0xde0b6b3a7640000"}
+]"#;
+    assert_eq!(
+        decode_balances_batch(out, 3).unwrap(),
+        vec![
+            U256::from(1),
+            U256::from(1_000_000_000_000_000_000_u64),
+            U256::from(3)
+        ]
+    );
+
+    // batch rejected
+    let out = br#"{"jsonrpc":"2.0","id":null,"error":{"code":-32600,"message":"batch too large"}}"#;
+    assert_eq!(
+        decode_balances_batch(out, 3).unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(
+        decode_balances_batch(b"<html>", 3).unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+
+    // error for one of the requests
+    let out = br#"[
+    {"jsonrpc":"2.0","id":1,"result":"0x1"},
+    {"jsonrpc":"2.0","id":0,"error":{"code":-32000,"message":"header not found"}}
+]"#;
+    let err = decode_balances_batch(out, 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert!(err.to_string().contains("header not found"));
+
+    // missing, duplicate, or unknown ids
+    let out = br#"[{"jsonrpc":"2.0","id":0,"result":"0x1"}]"#;
+    assert!(decode_balances_batch(out, 2).is_err());
+    let out =
+        br#"[{"jsonrpc":"2.0","id":0,"result":"0x1"},{"jsonrpc":"2.0","id":0,"result":"0x2"}]"#;
+    assert!(decode_balances_batch(out, 2).is_err());
+    let out =
+        br#"[{"jsonrpc":"2.0","id":0,"result":"0x1"},{"jsonrpc":"2.0","id":5,"result":"0x2"}]"#;
+    assert!(decode_balances_batch(out, 2).is_err());
+}
+
+/// Fetches the transaction count (nonce) from "{http_rpc}/ext/bc/{chain_id_alias}/rpc"
+/// via "eth_getTransactionCount", at the "pending" block if "pending" is true
+/// (including the transactions in the mempool), otherwise at the "latest" block.
+/// Use the "pending" nonce for the next transaction to send.
+/// The address length is guaranteed by the "H160" type.
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactioncount>
+pub async fn get_transaction_count(
+    rpc_ep: &str,
+    eth_addr: H160,
+    pending: bool,
+) -> io::Result<U256> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    let block = if pending {
+        BlockNumber::Pending
+    } else {
+        BlockNumber::Latest
+    };
+    log::info!(
+        "getting transaction count for {} at {} via {rpc_ep}",
+        eth_addr,
+        block
+    );
+    provider
+        .get_transaction_count(eth_addr, Some(block.into()))
+        .await
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed get_transaction_count '{}'", e),
+            )
+        })
+}
+
+/// Fetches the transaction receipt from "{http_rpc}/ext/bc/{chain_id_alias}/rpc"
+/// via "eth_getTransactionReceipt". Returns "None" if the transaction is still
+/// pending (or unknown). Use "receipt_status" for the execution result,
+/// and "logs" field for the emitted events (e.g., "evm::abi::decode_event").
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactionreceipt>
+pub async fn get_transaction_receipt(
+    rpc_ep: &str,
+    tx_hash: H256,
+) -> io::Result<Option<TransactionReceipt>> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!(
+        "getting transaction receipt for 0x{:x} via {rpc_ep}",
+        tx_hash
+    );
+    provider
+        .get_transaction_receipt(tx_hash)
+        .await
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed get_transaction_receipt '{}'", e),
+            )
+        })
+}
+
+/// Returns true if the transaction execution succeeded ("status" 1),
+/// false if reverted ("status" 0), or "None" if the receipt has no status
+/// (pre-Byzantium receipts only have the state root).
+/// ref. <https://eips.ethereum.org/EIPS/eip-658>
+pub fn receipt_status(receipt: &TransactionReceipt) -> Option<bool> {
+    receipt.status.map(|s| s.as_u64() == 1)
+}
+
+/// Filter for "eth_getLogs".
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_getlogs>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Defaults to "latest" if "None".
+    pub from_block: Option<BlockNumber>,
+    /// Defaults to "latest" if "None".
+    pub to_block: Option<BlockNumber>,
+    /// Contract address that emitted the logs, any if "None".
+    pub address: Option<H160>,
+    /// Positional topics (up to 4), where "None" matches any topic.
+    /// The first topic is the event signature hash for non-anonymous events.
+    pub topics: Vec<Option<H256>>,
+}
+
+impl LogFilter {
+    /// Converts to the "eth_getLogs" params.
+    pub fn to_filter(&self) -> io::Result<Filter> {
+        if self.topics.len() > 4 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("too many topics {} (max 4)", self.topics.len()),
+            ));
+        }
+
+        let mut filter = Filter::new();
+        if let Some(from_block) = self.from_block {
+            filter = filter.from_block(from_block);
+        }
+        if let Some(to_block) = self.to_block {
+            filter = filter.to_block(to_block);
+        }
+        if let Some(address) = self.address {
+            filter = filter.address(address);
+        }
+        for (i, topic) in self.topics.iter().enumerate() {
+            if let Some(topic) = topic {
+                filter.topics[i] = Some((*topic).into());
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// Fetches the logs matching the filter from "{http_rpc}/ext/bc/{chain_id_alias}/rpc"
+/// via "eth_getLogs".
+/// Nodes limit the block range (e.g., coreth "api-max-blocks-per-request")
+/// or the number of results per request. Such rejections are returned
+/// as "ErrorKind::InvalidInput", so callers can split the range and retry.
+pub async fn get_logs(rpc_ep: &str, filter: LogFilter) -> io::Result<Vec<Log>> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    let filter = filter.to_filter()?;
+    log::info!("getting logs for {:?} via {rpc_ep}", filter);
+    provider.get_logs(&filter).await.map_err(|e| {
+        let msg = e.to_string();
+        if is_range_limit_error(&msg) {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "get_logs range too wide, split into smaller ranges '{}'",
+                    msg
+                ),
+            )
+        } else {
+            Error::new(ErrorKind::Other, format!("failed get_logs '{}'", msg))
+        }
+    })
+}
+
+/// Returns true if the node rejected "eth_getLogs" for its block range
+/// or result size limit.
+fn is_range_limit_error(msg: &str) -> bool {
+    let msg = msg.to_lowercase();
+    [
+        // coreth "requested too many blocks from 0 to 5000, maximum is set to 2048"
+        "too many blocks",
+        // geth "query returned more than 10000 results"
+        "query returned more than",
+        "block range",
+        "range too large",
+        "limit exceeded",
+    ]
+    .iter()
+    .any(|s| msg.contains(s))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::evm::test_log_filter --exact --show-output
+#[test]
+fn test_log_filter() {
+    use std::str::FromStr;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let transfer_topic =
+        H256::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+            .unwrap();
+    let to_topic =
+        H256::from_str("0x0000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc")
+            .unwrap();
+    let filter = LogFilter {
+        from_block: Some(BlockNumber::Number(1.into())),
+        to_block: Some(BlockNumber::Latest),
+        address: Some(H160::from_str("0x5DB9A7629912EBF95876228C24A848de0bfB43A9").unwrap()),
+        topics: vec![Some(transfer_topic), None, Some(to_topic)],
+    };
+    assert_eq!(
+        serde_json::to_value(filter.to_filter().unwrap()).unwrap(),
+        serde_json::json!({
+            "fromBlock": "0x1",
+            "toBlock": "latest",
+            "address": "0x5db9a7629912ebf95876228c24a848de0bfb43a9",
+            "topics": [
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                null,
+                "0x0000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc"
+            ]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(LogFilter::default().to_filter().unwrap()).unwrap(),
+        serde_json::json!({ "topics": [] })
+    );
+
+    let filter = LogFilter {
+        topics: vec![None; 5],
+        ..Default::default()
+    };
+    assert!(filter.to_filter().is_err());
+
+    assert!(is_range_limit_error(
+        "(code: -32000, message: requested too many blocks from 0 to 5000, maximum is set to 2048, data: None)"
+    ));
+    assert!(is_range_limit_error(
+        "(code: -32005, message: query returned more than 10000 results, data: None)"
+    ));
+    assert!(!is_range_limit_error(
+        "(code: -32000, message: unknown block, data: None)"
+    ));
+}
+
+/// Calls the contract via "eth_call" at the "block" ("latest" if "None")
+/// from "{http_rpc}/ext/bc/{chain_id_alias}/rpc", and returns the raw return data
+/// (e.g., to decode with "evm::abi::decode_output").
+/// If the call reverts with the "Error(string)" reason, the error includes the reason.
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_call>
+pub async fn call(
+    rpc_ep: &str,
+    to: H160,
+    data: Vec<u8>,
+    block: Option<BlockId>,
+) -> io::Result<Vec<u8>> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    let tx: TypedTransaction = TransactionRequest::new().to(to).data(data).into();
+    log::info!("calling {} at {:?} via {rpc_ep}", to, block);
+    let output = provider
+        .call(&tx, block)
+        .await
+        .map_err(|e| match revert_reason(&e) {
+            Some(reason) => Error::new(ErrorKind::Other, format!("call reverted '{}'", reason)),
+            None => Error::new(ErrorKind::Other, format!("failed call '{}'", e)),
+        })?;
+    Ok(output.to_vec())
+}
+
+/// Returns the revert reason from the JSON-RPC error, if any
+/// (e.g., the "eth_call" or "eth_estimateGas" error on the reverted execution).
+/// Use "evm::revert::from_provider_error" for the structured reason.
+#[cfg(feature = "evm")]
+pub fn revert_reason(e: &ProviderError) -> Option<String> {
+    crate::evm::revert::from_provider_error(e).map(|reason| reason.to_string())
+}
+
+/// Returns the revert reason from the JSON-RPC error, if any
+/// (e.g., the "eth_call" or "eth_estimateGas" error on the reverted execution).
+#[cfg(not(feature = "evm"))]
+pub fn revert_reason(e: &ProviderError) -> Option<String> {
+    use ethers_providers::HttpClientError;
+
+    let ProviderError::JsonRpcClientError(err) = e else {
+        return None;
+    };
+    let HttpClientError::JsonRpcError(rpc_err) = err.downcast_ref::<HttpClientError>()? else {
+        return None;
+    };
+    revert_reason_from_rpc_error(&rpc_err.message, rpc_err.data.as_ref())
+}
+
+/// Decodes the "Error(string)" revert data (e.g., "0x08c379a0..."),
+/// or falls back to the message (e.g., "execution reverted: reason").
+/// Only for the builds without "evm", which has "evm::revert" instead.
+#[cfg(not(feature = "evm"))]
+fn revert_reason_from_rpc_error(message: &str, data: Option<&serde_json::Value>) -> Option<String> {
+    const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+    if let Some(data) = data.and_then(|d| d.as_str()) {
+        if let Ok(b) = hex::decode(data.trim_start_matches("0x")) {
+            if b.len() > 4 && b[..4] == ERROR_STRING_SELECTOR {
+                if let Ok(mut tokens) =
+                    ethers_core::abi::decode(&[ethers_core::abi::ParamType::String], &b[4..])
+                {
+                    if let Some(reason) = tokens.pop().and_then(|t| t.into_string()) {
+                        return Some(reason);
+                    }
+                }
+            }
+        }
+    }
+
+    if message.starts_with("execution reverted") {
+        return Some(message.to_string());
+    }
+    None
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::evm::test_revert_reason_from_rpc_error --exact --show-output
+#[cfg(not(feature = "evm"))]
+#[test]
+fn test_revert_reason_from_rpc_error() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // revert("Ownable: caller is not the owner")
+    let data = serde_json::json!("0x08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000204f776e61626c653a2063616c6c6572206973206e6f7420746865206f776e6572");
+    assert_eq!(
+        revert_reason_from_rpc_error("execution reverted", Some(&data)).unwrap(),
+        "Ownable: caller is not the owner"
+    );
+
+    // custom error falls back to the message
+    let data = serde_json::json!("0x82b42900");
+    assert_eq!(
+        revert_reason_from_rpc_error("execution reverted", Some(&data)).unwrap(),
+        "execution reverted"
+    );
+
+    assert!(revert_reason_from_rpc_error("out of gas", None).is_none());
+}
+
+/// Fetches the estimated base fee (in wei) of the next block via "eth_baseFee",
+/// which the C-chain atomic transactions pay for their gas.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#eth_basefee>
+pub async fn base_fee(rpc_ep: &str) -> io::Result<U256> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("getting base fee via {rpc_ep}");
+    provider
+        .request::<_, U256>("eth_baseFee", ())
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed eth_baseFee '{}'", e)))
+}
+
+/// Number of the most recent blocks to sample for "suggest_1559_fees".
+pub const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// Suggests the EIP-1559 "(maxFeePerGas, maxPriorityFeePerGas)" based on "eth_feeHistory"
+/// over the last "FEE_HISTORY_BLOCKS" blocks.
+///
+/// For each block, the node returns the priority fee paid at "reward_percentile"
+/// (0 to 100) of the gas used in the block, ordered by priority fee
+/// (e.g., 50 is the median tip weighted by gas, 90 outbids most transactions).
+/// The suggested tip is the median of those across the non-empty blocks,
+/// and the max fee is twice the next block base fee plus the tip,
+/// so the transaction stays valid even if the base fee keeps increasing for a few blocks.
+///
+/// For pre-London chains without the base fee (or without "eth_feeHistory"),
+/// falls back to "eth_gasPrice" for both.
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_feehistory>
+/// ref. <https://docs.avax.network/quickstart/adjusting-gas-price-during-high-network-activity>
+pub async fn suggest_1559_fees(rpc_ep: &str, reward_percentile: f64) -> io::Result<(U256, U256)> {
+    if !(0.0..=100.0).contains(&reward_percentile) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "reward percentile {} out of range [0, 100]",
+                reward_percentile
+            ),
+        ));
+    }
+
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to create provider '{}'", e),
+            )
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("getting fee history at {reward_percentile} percentile via {rpc_ep}");
+    match provider
+        .fee_history(
+            FEE_HISTORY_BLOCKS,
+            BlockNumber::Latest,
+            &[reward_percentile],
+        )
+        .await
+    {
+        Ok(history) => {
+            if let Some(fees) = compute_1559_fees(&history) {
+                return Ok(fees);
+            }
+            log::warn!("no base fee in fee history -- falling back to gas price");
+        }
+        Err(e) => {
+            log::warn!("failed fee_history '{}' -- falling back to gas price", e);
+        }
+    }
+
+    let gas_price = provider
+        .get_gas_price()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_gas_price '{}'", e)))?;
+    Ok((gas_price, gas_price))
+}
+
+/// Computes "(maxFeePerGas, maxPriorityFeePerGas)" from the fee history
+/// with a single reward percentile, or "None" if it has no base fee (pre-London).
+fn compute_1559_fees(history: &FeeHistory) -> Option<(U256, U256)> {
+    // the last element is the base fee of the next block
+    let base_fee = *history.base_fee_per_gas.last()?;
+    if base_fee.is_zero() {
+        return None;
+    }
+
+    // empty blocks report zero rewards
+    let mut rewards: Vec<U256> = history
+        .reward
+        .iter()
+        .filter_map(|r| r.first().copied())
+        .filter(|r| !r.is_zero())
+        .collect();
+    rewards.sort();
+    let tip = if rewards.is_empty() {
+        U256::zero()
+    } else {
+        rewards[rewards.len() / 2]
+    };
+
+    let max_fee = base_fee.checked_mul(U256::from(2))?.checked_add(tip)?;
+    Some((max_fee, tip))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::evm::test_compute_1559_fees --exact --show-output
+#[test]
+fn test_compute_1559_fees() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // 25, 26, 27 gwei base fees, and the next block base fee 28 gwei
+    let history: FeeHistory = serde_json::from_str(
+        r#"{
+    "oldestBlock": "0x10",
+    "baseFeePerGas": ["0x5d21dba00", "0x60db88400", "0x649534e00", "0x684ee1800"],
+    "gasUsedRatio": [0.5, 0.0, 0.9],
+    "reward": [["0x3b9aca00"], ["0x0"], ["0x77359400"]]
+}"#,
+    )
+    .unwrap();
+    let (max_fee, tip) = compute_1559_fees(&history).unwrap();
+
+    // median of non-empty blocks [1, 2] gwei
+    assert_eq!(tip, U256::from(2_000_000_000u64));
+    // 2 * 28 + 2 gwei
+    assert_eq!(max_fee, U256::from(58_000_000_000u64));
+
+    // odd number of samples
+    let mut history = history;
+    history.reward = vec![
+        vec![U256::from(3)],
+        vec![U256::from(1)],
+        vec![U256::from(2)],
+    ];
+    assert_eq!(compute_1559_fees(&history).unwrap().1, U256::from(2));
+
+    // all empty blocks
+    history.reward = vec![vec![U256::zero()]; 3];
+    let (max_fee, tip) = compute_1559_fees(&history).unwrap();
+    assert!(tip.is_zero());
+    assert_eq!(max_fee, U256::from(56_000_000_000u64));
+
+    // pre-London
+    history.base_fee_per_gas = vec![U256::zero(); 4];
+    assert!(compute_1559_fees(&history).is_none());
+    history.base_fee_per_gas.clear();
+    assert!(compute_1559_fees(&history).is_none());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::evm::test_receipt_status --exact --show-output
+#[test]
+fn test_receipt_status() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let receipt: TransactionReceipt = serde_json::from_str(
+        r#"{
+    "transactionHash": "0x31b977eff419b20c7f0e1c612530258e65cf51a38676b4c7930060ec3b9f10ee",
+    "transactionIndex": "0x0",
+    "blockHash": "0x8a0f7a3dc0fe1d0e5ef1a5b8e1a4f9c5b8a2b5d0e0c1f6a7f1c7c1b1d2e3f4a5",
+    "blockNumber": "0x10",
+    "from": "0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc",
+    "to": "0x5db9a7629912ebf95876228c24a848de0bfb43a9",
+    "cumulativeGasUsed": "0x6a5c",
+    "gasUsed": "0x6a5c",
+    "contractAddress": null,
+    "logs": [
+        {
+            "address": "0x5db9a7629912ebf95876228c24a848de0bfb43a9",
+            "topics": ["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"],
+            "data": "0x",
+            "blockNumber": "0x10",
+            "transactionHash": "0x31b977eff419b20c7f0e1c612530258e65cf51a38676b4c7930060ec3b9f10ee",
+            "transactionIndex": "0x0",
+            "blockHash": "0x8a0f7a3dc0fe1d0e5ef1a5b8e1a4f9c5b8a2b5d0e0c1f6a7f1c7c1b1d2e3f4a5",
+            "logIndex": "0x0",
+            "removed": false
+        }
+    ],
+    "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    "status": "0x1",
+    "type": "0x2",
+    "effectiveGasPrice": "0x5d21dba00"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(receipt_status(&receipt), Some(true));
+    assert_eq!(receipt.logs.len(), 1);
+    assert_eq!(receipt.gas_used, Some(U256::from(0x6a5c)));
+
+    let mut reverted = receipt.clone();
+    reverted.status = Some(0u64.into());
+    assert_eq!(receipt_status(&reverted), Some(false));
+
+    reverted.status = None;
+    assert_eq!(receipt_status(&reverted), None);
+}
@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    ids::node,
     jsonrpc::{self, info},
     utils,
 };
@@ -66,8 +67,10 @@ pub async fn get_network_name(http_rpc: &str) -> io::Result<info::GetNetworkName
 }
 
 /// e.g., "info.getNetworkID".
+/// Returns the network Id (e.g., 1 for mainnet) to be used for key addresses
+/// (e.g., "to_info(network_id)").
 /// ref. <https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkid>
-pub async fn get_network_id(http_rpc: &str) -> io::Result<info::GetNetworkIdResponse> {
+pub async fn get_network_id(http_rpc: &str) -> io::Result<u32> {
     let (scheme, host, port, _, _) =
         utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc)?;
     let u = if let Some(scheme) = scheme {
@@ -112,12 +115,19 @@ pub async fn get_network_id(http_rpc: &str) -> io::Result<info::GetNetworkIdResp
     })?;
     let out: Vec<u8> = out.into();
 
-    serde_json::from_slice(&out).map_err(|e| {
+    let resp: info::GetNetworkIdResponse = serde_json::from_slice(&out).map_err(|e| {
         Error::new(
             ErrorKind::Other,
             format!("failed info.getNetworkID '{}'", e),
         )
-    })
+    })?;
+    match resp.result {
+        Some(result) => Ok(result.network_id),
+        None => Err(Error::new(
+            ErrorKind::Other,
+            "no result in info.getNetworkID response",
+        )),
+    }
 }
 
 /// e.g., "info.getBlockchainID".
@@ -183,8 +193,10 @@ pub async fn get_blockchain_id(
 }
 
 /// e.g., "info.getNodeID".
+/// Returns the node Id and its hex-encoded BLS proof of possession,
+/// which is None for nodes that do not report "nodePOP".
 /// ref. <https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeid>
-pub async fn get_node_id(http_rpc: &str) -> io::Result<info::GetNodeIdResponse> {
+pub async fn get_node_id(http_rpc: &str) -> io::Result<(node::Id, Option<String>)> {
     let (scheme, host, port, _, _) =
         utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc)?;
     let u = if let Some(scheme) = scheme {
@@ -229,8 +241,18 @@ pub async fn get_node_id(http_rpc: &str) -> io::Result<info::GetNodeIdResponse>
     })?;
     let out: Vec<u8> = out.into();
 
-    serde_json::from_slice(&out)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed info.getNodeID '{}'", e)))
+    let resp: info::GetNodeIdResponse = serde_json::from_slice(&out)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed info.getNodeID '{}'", e)))?;
+    match resp.result {
+        Some(result) => Ok((
+            result.node_id,
+            result.node_pop.map(|pop| pop.proof_of_possession),
+        )),
+        None => Err(Error::new(
+            ErrorKind::Other,
+            "no result in info.getNodeID response",
+        )),
+    }
 }
 
 /// e.g., "info.getNodeVersion".
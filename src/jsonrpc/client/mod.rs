@@ -1,3 +1,4 @@
+pub mod c;
 pub mod evm;
 pub mod health;
 pub mod info;
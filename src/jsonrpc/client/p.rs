@@ -4,7 +4,10 @@ use std::{
     time::Duration,
 };
 
-use crate::jsonrpc::{self, platformvm};
+use crate::{
+    ids,
+    jsonrpc::{self, platformvm},
+};
 use reqwest::{header::CONTENT_TYPE, ClientBuilder};
 
 /// e.g., "platform.issueTx" on "http://[ADDR]:9650" and "/ext/P" path.
@@ -247,7 +250,31 @@ pub async fn get_balance(
 /// e.g., "platform.getUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
 /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos>
 pub async fn get_utxos(http_rpc: &str, paddr: &str) -> io::Result<platformvm::GetUtxosResponse> {
-    log::debug!("getting UTXOs for {} via {http_rpc}/ext/P", paddr);
+    get_utxos_with_source_chain(http_rpc, paddr, None).await
+}
+
+/// e.g., "platform.getUTXOs" with "sourceChain" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Fetches the atomic UTXOs exported from the source chain (e.g., "X")
+/// to the address, which are not returned by "get_utxos" until imported.
+/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos>
+pub async fn get_atomic_utxos(
+    http_rpc: &str,
+    paddr: &str,
+    source_chain: &str,
+) -> io::Result<platformvm::GetUtxosResponse> {
+    get_utxos_with_source_chain(http_rpc, paddr, Some(source_chain.to_string())).await
+}
+
+async fn get_utxos_with_source_chain(
+    http_rpc: &str,
+    paddr: &str,
+    source_chain: Option<String>,
+) -> io::Result<platformvm::GetUtxosResponse> {
+    log::debug!(
+        "getting UTXOs for {} (source chain {:?}) via {http_rpc}/ext/P",
+        paddr,
+        source_chain
+    );
 
     let mut data = platformvm::GetUtxosRequest::default();
     data.method = String::from("platform.getUTXOs");
@@ -255,6 +282,7 @@ pub async fn get_utxos(http_rpc: &str, paddr: &str) -> io::Result<platformvm::Ge
         addresses: vec![paddr.to_string()],
         limit: 100,
         encoding: String::from("hex"), // don't use "cb58"
+        source_chain,
     };
     data.params = Some(params);
     let d = data.encode_json()?;
@@ -396,3 +424,24 @@ pub async fn get_subnet_validators(
         )
     })
 }
+
+/// Fetches the current validators of the primary network (if "subnet_id" is None)
+/// or of the subnet via "platform.getCurrentValidators".
+/// Returns an empty list if the node reports no validator.
+/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
+pub async fn get_current_validators(
+    http_rpc: &str,
+    subnet_id: Option<ids::Id>,
+) -> io::Result<Vec<platformvm::ApiPrimaryValidator>> {
+    let resp = match subnet_id {
+        Some(subnet_id) => get_subnet_validators(http_rpc, &subnet_id.to_string()).await?,
+        None => get_primary_network_validators(http_rpc).await?,
+    };
+    let result = resp.result.ok_or_else(|| {
+        Error::new(
+            ErrorKind::Other,
+            "no result in platform.getCurrentValidators response",
+        )
+    })?;
+    Ok(result.validators.unwrap_or_default())
+}
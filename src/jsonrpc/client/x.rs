@@ -4,7 +4,10 @@ use std::{
     time::Duration,
 };
 
-use crate::jsonrpc::{self, avm};
+use crate::{
+    jsonrpc::{self, avm},
+    txs,
+};
 use reqwest::{header::CONTENT_TYPE, ClientBuilder};
 
 /// e.g., "avm.issueTx" on "http://[ADDR]:9650" and "/ext/bc/X" path.
@@ -193,18 +196,158 @@ pub async fn get_asset_description(
     })
 }
 
+/// Maximum number of UTXOs per "avm.getUTXOs" call.
+pub const MAX_UTXOS_LIMIT: u32 = 1024;
+
+/// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+/// Fetches all pages of the UTXOs for the addresses, "limit" UTXOs per call,
+/// and returns them in a single response with the total "numFetched"
+/// and the last "endIndex".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+pub async fn get_utxos(
+    http_rpc: &str,
+    addresses: &[String],
+    limit: u32,
+) -> io::Result<avm::GetUtxosResponse> {
+    collect_utxos(UtxoPager::new(http_rpc, addresses, limit)?).await
+}
+
+/// e.g., "avm.getUTXOs" with "sourceChain" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+/// Fetches all pages of the atomic UTXOs exported from the source chain (e.g., "P")
+/// to the addresses, which are not returned by "get_utxos" until imported.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+pub async fn get_atomic_utxos(
+    http_rpc: &str,
+    addresses: &[String],
+    limit: u32,
+    source_chain: &str,
+) -> io::Result<avm::GetUtxosResponse> {
+    collect_utxos(UtxoPager::new(http_rpc, addresses, limit)?.source_chain(source_chain)).await
+}
+
+async fn collect_utxos(mut pager: UtxoPager) -> io::Result<avm::GetUtxosResponse> {
+    let mut utxos = Vec::new();
+    while let Some(page) = pager.next_page().await? {
+        utxos.extend(page);
+    }
+
+    Ok(avm::GetUtxosResponse {
+        jsonrpc: String::from(jsonrpc::DEFAULT_VERSION),
+        id: jsonrpc::DEFAULT_ID,
+        result: Some(avm::GetUtxosResult {
+            num_fetched: utxos.len() as u32,
+            utxos: Some(utxos),
+            end_index: pager.start_index,
+            encoding: Some(String::from("hex")),
+        }),
+        error: None,
+    })
+}
+
+/// Pages through all the UTXOs of "avm.getUTXOs", following the "endIndex" cursor.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+pub struct UtxoPager {
+    http_rpc: String,
+    addresses: Vec<String>,
+    limit: u32,
+
+    /// Set to page through the atomic UTXOs exported from the source chain.
+    source_chain: Option<String>,
+
+    /// The "endIndex" of the last fetched page.
+    start_index: Option<jsonrpc::EndIndex>,
+    done: bool,
+}
+
+impl UtxoPager {
+    pub fn new(http_rpc: &str, addresses: &[String], limit: u32) -> io::Result<Self> {
+        if limit == 0 || limit > MAX_UTXOS_LIMIT {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("limit {} out of range [1, {}]", limit, MAX_UTXOS_LIMIT),
+            ));
+        }
+        Ok(Self {
+            http_rpc: http_rpc.to_string(),
+            addresses: addresses.to_vec(),
+            limit,
+            source_chain: None,
+            start_index: None,
+            done: false,
+        })
+    }
+
+    /// Sets the source chain (e.g., "P") to page through the atomic UTXOs.
+    #[must_use]
+    pub fn source_chain(mut self, source_chain: &str) -> Self {
+        self.source_chain = Some(source_chain.to_string());
+        self
+    }
+
+    /// Returns the next page of UTXOs, or "None" once all pages are fetched.
+    pub async fn next_page(&mut self) -> io::Result<Option<Vec<txs::utxo::Utxo>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = get_utxos_page(
+            &self.http_rpc,
+            &self.addresses,
+            self.limit,
+            self.start_index.clone(),
+            self.source_chain.clone(),
+        )
+        .await?;
+        if let Some(e) = resp.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("failed avm.getUTXOs '{}'", e.message),
+            ));
+        }
+        let result = resp.result.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Other,
+                "unexpected None GetUtxosResult".to_string(),
+            )
+        })?;
+
+        // the last page has fewer UTXOs than the limit
+        if result.num_fetched < self.limit || result.end_index.is_none() {
+            self.done = true;
+        }
+        if result.end_index.is_some() {
+            self.start_index = result.end_index;
+        }
+        Ok(Some(result.utxos.unwrap_or_default()))
+    }
+}
+
 /// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// TODO: support paginated calls
+/// Fetches a single page of the UTXOs, starting from the "endIndex" of the previous page.
+/// Set "source_chain" to fetch the atomic UTXOs exported from the chain.
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
-pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> io::Result<avm::GetUtxosResponse> {
-    log::debug!("getting UTXOs for {} via {http_rpc}/ext/bc/X", xaddr);
+pub async fn get_utxos_page(
+    http_rpc: &str,
+    addresses: &[String],
+    limit: u32,
+    start_index: Option<jsonrpc::EndIndex>,
+    source_chain: Option<String>,
+) -> io::Result<avm::GetUtxosResponse> {
+    log::debug!(
+        "getting UTXOs for {:?} from {:?} (source chain {:?}) via {http_rpc}/ext/bc/X",
+        addresses,
+        start_index,
+        source_chain
+    );
 
     let mut data = avm::GetUtxosRequest::default();
     data.method = String::from("avm.getUTXOs");
     let params = avm::GetUtxosParams {
-        addresses: vec![xaddr.to_string()],
-        limit: 1024,
+        addresses: addresses.to_vec(),
+        limit,
         encoding: String::from("hex"), // don't use "cb58"
+        start_index,
+        source_chain,
     };
     data.params = Some(params);
     let d = data.encode_json()?;
@@ -280,3 +423,102 @@ pub async fn issue_stop_vertex(http_rpc: &str) -> io::Result<()> {
 
     Ok(())
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::x::test_get_utxos_pagination --exact --show-output
+#[test]
+fn test_get_utxos_pagination() {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let raw_utxo = "0x000000000000000000000000000000000000000000000000000000000000000000000000000088eec2e099c6a528e689618e8721e04ae85ea574c7a15a7968644d14d54780140000000702c68af0bb1400000000000000000000000000010000000165844a05405f3662c1928142c6c2a783ef871de939b564db";
+    let page1 = format!(
+        r#"{{"jsonrpc":"2.0","result":{{"numFetched":"1","utxos":["{}"],"endIndex":{{"address":"X-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz","utxo":"LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq"}},"encoding":"hex"}},"id":1}}"#,
+        raw_utxo
+    );
+    let page2 = r#"{"jsonrpc":"2.0","result":{"numFetched":"0","utxos":[],"endIndex":{"address":"X-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz","utxo":"LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq"},"encoding":"hex"},"id":1}"#.to_string();
+
+    ab!(async move {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let http_rpc = format!("http://{}", listener.local_addr().unwrap());
+
+        // serves the first page, then the second page only for the requests with the cursor
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 8192];
+                let mut n = 0;
+                loop {
+                    n += stream.read(&mut buf[n..]).await.unwrap();
+                    let req = String::from_utf8_lossy(&buf[..n]).to_string();
+                    if let Some(pos) = req.find("\r\n\r\n") {
+                        let content_length = req
+                            .lines()
+                            .find_map(|l| {
+                                l.to_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if n >= pos + 4 + content_length {
+                            requests.push(req[pos + 4..].to_string());
+                            break;
+                        }
+                    }
+                }
+
+                let body = if requests.last().unwrap().contains("startIndex") {
+                    &page2
+                } else {
+                    &page1
+                };
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(resp.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+            requests
+        });
+
+        let addresses = vec![String::from(
+            "X-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz",
+        )];
+        let resp = get_utxos(&http_rpc, &addresses, 1).await.unwrap();
+        let result = resp.result.unwrap();
+        assert_eq!(result.num_fetched, 1);
+        assert_eq!(
+            result.utxos.unwrap(),
+            vec![txs::utxo::Utxo::from_hex(raw_utxo).unwrap()]
+        );
+        assert_eq!(
+            result.end_index.unwrap().utxo,
+            "LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq"
+        );
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].contains("startIndex"));
+        assert!(requests[1].contains(
+            r#""startIndex":{"address":"X-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz","utxo":"LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq"}"#
+        ));
+    });
+
+    assert!(UtxoPager::new("http://127.0.0.1:9650", &[], 0).is_err());
+    assert!(UtxoPager::new("http://127.0.0.1:9650", &[], MAX_UTXOS_LIMIT + 1).is_err());
+}
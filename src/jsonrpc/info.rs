@@ -173,6 +173,10 @@ pub struct GetNodeIdResponse {
 pub struct GetNodeIdResult {
     #[serde(rename = "nodeID")]
     pub node_id: node::Id,
+
+    /// None for nodes that predate the BLS keys.
+    #[serde(rename = "nodePOP", default, skip_serializing_if = "Option::is_none")]
+    pub node_pop: Option<ProofOfPossession>,
 }
 
 impl Default for GetNodeIdResult {
@@ -185,10 +189,20 @@ impl GetNodeIdResult {
     pub fn default() -> Self {
         Self {
             node_id: node::Id::default(),
+            node_pop: None,
         }
     }
 }
 
+/// BLS public key and its proof of possession, both hex-encoded with "0x" prefix.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/signer#ProofOfPossession>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofOfPossession {
+    pub public_key: String,
+    pub proof_of_possession: String,
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::info::test_get_node_id --exact --show-output
 #[test]
 fn test_get_node_id() {
@@ -214,6 +228,38 @@ fn test_get_node_id() {
         id: 1,
         result: Some(GetNodeIdResult {
             node_id: node::Id::from_str("NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD").unwrap(),
+            node_pop: None,
+        }),
+    };
+    assert_eq!(resp, expected);
+
+    let resp: GetNodeIdResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"nodeID\": \"NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD\",
+        \"nodePOP\": {
+            \"publicKey\": \"0x8f95423f7142d00a48e1014a3de8d28907d420dc33b3052a6dee03a3f2941a393c2351e354704ca66a3fc29870282e15\",
+            \"proofOfPossession\": \"0x86a3ab4c45cfe31cae34c1d06f212434ac71b1be6cfe046c80c162e057614a94a5bc9f1ded1a7029deb0ba4ca7c9b71411e293438691be79c2dbf19d1ca7c3eadb9c756246fc5de5b7b89511c7d7302ae051d9e03d7991138299b5ed6a570a98\"
+        }
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+    let expected = GetNodeIdResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetNodeIdResult {
+            node_id: node::Id::from_str("NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD").unwrap(),
+            node_pop: Some(ProofOfPossession {
+                public_key: "0x8f95423f7142d00a48e1014a3de8d28907d420dc33b3052a6dee03a3f2941a393c2351e354704ca66a3fc29870282e15".to_string(),
+                proof_of_possession: "0x86a3ab4c45cfe31cae34c1d06f212434ac71b1be6cfe046c80c162e057614a94a5bc9f1ded1a7029deb0ba4ca7c9b71411e293438691be79c2dbf19d1ca7c3eadb9c756246fc5de5b7b89511c7d7302ae051d9e03d7991138299b5ed6a570a98".to_string(),
+            }),
         }),
     };
     assert_eq!(resp, expected);
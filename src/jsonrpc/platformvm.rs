@@ -438,6 +438,11 @@ pub struct GetUtxosParams {
     pub addresses: Vec<String>,
     pub limit: u32,
     pub encoding: String,
+
+    /// Set to fetch the atomic UTXOs exported from the source chain
+    /// (e.g., "X"), which are consumed by the import transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_chain: Option<String>,
 }
 
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetutxos>
@@ -1034,6 +1039,136 @@ fn test_get_current_validators() {
     assert_eq!(resp, expected);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_current_validators_mainnet --exact --show-output
+#[test]
+fn test_get_current_validators_mainnet() {
+    use std::str::FromStr;
+
+    // mainnet response shape with BLS signer and split reward owners,
+    // which are not (yet) decoded
+    let resp: GetCurrentValidatorsResponse = serde_json::from_str(
+        "
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"validators\": [
+            {
+                \"txID\": \"TREerYN2wi8rer9R1vJrQrL8FfnzJNJg6ULsArPDsaesz2Sqh\",
+                \"startTime\": \"1681490624\",
+                \"endTime\": \"1713026624\",
+                \"stakeAmount\": \"2000000000000\",
+                \"nodeID\": \"NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ\",
+                \"weight\": \"2000000000000\",
+                \"rewardOwner\": {
+                    \"locktime\": \"0\",
+                    \"threshold\": \"1\",
+                    \"addresses\": [
+                        \"P-avax1gfpj30csekhwmf4mqkncelus5zl2ztqzvv7aww\"
+                    ]
+                },
+                \"validationRewardOwner\": {
+                    \"locktime\": \"0\",
+                    \"threshold\": \"1\",
+                    \"addresses\": [
+                        \"P-avax1gfpj30csekhwmf4mqkncelus5zl2ztqzvv7aww\"
+                    ]
+                },
+                \"delegationRewardOwner\": {
+                    \"locktime\": \"0\",
+                    \"threshold\": \"1\",
+                    \"addresses\": [
+                        \"P-avax1gfpj30csekhwmf4mqkncelus5zl2ztqzvv7aww\"
+                    ]
+                },
+                \"potentialReward\": \"137659401985\",
+                \"delegationFee\": \"2.0000\",
+                \"uptime\": \"99.9513\",
+                \"connected\": true,
+                \"signer\": {
+                    \"publicKey\": \"0x8f95423f7142d00a48e1014a3de8d28907d420dc33b3052a6dee03a3f2941a393c2351e354704ca66a3fc29870282e15\",
+                    \"proofOfPossession\": \"0x86a3ab4c45cfe31cae34c1d06f212434ac71b1be6cfe046c80c162e057614a94a5bc9f1ded1a7029deb0ba4ca7c9b71411e293438691be79c2dbf19d1ca7c3eadb9c756246fc5de5b7b89511c7d7302ae051d9e03d7991138299b5ed6a570a98\"
+                },
+                \"delegatorCount\": \"0\",
+                \"delegatorWeight\": \"0\"
+            },
+            {
+                \"txID\": \"2uYwi9yuNb8hqCbFBK1tDhhXiUh4egX4rxY3nkPGFJA5y8LoGy\",
+                \"startTime\": \"1683231247\",
+                \"endTime\": \"1714767247\",
+                \"stakeAmount\": \"2500000000000\",
+                \"nodeID\": \"NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN\",
+                \"weight\": \"3125000000000\",
+                \"rewardOwner\": {
+                    \"locktime\": \"0\",
+                    \"threshold\": \"1\",
+                    \"addresses\": [
+                        \"P-avax1gfpj30csekhwmf4mqkncelus5zl2ztqzvv7aww\"
+                    ]
+                },
+                \"potentialReward\": \"171036024163\",
+                \"delegationFee\": \"10.0000\",
+                \"uptime\": \"87.2108\",
+                \"connected\": false,
+                \"delegatorCount\": \"1\",
+                \"delegatorWeight\": \"625000000000\"
+            }
+        ]
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let reward_owner = Some(ApiOwner {
+        locktime: 0,
+        threshold: 1,
+        addresses: vec!["P-avax1gfpj30csekhwmf4mqkncelus5zl2ztqzvv7aww".to_string()],
+    });
+    let expected = GetCurrentValidatorsResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetCurrentValidatorsResult {
+            validators: Some(<Vec<ApiPrimaryValidator>>::from([
+                ApiPrimaryValidator {
+                    tx_id: ids::Id::from_str("TREerYN2wi8rer9R1vJrQrL8FfnzJNJg6ULsArPDsaesz2Sqh")
+                        .unwrap(),
+                    start_time: 1681490624,
+                    end_time: 1713026624,
+                    weight: Some(2000000000000),
+                    stake_amount: Some(2000000000000),
+                    node_id: node::Id::from_str("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ")
+                        .unwrap(),
+                    reward_owner: reward_owner.clone(),
+                    potential_reward: Some(137659401985),
+                    delegation_fee: Some(2.0),
+                    uptime: Some(99.9513),
+                    connected: Some(true),
+                    ..ApiPrimaryValidator::default()
+                },
+                ApiPrimaryValidator {
+                    tx_id: ids::Id::from_str("2uYwi9yuNb8hqCbFBK1tDhhXiUh4egX4rxY3nkPGFJA5y8LoGy")
+                        .unwrap(),
+                    start_time: 1683231247,
+                    end_time: 1714767247,
+                    weight: Some(3125000000000),
+                    stake_amount: Some(2500000000000),
+                    node_id: node::Id::from_str("NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN")
+                        .unwrap(),
+                    reward_owner,
+                    potential_reward: Some(171036024163),
+                    delegation_fee: Some(10.0),
+                    uptime: Some(87.2108),
+                    connected: Some(false),
+                    ..ApiPrimaryValidator::default()
+                },
+            ])),
+        }),
+    };
+    assert_eq!(resp, expected);
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#APIUTXO>
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
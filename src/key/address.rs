@@ -0,0 +1,354 @@
+//! Detects the address type of an arbitrary address string
+//! (e.g., the "paste any address" input), and converts between
+//! the C-chain and the X/P-chain addresses of the same key.
+//!
+//! The C-chain address is the last 20 bytes of the Keccak-256 of the
+//! uncompressed public key, while the X/P-chain short address is the
+//! RIPEMD-160 of the SHA-256 of the compressed public key. Neither hash can
+//! be inverted, thus one address can only be converted to the other with
+//! the public key (e.g., recovered from a signature).
+use std::{
+    io::{self, Error, ErrorKind},
+    str::FromStr,
+};
+
+use crate::{
+    ids::{node, short},
+    key::{
+        network::NetworkId,
+        secp256k1::{address, public_key},
+    },
+};
+use primitive_types::H160;
+
+/// Represents the classified address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressKind {
+    /// C-chain (EVM) "0x"-prefixed 20-byte hex address.
+    Eth(H160),
+    /// X/P-chain bech32 address (e.g., "X-avax1...").
+    Bech32 {
+        /// Chain alias before "-" (e.g., "X" or "P"),
+        /// "None" if the address has no alias (e.g., "avax1...").
+        chain_alias: Option<String>,
+        hrp: String,
+        /// Same as "short::Id::from_hrp_address", the "custom" HRP maps to
+        /// "constants::DEFAULT_CUSTOM_NETWORK_ID" since the address does not
+        /// encode the actual custom network Id.
+        network_id: NetworkId,
+        short_address: short::Id,
+    },
+    /// CB58-encoded short Id (e.g., "6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV").
+    ShortId(short::Id),
+    /// Invalid or ambiguous input, with the reason.
+    Invalid(String),
+}
+
+/// Classifies the address string, ignoring the surrounding whitespaces.
+/// The mixed-case C-chain address must match its EIP-55 checksum,
+/// while the all-lowercase or all-uppercase one is accepted as is.
+/// The bech32 address is parsed by "short::Id::from_hrp_address"
+/// (or "short::Id::from_bech32" without the chain alias), thus rejects
+/// the unknown HRPs.
+pub fn classify(s: &str) -> AddressKind {
+    let s = s.trim();
+    if s.is_empty() {
+        return AddressKind::Invalid("empty address".to_string());
+    }
+
+    if let Some(hex_addr) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return classify_eth(s, hex_addr);
+    }
+
+    if s.starts_with(node::ENCODE_PREFIX) {
+        return AddressKind::Invalid(format!(
+            "'{}' is a node Id, not an address",
+            node::ENCODE_PREFIX
+        ));
+    }
+
+    // "-" is in neither the bech32 nor the CB58 alphabet
+    if s.contains('-') {
+        return classify_bech32(s);
+    }
+
+    // both have checksums, so that at most one decodes in practice
+    match (short::Id::from_str(s), classify_bech32(s)) {
+        (Ok(_), AddressKind::Bech32 { .. }) => {
+            AddressKind::Invalid(format!("'{}' is ambiguous (both CB58 and bech32)", s))
+        }
+        (Ok(id), _) => AddressKind::ShortId(id),
+        (Err(_), bech32 @ AddressKind::Bech32 { .. }) => bech32,
+        (Err(e), _) => AddressKind::Invalid(format!("not a hex, bech32, or CB58 address ({})", e)),
+    }
+}
+
+fn classify_eth(s: &str, hex_addr: &str) -> AddressKind {
+    if hex_addr.len() != 40 {
+        return AddressKind::Invalid(format!(
+            "invalid hex address length {} (expected 40)",
+            hex_addr.len()
+        ));
+    }
+    let b = match hex::decode(hex_addr) {
+        Ok(b) => b,
+        Err(e) => return AddressKind::Invalid(format!("invalid hex address ({})", e)),
+    };
+    let h160 = H160::from_slice(&b);
+
+    let has_lower = hex_addr.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex_addr.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        let checksummed = address::h160_to_eth_address(&h160, None);
+        if checksummed[2..] != *hex_addr {
+            return AddressKind::Invalid(format!(
+                "invalid EIP-55 checksum for '{}' (expected '{}')",
+                s, checksummed
+            ));
+        }
+    }
+    AddressKind::Eth(h160)
+}
+
+fn classify_bech32(s: &str) -> AddressKind {
+    let parsed = if s.contains('-') {
+        short::Id::from_hrp_address(s)
+            .map(|(network_id, chain_alias, id)| (network_id, Some(chain_alias), id))
+    } else {
+        short::Id::from_bech32(s).map(|(network_id, id)| (network_id, None, id))
+    };
+    match parsed {
+        Ok((network_id, chain_alias, short_address)) => {
+            let network_id = NetworkId::from(network_id);
+            AddressKind::Bech32 {
+                chain_alias,
+                hrp: network_id.hrp().to_string(),
+                network_id,
+                short_address,
+            }
+        }
+        Err(e) => AddressKind::Invalid(format!("invalid bech32 address ({})", e)),
+    }
+}
+
+/// Parses the X/P-chain bech32 address, with or without the chain alias
+/// (e.g., "X-avax1..." or "avax1..."), to its short address.
+pub fn bech32_to_short_id(addr: &str) -> io::Result<short::Id> {
+    match classify_bech32(addr.trim()) {
+        AddressKind::Bech32 { short_address, .. } => Ok(short_address),
+        AddressKind::Invalid(reason) => Err(Error::new(ErrorKind::InvalidInput, reason)),
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("'{}' is not a bech32 address", addr),
+        )),
+    }
+}
+
+/// Converts the C-chain address to the X/P-chain bech32 address
+/// (e.g., "X-avax1...") of the same key.
+/// The address alone does not determine the X/P-chain address (see the module
+/// doc), thus requires the public key, and fails if the public key does not
+/// derive the C-chain address.
+pub fn eth_to_bech32(
+    eth_addr: H160,
+    pubkey: &public_key::Key,
+    network_id: impl Into<NetworkId>,
+    chain_alias: &str,
+) -> io::Result<String> {
+    if pubkey.to_h160() != eth_addr {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "public key derives the C-chain address {} instead of {}",
+                pubkey.to_eth_address(),
+                address::h160_to_eth_address(&eth_addr, None)
+            ),
+        ));
+    }
+    pubkey.to_hrp_address(network_id, chain_alias)
+}
+
+/// Converts the X/P-chain bech32 address to the C-chain address of the same key.
+/// Same as "eth_to_bech32", requires the public key, and fails if the public key
+/// does not derive the X/P-chain address.
+pub fn bech32_to_eth(addr: &str, pubkey: &public_key::Key) -> io::Result<H160> {
+    let short_address = bech32_to_short_id(addr)?;
+    if pubkey.to_short_id()? != short_address {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "public key derives the short address {} instead of {}",
+                pubkey.to_short_id()?,
+                short_address
+            ),
+        ));
+    }
+    Ok(pubkey.to_h160())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::address::test_classify --exact --show-output
+#[test]
+fn test_classify() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = crate::key::secp256k1::TEST_KEYS[0].clone();
+    let info = k
+        .to_info_for_networks(&[1, 5, crate::constants::DEFAULT_CUSTOM_NETWORK_ID])
+        .unwrap();
+
+    for (network_id, expected_hrp, expected_network_id) in [
+        (1, "avax", NetworkId::Mainnet),
+        (5, "fuji", NetworkId::Fuji),
+        (
+            crate::constants::DEFAULT_CUSTOM_NETWORK_ID,
+            "custom",
+            NetworkId::Custom(crate::constants::DEFAULT_CUSTOM_NETWORK_ID),
+        ),
+    ] {
+        let addrs = info.addresses.get(&network_id).unwrap();
+        for (addr, alias) in [(&addrs.x, "X"), (&addrs.p, "P")] {
+            assert_eq!(
+                classify(addr),
+                AddressKind::Bech32 {
+                    chain_alias: Some(alias.to_string()),
+                    hrp: expected_hrp.to_string(),
+                    network_id: expected_network_id,
+                    short_address: info.short_address,
+                }
+            );
+        }
+    }
+
+    // without the chain alias
+    let addr = info.addresses.get(&1).unwrap().x.trim_start_matches("X-");
+    assert!(matches!(
+        classify(addr),
+        AddressKind::Bech32 {
+            chain_alias: None,
+            network_id: NetworkId::Mainnet,
+            ..
+        }
+    ));
+
+    // same as "short::Id::from_hrp_address"
+    for addrs in info.addresses.values() {
+        for addr in [&addrs.x, &addrs.p] {
+            let (network_id, chain_alias, short_address) =
+                short::Id::from_hrp_address(addr).unwrap();
+            assert_eq!(
+                classify(addr),
+                AddressKind::Bech32 {
+                    chain_alias: Some(chain_alias),
+                    hrp: NetworkId::from(network_id).hrp().to_string(),
+                    network_id: NetworkId::from(network_id),
+                    short_address,
+                }
+            );
+        }
+    }
+    let unknown_hrp =
+        crate::formatting::address("X", "avaxx", info.short_address.as_ref()).unwrap();
+    assert!(short::Id::from_hrp_address(&unknown_hrp).is_err());
+    assert!(matches!(classify(&unknown_hrp), AddressKind::Invalid(_)));
+    assert!(matches!(
+        classify(unknown_hrp.trim_start_matches("X-")),
+        AddressKind::Invalid(_)
+    ));
+
+    assert_eq!(
+        classify(&info.eth_address),
+        AddressKind::Eth(info.h160_address)
+    );
+    assert_eq!(
+        classify(&info.eth_address.to_lowercase()),
+        AddressKind::Eth(info.h160_address)
+    );
+    assert_eq!(
+        classify(&format!(" {} ", info.short_address)),
+        AddressKind::ShortId(info.short_address)
+    );
+
+    // flips the case of the first letter to break the EIP-55 checksum
+    let mut bad_checksum: Vec<char> = info.eth_address.chars().collect();
+    let idx = bad_checksum
+        .iter()
+        .skip(2)
+        .position(|c| c.is_ascii_alphabetic())
+        .unwrap()
+        + 2;
+    bad_checksum[idx] = if bad_checksum[idx].is_ascii_uppercase() {
+        bad_checksum[idx].to_ascii_lowercase()
+    } else {
+        bad_checksum[idx].to_ascii_uppercase()
+    };
+    let bad_checksum: String = bad_checksum.into_iter().collect();
+
+    for invalid in [
+        "",
+        "   ",
+        "0x1234",
+        "0xzz00000000000000000000000000000000000000",
+        bad_checksum.as_str(),
+        "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg",
+        "X-avax1invalid",
+        "-avax1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        "not-an-address",
+        "hello",
+    ] {
+        assert!(
+            matches!(classify(invalid), AddressKind::Invalid(_)),
+            "'{}' should be invalid",
+            invalid
+        );
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::address::test_eth_bech32_conversion --exact --show-output
+#[test]
+fn test_eth_bech32_conversion() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = crate::key::secp256k1::TEST_KEYS[0].clone();
+    let pubkey = k.to_public_key();
+    let info = k.to_info(1u32).unwrap();
+    let addrs = info.addresses.get(&1).unwrap();
+
+    assert_eq!(bech32_to_short_id(&addrs.x).unwrap(), info.short_address);
+    assert_eq!(bech32_to_short_id(&addrs.p).unwrap(), info.short_address);
+    assert_eq!(
+        bech32_to_short_id(addrs.x.trim_start_matches("X-")).unwrap(),
+        info.short_address
+    );
+    assert!(bech32_to_short_id(&info.eth_address).is_err());
+    assert!(bech32_to_short_id(&info.short_address.to_string()).is_err());
+    assert!(bech32_to_short_id("X-avax1invalid").is_err());
+
+    assert_eq!(
+        eth_to_bech32(info.h160_address, &pubkey, 1u32, "X").unwrap(),
+        addrs.x
+    );
+    assert_eq!(
+        eth_to_bech32(info.h160_address, &pubkey, 1u32, "P").unwrap(),
+        addrs.p
+    );
+    assert_eq!(bech32_to_eth(&addrs.p, &pubkey).unwrap(), info.h160_address);
+
+    // the public key of another key cannot convert the addresses
+    let other = crate::key::secp256k1::TEST_KEYS[1].to_public_key();
+    assert_eq!(
+        eth_to_bech32(info.h160_address, &other, 1u32, "X")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        bech32_to_eth(&addrs.x, &other).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+}
@@ -0,0 +1,18 @@
+//! BLS12-381 keys for the P-chain validator proof-of-possession (post-Banff).
+//!
+//! Uses the "min-pk" variant as avalanchego: 48-byte compressed public keys
+//! in G1, and 96-byte compressed signatures in G2.
+//!
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto/bls>
+//! ref. <https://github.com/supranational/blst>
+pub mod private_key;
+pub mod public_key;
+pub mod signature;
+
+/// The domain separation tag for the message signatures.
+/// ref. "avalanchego/utils/crypto/bls.ciphersuiteSignature"
+pub const DST_SIGNATURE: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The domain separation tag for the proof-of-possession signatures.
+/// ref. "avalanchego/utils/crypto/bls.ciphersuiteProofOfPossession"
+pub const DST_PROOF_OF_POSSESSION: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
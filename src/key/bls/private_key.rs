@@ -0,0 +1,134 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::key::bls::{self, public_key::Key as PublicKey, signature::Sig};
+use blst::min_pk::SecretKey;
+use rand::RngCore;
+use zeroize::Zeroizing;
+
+/// The size (in bytes) of a secret key.
+/// ref. "avalanchego/utils/crypto/bls.SecretKeyLen"
+pub const LEN: usize = 32;
+
+/// Represents "blst::min_pk::SecretKey", zeroed out on drop.
+#[derive(Clone)]
+pub struct Key(SecretKey);
+
+/// Redacts the secret key, so that the key is never leaked to the logs.
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Key(<redacted BLS secret key>, public_key: {})",
+            self.to_public_key()
+        )
+    }
+}
+
+impl Key {
+    /// Generates a private key from random bytes.
+    /// ref. "avalanchego/utils/crypto/bls.NewSecretKey"
+    pub fn generate() -> io::Result<Self> {
+        let mut ikm = Zeroizing::new([0u8; 32]);
+        rand::thread_rng()
+            .try_fill_bytes(&mut *ikm)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed try_fill_bytes {}", e)))?;
+
+        let sk = SecretKey::key_gen(&*ikm, &[]).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed blst::SecretKey::key_gen {:?}", e),
+            )
+        })?;
+        Ok(Self(sk))
+    }
+
+    /// Loads the private key from the 32-byte big-endian scalar.
+    /// ref. "avalanchego/utils/crypto/bls.SecretKeyFromBytes"
+    pub fn from_bytes(raw: &[u8]) -> io::Result<Self> {
+        if raw.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid BLS secret key length {} (expected {})",
+                    raw.len(),
+                    LEN
+                ),
+            ));
+        }
+        let sk = SecretKey::from_bytes(raw).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed blst::SecretKey::from_bytes {:?}", e),
+            )
+        })?;
+        Ok(Self(sk))
+    }
+
+    /// Converts the private key to raw scalar bytes
+    /// that are zeroed out when the returned buffer is dropped.
+    /// ref. "avalanchego/utils/crypto/bls.SecretKeyToBytes"
+    pub fn to_bytes(&self) -> Zeroizing<[u8; LEN]> {
+        Zeroizing::new(self.0.to_bytes())
+    }
+
+    /// Derives the public key from this private key.
+    pub fn to_public_key(&self) -> PublicKey {
+        PublicKey(self.0.sk_to_pk())
+    }
+
+    /// Signs the message with the signature ciphersuite.
+    /// ref. "avalanchego/utils/crypto/bls.Sign"
+    pub fn sign(&self, msg: &[u8]) -> Sig {
+        Sig(self.0.sign(msg, bls::DST_SIGNATURE, &[]))
+    }
+
+    /// Signs the message with the proof-of-possession ciphersuite.
+    /// ref. "avalanchego/utils/crypto/bls.SignProofOfPossession"
+    pub fn sign_proof_of_possession(&self, msg: &[u8]) -> Sig {
+        Sig(self.0.sign(msg, bls::DST_PROOF_OF_POSSESSION, &[]))
+    }
+
+    /// Returns the public key and its proof-of-possession, the signature over
+    /// the compressed public key bytes, as registered by P-chain validators.
+    /// ref. "avalanchego/vms/platformvm/signer.NewProofOfPossession"
+    pub fn proof_of_possession(&self) -> (PublicKey, Sig) {
+        let public_key = self.to_public_key();
+        let sig = self.sign_proof_of_possession(&public_key.to_compressed_bytes());
+        (public_key, sig)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="bls" -- key::bls::private_key::test_key --exact --show-output
+#[test]
+fn test_key() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let sk = Key::generate().unwrap();
+    let pk = sk.to_public_key();
+    log::info!("BLS key {:?}", sk);
+
+    let raw = sk.to_bytes();
+    let loaded = Key::from_bytes(&*raw).unwrap();
+    assert_eq!(loaded.to_public_key(), pk);
+    assert!(!format!("{:?}", sk).contains(&hex::encode(&*raw)));
+    assert!(Key::from_bytes(&raw[1..]).is_err());
+
+    let msg = b"hello";
+    let sig = sk.sign(msg);
+    assert!(pk.verify(msg, &sig));
+    assert!(!pk.verify(b"hellO", &sig));
+    assert!(!Key::generate().unwrap().to_public_key().verify(msg, &sig));
+
+    // the ciphersuites are not interchangeable
+    assert!(!pk.verify_proof_of_possession(msg, &sig));
+    let pop_sig = sk.sign_proof_of_possession(msg);
+    assert!(pk.verify_proof_of_possession(msg, &pop_sig));
+    assert!(!pk.verify(msg, &pop_sig));
+
+    let (pop_pk, pop) = sk.proof_of_possession();
+    assert_eq!(pop_pk, pk);
+    assert!(pk.verify_proof_of_possession(&pk.to_compressed_bytes(), &pop));
+}
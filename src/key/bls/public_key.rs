@@ -0,0 +1,92 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::key::bls::{self, signature::Sig};
+use blst::{min_pk::PublicKey, BLST_ERROR};
+
+/// The size (in bytes) of a compressed public key.
+/// ref. "avalanchego/utils/crypto/bls.PublicKeyLen"
+pub const LEN: usize = 48;
+
+/// Represents "blst::min_pk::PublicKey" in G1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key(pub PublicKey);
+
+impl Key {
+    /// Loads the public key from the 48-byte compressed form,
+    /// and fails if the point is not in the group or is the identity.
+    /// ref. "avalanchego/utils/crypto/bls.PublicKeyFromBytes"
+    pub fn from_bytes(b: &[u8]) -> io::Result<Self> {
+        if b.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid BLS public key length {} (expected {})",
+                    b.len(),
+                    LEN
+                ),
+            ));
+        }
+        let pk = PublicKey::key_validate(b).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed blst::PublicKey::key_validate {:?}", e),
+            )
+        })?;
+        Ok(Self(pk))
+    }
+
+    /// Converts the public key to the 48-byte compressed form.
+    /// ref. "avalanchego/utils/crypto/bls.PublicKeyToBytes"
+    pub fn to_compressed_bytes(&self) -> [u8; LEN] {
+        self.0.compress()
+    }
+
+    /// Returns true if the signature is valid for the message
+    /// with the signature ciphersuite.
+    /// ref. "avalanchego/utils/crypto/bls.Verify"
+    pub fn verify(&self, msg: &[u8], sig: &Sig) -> bool {
+        sig.0
+            .verify(true, msg, bls::DST_SIGNATURE, &[], &self.0, false)
+            == BLST_ERROR::BLST_SUCCESS
+    }
+
+    /// Returns true if the signature is valid for the message
+    /// with the proof-of-possession ciphersuite.
+    /// ref. "avalanchego/utils/crypto/bls.VerifyProofOfPossession"
+    pub fn verify_proof_of_possession(&self, msg: &[u8], sig: &Sig) -> bool {
+        sig.0
+            .verify(true, msg, bls::DST_PROOF_OF_POSSESSION, &[], &self.0, false)
+            == BLST_ERROR::BLST_SUCCESS
+    }
+}
+
+/// Encodes the compressed public key in "0x"-prefixed hex, as in "info.getNodeID".
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{}", hex::encode(self.to_compressed_bytes()))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="bls" -- key::bls::public_key::test_key --exact --show-output
+#[test]
+fn test_key() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::bls::private_key::Key::generate()
+        .unwrap()
+        .to_public_key();
+    let b = pk.to_compressed_bytes();
+    assert_eq!(Key::from_bytes(&b).unwrap(), pk);
+    assert!(pk.to_string().starts_with("0x"));
+    assert_eq!(pk.to_string().len(), 2 + 2 * LEN);
+
+    assert!(Key::from_bytes(&b[1..]).is_err());
+
+    // the compressed identity (infinity) point
+    let mut identity = [0u8; LEN];
+    identity[0] = 0xc0;
+    assert!(Key::from_bytes(&identity).is_err());
+}
@@ -0,0 +1,65 @@
+use std::io::{self, Error, ErrorKind};
+
+use blst::min_pk::Signature;
+
+/// The size (in bytes) of a compressed signature.
+/// ref. "avalanchego/utils/crypto/bls.SignatureLen"
+pub const LEN: usize = 96;
+
+/// Represents "blst::min_pk::Signature" in G2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sig(pub Signature);
+
+impl Sig {
+    /// Loads the signature from the 96-byte compressed form,
+    /// and fails if the point is not in the group.
+    /// ref. "avalanchego/utils/crypto/bls.SignatureFromBytes"
+    pub fn from_bytes(b: &[u8]) -> io::Result<Self> {
+        if b.len() != LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid BLS signature length {} (expected {})",
+                    b.len(),
+                    LEN
+                ),
+            ));
+        }
+        let sig = Signature::sig_validate(b, false).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed blst::Signature::sig_validate {:?}", e),
+            )
+        })?;
+        Ok(Self(sig))
+    }
+
+    /// Converts the signature to the 96-byte compressed form.
+    /// ref. "avalanchego/utils/crypto/bls.SignatureToBytes"
+    pub fn to_compressed_bytes(&self) -> [u8; LEN] {
+        self.0.compress()
+    }
+}
+
+/// Encodes the compressed signature in "0x"-prefixed hex, as in "info.getNodeID".
+impl std::fmt::Display for Sig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{}", hex::encode(self.to_compressed_bytes()))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="bls" -- key::bls::signature::test_sig --exact --show-output
+#[test]
+fn test_sig() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let sk = crate::key::bls::private_key::Key::generate().unwrap();
+    let sig = sk.sign(b"hello");
+    let b = sig.to_compressed_bytes();
+    assert_eq!(Sig::from_bytes(&b).unwrap(), sig);
+    assert!(Sig::from_bytes(&b[1..]).is_err());
+    assert!(sig.to_string().starts_with("0x"));
+}
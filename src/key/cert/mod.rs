@@ -1 +1,3 @@
 pub mod x509;
+
+pub use x509::{generate_staking_cert, node_id_from_cert};
@@ -1,4 +1,12 @@
 pub mod secp256k1;
 
+#[cfg(feature = "std")]
+pub mod address;
+#[cfg(feature = "std")]
+pub mod network;
+
+#[cfg(feature = "bls")]
+pub mod bls;
+
 #[cfg(feature = "cert")]
 pub mod cert;
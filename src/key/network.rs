@@ -0,0 +1,151 @@
+//! Typed network Id, to make the HRP selection of the X/P-chain addresses explicit.
+use crate::constants;
+
+/// Represents the Avalanche network Id.
+/// Use "From<u32>" to load the raw Id, which maps the well-known Ids
+/// to their variants (e.g., "5" to "Fuji"), and "u32::from" (or "as_u32")
+/// for the raw Id. The address APIs (e.g., "to_info") take either.
+/// Compared and hashed by the raw Id, so "Custom(1)" equals "Mainnet".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants>
+#[derive(Debug, Clone, Copy)]
+pub enum NetworkId {
+    /// "1" with the HRP "avax".
+    Mainnet,
+    /// "5" with the HRP "fuji".
+    Fuji,
+    /// "12345" with the HRP "local".
+    Local,
+    /// Any other network Id (e.g., "1000000" for the custom network).
+    Custom(u32),
+}
+
+pub const MAINNET_ID: u32 = 1;
+pub const FUJI_ID: u32 = 5;
+pub const LOCAL_ID: u32 = 12345;
+
+impl NetworkId {
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            NetworkId::Mainnet => MAINNET_ID,
+            NetworkId::Fuji => FUJI_ID,
+            NetworkId::Local => LOCAL_ID,
+            NetworkId::Custom(id) => *id,
+        }
+    }
+
+    /// Returns the human-readable part (HRP) of the bech32 addresses
+    /// (e.g., "avax" for "X-avax1..."), falling back to "custom" for the
+    /// unknown network Ids as in "avalanchego/utils/constants.GetHRP".
+    pub fn hrp(&self) -> &'static str {
+        match self {
+            NetworkId::Mainnet => "avax",
+            NetworkId::Fuji => "fuji",
+            NetworkId::Local => "local",
+            NetworkId::Custom(id) => match constants::NETWORK_ID_TO_HRP.get(id) {
+                Some(hrp) => hrp,
+                None => constants::FALLBACK_HRP,
+            },
+        }
+    }
+}
+
+impl From<u32> for NetworkId {
+    fn from(id: u32) -> Self {
+        match id {
+            MAINNET_ID => NetworkId::Mainnet,
+            FUJI_ID => NetworkId::Fuji,
+            LOCAL_ID => NetworkId::Local,
+            _ => NetworkId::Custom(id),
+        }
+    }
+}
+
+impl PartialEq for NetworkId {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_u32() == other.as_u32()
+    }
+}
+
+impl Eq for NetworkId {}
+
+impl std::hash::Hash for NetworkId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_u32().hash(state);
+    }
+}
+
+impl From<NetworkId> for u32 {
+    fn from(network_id: NetworkId) -> Self {
+        network_id.as_u32()
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
+/// Use "Self.to_string()" to directly invoke this
+impl std::fmt::Display for NetworkId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_u32())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::network::test_network_id --exact --show-output
+#[test]
+fn test_network_id() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    assert_eq!(NetworkId::Mainnet.hrp(), "avax");
+    assert_eq!(NetworkId::Fuji.hrp(), "fuji");
+    assert_eq!(NetworkId::Local.hrp(), "local");
+    assert_eq!(NetworkId::Custom(2).hrp(), "cascade");
+    assert_eq!(
+        NetworkId::Custom(constants::DEFAULT_CUSTOM_NETWORK_ID).hrp(),
+        "custom"
+    );
+
+    // same HRPs as the raw network Id lookup
+    for (id, hrp) in constants::NETWORK_ID_TO_HRP.iter() {
+        assert_eq!(NetworkId::from(*id).hrp(), *hrp);
+    }
+
+    assert_eq!(NetworkId::from(1), NetworkId::Mainnet);
+    assert_eq!(NetworkId::from(5), NetworkId::Fuji);
+    assert_eq!(NetworkId::from(12345), NetworkId::Local);
+    assert_eq!(NetworkId::from(1337), NetworkId::Custom(1337));
+    for id in [1, 5, 12345, 1337] {
+        assert_eq!(u32::from(NetworkId::from(id)), id);
+    }
+    assert_eq!(NetworkId::Fuji.to_string(), "5");
+
+    // compared and hashed by the raw Id
+    assert_eq!(NetworkId::Custom(1), NetworkId::Mainnet);
+    assert_eq!(NetworkId::Custom(12345), NetworkId::Local);
+    assert_ne!(NetworkId::Custom(2), NetworkId::Mainnet);
+    let ids: std::collections::HashSet<NetworkId> = [
+        NetworkId::Mainnet,
+        NetworkId::Custom(1),
+        NetworkId::Custom(5),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&NetworkId::Fuji));
+
+    // typed network Id with the raw APIs
+    let k = crate::key::secp256k1::TEST_KEYS[0].clone();
+    let info = k.to_info(NetworkId::Mainnet).unwrap();
+    assert!(info.addresses.get(&1).unwrap().x.starts_with("X-avax1"));
+    assert_eq!(info.addresses, k.to_info(1u32).unwrap().addresses);
+    assert_eq!(
+        info.addresses,
+        k.to_info(NetworkId::Custom(1)).unwrap().addresses
+    );
+    assert_eq!(
+        k.to_public_key()
+            .to_hrp_address(NetworkId::Fuji, "P")
+            .unwrap(),
+        k.to_public_key().to_hrp_address(5u32, "P").unwrap()
+    );
+}
@@ -0,0 +1,108 @@
+//! Checks that the "k256" (default) and "libsecp256k1" backends never diverge:
+//! both must produce the same recoverable signature for the same key and digest
+//! (RFC 6979 nonce with low-S normalization), and each must accept the other's
+//! signatures and public key derivation.
+//! "k256" is always enabled, thus only gated by the "libsecp256k1" feature.
+use crate::key::secp256k1::{
+    libsecp256k1::{PrivateKey, PublicKey},
+    private_key::{self, Key},
+    signature::Sig,
+};
+
+/// Asserts the parity of the two backends for the key and the digest.
+fn assert_parity(k256_key: &Key, digest: &[u8; 32]) {
+    let libsecp_key: PrivateKey = k256_key.to_libsecp256k1().unwrap();
+
+    let k256_pubkey = k256_key.to_public_key();
+    let libsecp_pubkey: PublicKey = libsecp_key.to_public_key();
+    assert_eq!(
+        k256_pubkey.to_compressed_bytes(),
+        libsecp_pubkey.to_compressed_bytes()
+    );
+    assert_eq!(
+        k256_pubkey.to_uncompressed_bytes(),
+        libsecp_pubkey.to_uncompressed_bytes()
+    );
+    assert_eq!(k256_pubkey.to_h160(), libsecp_pubkey.to_h160());
+
+    let k256_sig = k256_key.sign_digest(digest).unwrap();
+    let libsecp_sig = libsecp_key.sign_digest(digest).unwrap();
+    assert_eq!(
+        k256_sig.to_bytes(),
+        libsecp_sig.to_bytes(),
+        "signatures diverge for digest {}",
+        hex::encode(digest)
+    );
+
+    // each backend verifies and recovers the other's signature
+    assert!(k256_pubkey.verify_digest(digest, &libsecp_sig));
+    assert!(libsecp_pubkey.verify_digest(digest, &k256_sig));
+
+    let (recovered, _) = libsecp_sig.recover_public_key(digest).unwrap();
+    assert_eq!(recovered, k256_pubkey);
+    assert_eq!(
+        PublicKey::recover(digest, &k256_sig).unwrap(),
+        libsecp_pubkey
+    );
+
+    // round-trips through the 65-byte encoding
+    let loaded = Sig::from_bytes(&libsecp_sig.to_bytes()).unwrap();
+    assert!(k256_pubkey.verify_digest(digest, &loaded));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="libsecp256k1" -- key::secp256k1::backend_parity::test_random_keys --exact --show-output
+#[test]
+fn test_random_keys() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    for _ in 0..200 {
+        let k = Key::generate().unwrap();
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&random_manager::secure_bytes(32).unwrap());
+        assert_parity(&k, &digest);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="libsecp256k1" -- key::secp256k1::backend_parity::test_edge_vectors --exact --show-output
+#[test]
+fn test_edge_vectors() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // secp256k1 group order "n"
+    let mut order = [0u8; 32];
+    order.copy_from_slice(
+        &hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141").unwrap(),
+    );
+
+    // smallest and largest valid scalars
+    let mut one = [0u8; private_key::LEN];
+    one[private_key::LEN - 1] = 1;
+    let mut max = order;
+    max[31] -= 1;
+
+    let mut keys = vec![
+        Key::from_bytes(&one).unwrap(),
+        Key::from_bytes(&max).unwrap(),
+    ];
+    keys.extend(crate::key::secp256k1::TEST_KEYS.iter().cloned());
+
+    let mut digest_one = [0u8; 32];
+    digest_one[31] = 1;
+    let digests = [
+        [0u8; 32], [0xff; 32], // greater than "n", thus reduced
+        order,      // reduces to zero
+        digest_one,
+    ];
+
+    for k in keys.iter() {
+        for digest in digests.iter() {
+            assert_parity(k, digest);
+        }
+    }
+}
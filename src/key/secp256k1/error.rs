@@ -0,0 +1,83 @@
+//! Errors of the core secp256k1 key operations ("private_key::Key::from_bytes",
+//! "private_key::Key::sign_digest"), which build without "std".
+//! With the "std" feature, converts into "std::io::Error" so that
+//! the "io::Result" APIs keep propagating them with "?".
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The raw private key is not 32-byte.
+    InvalidKeyLength { expected: usize, actual: usize },
+    /// The digest to sign is not the 32-byte SHA256 output.
+    InvalidDigestLength { expected: usize, actual: usize },
+    /// The private key scalar is zero.
+    ZeroScalar,
+    /// The private key scalar is not less than the secp256k1 group order "n".
+    ScalarOutOfRange,
+    /// The RFC 6979 signing failed or returned no recovery Id.
+    Signing,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidKeyLength { expected, actual } => write!(
+                f,
+                "invalid private key length {} (expected {})",
+                actual, expected
+            ),
+            Error::InvalidDigestLength { expected, actual } => write!(
+                f,
+                "invalid digest length {} (expected {})",
+                actual, expected
+            ),
+            Error::ZeroScalar => f.write_str("invalid private key scalar (zero)"),
+            Error::ScalarOutOfRange => {
+                f.write_str("invalid private key scalar (not less than the secp256k1 group order)")
+            }
+            Error::Signing => f.write_str("failed try_sign_prehashed_rfc6979"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Maps the invalid inputs to "ErrorKind::InvalidInput",
+/// same as the "io::Result" APIs before this error type.
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            Error::Signing => std::io::ErrorKind::Other,
+            _ => std::io::ErrorKind::InvalidInput,
+        };
+        std::io::Error::new(kind, e)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::error::test_into_io_error --exact --show-output
+#[test]
+fn test_into_io_error() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let err = std::io::Error::from(Error::ZeroScalar);
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(err.to_string(), "invalid private key scalar (zero)");
+
+    let err = std::io::Error::from(Error::InvalidKeyLength {
+        expected: 32,
+        actual: 31,
+    });
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        err.to_string(),
+        "invalid private key length 31 (expected 32)"
+    );
+
+    let err = std::io::Error::from(Error::Signing);
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+}
@@ -0,0 +1,198 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::key;
+use async_trait::async_trait;
+use ethers_core::{
+    k256::ecdsa::recoverable::Signature as RSig,
+    types::{
+        transaction::{eip2718::TypedTransaction, eip712::Eip712},
+        Address, Signature,
+    },
+};
+
+/// Implements "ethers_signers::Signer" for the local private key,
+/// with the same recovery Id and "v" handling as the KMS signers
+/// (e.g., "key::secp256k1::kms::aws::eth_signer::Signer"),
+/// instead of converting to "ethers_signers::LocalWallet".
+#[derive(Clone, Debug)]
+pub struct LocalSigner {
+    pub inner: key::secp256k1::private_key::Key,
+    pub chain_id: primitive_types::U256,
+    pub address: Address,
+}
+
+impl LocalSigner {
+    pub fn new(
+        inner: key::secp256k1::private_key::Key,
+        chain_id: primitive_types::U256,
+    ) -> io::Result<Self> {
+        let address: Address = inner.to_public_key().to_h160().into();
+        Ok(Self {
+            inner,
+            chain_id,
+            address,
+        })
+    }
+
+    /// Signs the digest and converts to the "ethers-core" recoverable signature.
+    fn sign_digest_rsig(&self, digest: ethers_core::types::H256) -> io::Result<RSig> {
+        let sig = self.inner.sign_digest(digest.as_ref())?;
+        RSig::try_from(sig.to_bytes().as_ref()).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to convert recoverable signature {}", e),
+            )
+        })
+    }
+
+    fn sign_digest_with_eip155(
+        &self,
+        digest: ethers_core::types::H256,
+        chain_id: u64,
+    ) -> io::Result<Signature> {
+        let sig = self.sign_digest_rsig(digest)?;
+
+        let mut sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        key::secp256k1::signature::apply_eip155(&mut sig, chain_id);
+        Ok(sig)
+    }
+}
+
+#[async_trait]
+impl ethers_signers::Signer for LocalSigner {
+    type Error = io::Error;
+
+    /// Implements "eth_sign" using "ethers_core::utils::hash_message".
+    /// ref. <https://eips.ethereum.org/EIPS/eip-191>
+    /// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_sign>
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        let message = message.as_ref();
+        let message_hash = ethers_core::utils::hash_message(message);
+
+        self.sign_digest_with_eip155(message_hash, self.chain_id.as_u64())
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        let mut tx_with_chain = tx.clone();
+        let chain_id = tx_with_chain
+            .chain_id()
+            .map(|id| id.as_u64())
+            .unwrap_or(self.chain_id.as_u64());
+        tx_with_chain.set_chain_id(chain_id);
+
+        let sighash = tx_with_chain.sighash();
+        let sig = self.sign_digest_rsig(sighash)?;
+
+        // EIP-155 for legacy, y-parity for typed transactions
+        let mut sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        key::secp256k1::signature::apply_tx_v(&mut sig, &tx_with_chain, chain_id);
+        Ok(sig)
+    }
+
+    /// Implements "eth_signTypedData".
+    /// The returned "v" is 27 or 28 ("rsig_to_ethsig"), without EIP-155,
+    /// since the EIP-712 domain separator already commits to the chain Id.
+    /// ref. <https://eips.ethereum.org/EIPS/eip-712>
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let digest = payload
+            .encode_eip712()
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed encode_eip712 {}", e)))?;
+
+        let sig = self.sign_digest_rsig(digest.into())?;
+        let sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        Ok(sig)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id.as_u64()
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        let chain_id: u64 = chain_id.into();
+        self.chain_id = primitive_types::U256::from(chain_id);
+        self
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- key::secp256k1::eth_signer::test_local_signer --exact --show-output
+#[test]
+fn test_local_signer() {
+    use ethers_core::types::{Eip1559TransactionRequest, TransactionRequest};
+    use ethers_signers::Signer;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let chain_id = 43114_u64;
+
+    let local_signer = LocalSigner::new(k.clone(), primitive_types::U256::from(chain_id)).unwrap();
+    let local_wallet: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
+    let local_wallet = local_wallet.with_chain_id(chain_id);
+    assert_eq!(local_signer.address(), local_wallet.address());
+    assert_eq!(local_signer.chain_id(), chain_id);
+
+    let to = Address::random();
+    let legacy: TypedTransaction = TransactionRequest::new()
+        .to(to)
+        .value(1)
+        .nonce(3)
+        .gas(21000)
+        .gas_price(25)
+        .into();
+    let eip1559: TypedTransaction = Eip1559TransactionRequest::new()
+        .to(to)
+        .value(1)
+        .nonce(3)
+        .gas(21000)
+        .max_fee_per_gas(50)
+        .max_priority_fee_per_gas(2)
+        .into();
+
+    for tx in [legacy, eip1559] {
+        let sig = ab!(local_signer.sign_transaction(&tx)).unwrap();
+        let wallet_sig = ab!(local_wallet.sign_transaction(&tx)).unwrap();
+
+        // RFC6979 signing is deterministic, so the same "r" and "s"
+        assert_eq!(sig.r, wallet_sig.r);
+        assert_eq!(sig.s, wallet_sig.s);
+        match tx {
+            TypedTransaction::Legacy(_) => {
+                assert!(sig.v == chain_id * 2 + 35 || sig.v == chain_id * 2 + 36)
+            }
+            _ => assert!(sig.v == 0 || sig.v == 1),
+        }
+
+        // the signer's default chain Id is used
+        let mut tx_with_chain = tx.clone();
+        tx_with_chain.set_chain_id(chain_id);
+        let sighash = tx_with_chain.sighash();
+        assert_eq!(sig.recover(sighash).unwrap(), local_signer.address());
+        assert_eq!(wallet_sig.recover(sighash).unwrap(), local_wallet.address());
+    }
+
+    let sig = ab!(local_signer.sign_message("hello")).unwrap();
+    let wallet_sig = ab!(local_wallet.sign_message("hello")).unwrap();
+    assert_eq!(sig.r, wallet_sig.r);
+    assert_eq!(sig.s, wallet_sig.s);
+    assert_eq!(sig.recover("hello").unwrap(), local_signer.address());
+    assert_eq!(wallet_sig.recover("hello").unwrap(), local_wallet.address());
+}
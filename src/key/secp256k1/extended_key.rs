@@ -0,0 +1,317 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::key::secp256k1::private_key::Key;
+use hmac::{Hmac, Mac};
+use k256::{elliptic_curve::PrimeField, FieldBytes, Scalar};
+use sha2::Sha512;
+
+/// The size (in bytes) of the BIP32 chain code.
+pub const CHAIN_CODE_LEN: usize = 32;
+
+/// Child indices at or above this value are hardened.
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#extended-keys>
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// HMAC key to derive the master key from the seed.
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#master-key-generation>
+const MASTER_HMAC_KEY: &[u8] = b"Bitcoin seed";
+
+/// Represents the BIP32 extended private key: the secp256k1 private key
+/// with its chain code, so that child keys can be derived from it.
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedKey {
+    key: Key,
+    chain_code: [u8; CHAIN_CODE_LEN],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+}
+
+impl ExtendedKey {
+    /// Creates the extended key from the private key and the chain code.
+    /// The resulting key is treated as the root (depth 0).
+    pub fn new(key: Key, chain_code: [u8; CHAIN_CODE_LEN]) -> Self {
+        Self {
+            key,
+            chain_code,
+            depth: 0,
+            parent_fingerprint: [0u8; 4],
+            child_number: 0,
+        }
+    }
+
+    /// Derives the master extended key from the seed (e.g., BIP39 seed).
+    /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#master-key-generation>
+    pub fn from_seed(seed: &[u8]) -> io::Result<Self> {
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid seed length {} (expected 16 to 64)", seed.len()),
+            ));
+        }
+
+        let (il, ir) = hmac_sha512(MASTER_HMAC_KEY, &[seed])?;
+        let key = Key::from_bytes(&il)?;
+        Ok(Self::new(key, ir))
+    }
+
+    /// Returns the private key at this level.
+    pub fn private_key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns the chain code at this level.
+    pub fn chain_code(&self) -> [u8; CHAIN_CODE_LEN] {
+        self.chain_code
+    }
+
+    /// Returns the number of derivations from the master key.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the fingerprint of the parent key (zero for the master key).
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// Returns the child number used to derive this key,
+    /// including "HARDENED_OFFSET" if hardened.
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// Returns the first 4 bytes of "RIPEMD160(SHA256(compressed public key))".
+    /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#key-identifiers>
+    pub fn fingerprint(&self) -> io::Result<[u8; 4]> {
+        let b = self.key.to_public_key().to_short_bytes()?;
+
+        let mut fp = [0u8; 4];
+        fp.copy_from_slice(&b[..4]);
+        Ok(fp)
+    }
+
+    /// Derives the child extended key at the index.
+    /// The index must be lower than "HARDENED_OFFSET", and "hardened"
+    /// sets the hardened bit.
+    /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#private-parent-key--private-child-key>
+    pub fn derive_child(&self, index: u32, hardened: bool) -> io::Result<Self> {
+        if index >= HARDENED_OFFSET {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("child index {} out of range", index),
+            ));
+        }
+        if self.depth == u8::MAX {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "maximum derivation depth 255 exceeded",
+            ));
+        }
+
+        let child_number = if hardened {
+            index | HARDENED_OFFSET
+        } else {
+            index
+        };
+        let child_number_bytes = child_number.to_be_bytes();
+
+        let (il, ir) = if hardened {
+            let k = self.key.to_bytes();
+            hmac_sha512(&self.chain_code, &[&[0u8], &k, &child_number_bytes])?
+        } else {
+            let pubkey = self.key.to_public_key().to_compressed_bytes();
+            hmac_sha512(&self.chain_code, &[&pubkey, &child_number_bytes])?
+        };
+
+        // "parse256(IL) + kpar (mod n)"
+        let tweak: Option<Scalar> = Scalar::from_repr(FieldBytes::from(il)).into();
+        let tweak = match tweak {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid child key at index {}, try the next index", index),
+                ));
+            }
+        };
+        let parent: Option<Scalar> =
+            Scalar::from_repr(FieldBytes::from(self.key.to_bytes())).into();
+        let parent = parent.ok_or_else(|| Error::new(ErrorKind::Other, "invalid parent key"))?;
+
+        let child = tweak + parent;
+        let key = Key::from_bytes(&child.to_bytes()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "invalid child key at index {} ({}), try the next index",
+                    index, e
+                ),
+            )
+        })?;
+
+        Ok(Self {
+            key,
+            chain_code: ir,
+            depth: self.depth + 1,
+            parent_fingerprint: self.fingerprint()?,
+            child_number,
+        })
+    }
+
+    /// Derives the extended key following the derivation path (e.g., "m/44'/9000'/0'/0/3").
+    /// Hardened indices are marked with "'" or "h".
+    pub fn derive_path(&self, path: &str) -> io::Result<Self> {
+        let mut cur = self.clone();
+        for (index, hardened) in parse_path(path)? {
+            cur = cur.derive_child(index, hardened)?;
+        }
+        Ok(cur)
+    }
+}
+
+/// Parses the BIP32 derivation path into the list of (index, hardened).
+pub fn parse_path(path: &str) -> io::Result<Vec<(u32, bool)>> {
+    let mut parts = path.trim().split('/');
+    match parts.next() {
+        Some("m") | Some("M") => {}
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("derivation path '{}' must start with 'm'", path),
+            ));
+        }
+    }
+
+    let mut indices = Vec::new();
+    for part in parts {
+        let (num, hardened) = match part.strip_suffix(|c: char| c == '\'' || c == 'h' || c == 'H') {
+            Some(n) => (n, true),
+            None => (part, false),
+        };
+        let index = num.parse::<u32>().map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid index '{}' in derivation path '{}' ({})",
+                    part, path, e
+                ),
+            )
+        })?;
+        if index >= HARDENED_OFFSET {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "index '{}' in derivation path '{}' out of range",
+                    part, path
+                ),
+            ));
+        }
+        indices.push((index, hardened));
+    }
+
+    if indices.len() > u8::MAX as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "derivation path depth {} exceeds maximum 255",
+                indices.len()
+            ),
+        ));
+    }
+    Ok(indices)
+}
+
+/// Computes "HMAC-SHA512(key, data)" and splits the output into 32-byte halves.
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> io::Result<([u8; 32], [u8; 32])> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed Hmac::new_from_slice {}", e),
+        )
+    })?;
+    for d in data {
+        mac.update(d);
+    }
+    let out = mac.finalize().into_bytes();
+
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&out[..32]);
+    ir.copy_from_slice(&out[32..]);
+    Ok((il, ir))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::extended_key::test_extended_key --exact --show-output
+#[test]
+fn test_extended_key() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // test vector 1
+    // ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1>
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let master = ExtendedKey::from_seed(&seed).unwrap();
+    assert_eq!(
+        hex::encode(master.private_key().to_bytes()),
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+    );
+    assert_eq!(
+        hex::encode(master.chain_code()),
+        "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+    );
+
+    let m_0h = master.derive_child(0, true).unwrap();
+    assert_eq!(
+        hex::encode(m_0h.private_key().to_bytes()),
+        "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"
+    );
+    assert_eq!(
+        hex::encode(m_0h.chain_code()),
+        "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141"
+    );
+    assert_eq!(hex::encode(m_0h.parent_fingerprint()), "3442193e");
+
+    let m_0h_1 = m_0h.derive_child(1, false).unwrap();
+    assert_eq!(
+        hex::encode(m_0h_1.private_key().to_bytes()),
+        "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"
+    );
+    assert_eq!(
+        hex::encode(m_0h_1.chain_code()),
+        "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19"
+    );
+
+    let m_0h_1_2h = master.derive_path("m/0'/1/2h").unwrap();
+    assert_eq!(
+        hex::encode(m_0h_1_2h.private_key().to_bytes()),
+        "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca"
+    );
+    assert_eq!(
+        hex::encode(m_0h_1_2h.chain_code()),
+        "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f"
+    );
+    assert_eq!(m_0h_1_2h.depth(), 3);
+    assert_eq!(m_0h_1_2h.child_number(), 2 | HARDENED_OFFSET);
+
+    let m_0h_1_2h_2_1000000000 = m_0h_1_2h.derive_path("m/2/1000000000").unwrap();
+    assert_eq!(
+        hex::encode(m_0h_1_2h_2_1000000000.private_key().to_bytes()),
+        "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+    );
+    assert_eq!(
+        hex::encode(m_0h_1_2h_2_1000000000.chain_code()),
+        "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e"
+    );
+
+    assert!(master.derive_path("44'/9000'").is_err());
+    assert!(master.derive_path("m/x").is_err());
+    assert!(master.derive_path("m/2147483648").is_err());
+    assert!(master.derive_child(HARDENED_OFFSET, false).is_err());
+
+    let too_deep = format!("m{}", "/0".repeat(256));
+    assert!(master.derive_path(&too_deep).is_err());
+}
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io, str::FromStr};
 
 use crate::{ids::short, key};
+use primitive_types::H160;
 use serde::{Deserialize, Serialize};
 
 /// Support multiple keys as a chain.
@@ -10,6 +11,8 @@ use serde::{Deserialize, Serialize};
 pub struct Keychain<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly> {
     pub keys: Vec<T>,
     pub short_addr_to_key_index: HashMap<short::Id, u32>,
+    #[serde(default)]
+    pub h160_addr_to_key_index: HashMap<H160, u32>,
 }
 
 impl<T> Keychain<T>
@@ -18,15 +21,34 @@ where
 {
     pub fn new(keys: Vec<T>) -> Self {
         let mut short_addr_to_key_index = HashMap::new();
+        let mut h160_addr_to_key_index = HashMap::new();
         for (pos, k) in keys.iter().enumerate() {
             short_addr_to_key_index.insert(k.short_address().unwrap(), pos as u32);
+            h160_addr_to_key_index.insert(k.h160_address(), pos as u32);
         }
         Self {
             keys,
             short_addr_to_key_index,
+            h160_addr_to_key_index,
         }
     }
 
+    /// Adds the key to the keychain, and returns "false" if the key
+    /// with the same address is already in the keychain.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Add>
+    pub fn add(&mut self, key: T) -> io::Result<bool> {
+        let short_addr = key.short_address()?;
+        if self.short_addr_to_key_index.contains_key(&short_addr) {
+            return Ok(false);
+        }
+
+        let pos = self.keys.len() as u32;
+        self.short_addr_to_key_index.insert(short_addr, pos);
+        self.h160_addr_to_key_index.insert(key.h160_address(), pos);
+        self.keys.push(key);
+        Ok(true)
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Get>
     pub fn get(&self, short_addr: &short::Id) -> Option<T> {
         self.short_addr_to_key_index
@@ -34,6 +56,31 @@ where
             .map(|k| self.keys[(*k) as usize].clone())
     }
 
+    /// Returns the key that owns the short address.
+    pub fn get_by_short_id(&self, short_addr: &short::Id) -> Option<&T> {
+        self.short_addr_to_key_index
+            .get(short_addr)
+            .map(|k| &self.keys[(*k) as usize])
+    }
+
+    /// Returns the key that owns the Ethereum address (e.g., "0x8db97C7...").
+    /// The address is case-insensitive, regardless of its EIP-55 checksum.
+    pub fn get_by_eth_address(&self, eth_addr: &str) -> Option<&T> {
+        let h160_addr = H160::from_str(eth_addr.trim().trim_start_matches("0x")).ok()?;
+        self.h160_addr_to_key_index
+            .get(&h160_addr)
+            .map(|k| &self.keys[(*k) as usize])
+    }
+
+    /// Returns the keys that own any of the short addresses,
+    /// in the same order of the addresses, skipping the unknown ones.
+    pub fn match_signers(&self, short_addrs: &[short::Id]) -> Vec<&T> {
+        short_addrs
+            .iter()
+            .filter_map(|addr| self.get_by_short_id(addr))
+            .collect()
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Match>
     pub fn match_threshold(
         &self,
@@ -92,3 +139,41 @@ where
         ))
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::keychain::test_keychain --exact --show-output
+#[test]
+fn test_keychain() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    use key::secp256k1::{private_key::Key, ReadOnly};
+
+    let k1 = Key::generate().unwrap();
+    let k2 = Key::generate().unwrap();
+    let k3 = Key::generate().unwrap();
+
+    let mut kc = Keychain::new(vec![k1.clone()]);
+    assert!(kc.add(k2.clone()).unwrap());
+    assert!(!kc.add(k1.clone()).unwrap());
+    assert_eq!(kc.keys.len(), 2);
+
+    let k2_short = k2.short_address().unwrap();
+    assert_eq!(kc.get_by_short_id(&k2_short), Some(&k2));
+    assert_eq!(kc.get(&k2_short), Some(k2.clone()));
+    assert!(kc.get_by_short_id(&k3.short_address().unwrap()).is_none());
+
+    let eth_addr = k1.eth_address();
+    assert_eq!(kc.get_by_eth_address(&eth_addr), Some(&k1));
+    assert_eq!(kc.get_by_eth_address(&eth_addr.to_lowercase()), Some(&k1));
+    assert!(kc.get_by_eth_address(&k3.eth_address()).is_none());
+    assert!(kc.get_by_eth_address("invalid").is_none());
+
+    let signers = kc.match_signers(&[
+        k3.short_address().unwrap(),
+        k2_short,
+        k1.short_address().unwrap(),
+    ]);
+    assert_eq!(signers, vec![&k2, &k1]);
+}
@@ -0,0 +1,438 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{hash, key::secp256k1::private_key::Key};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use hmac::Hmac;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// The keystore file format version.
+/// ref. <https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/>
+pub const VERSION: u64 = 3;
+
+pub const CIPHER_AES_128_CTR: &str = "aes-128-ctr";
+pub const KDF_SCRYPT: &str = "scrypt";
+pub const KDF_PBKDF2: &str = "pbkdf2";
+pub const PRF_HMAC_SHA256: &str = "hmac-sha256";
+
+/// Default scrypt parameters used by "geth" ("StandardScryptN", "StandardScryptP").
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 18;
+pub const DEFAULT_SCRYPT_R: u32 = 8;
+pub const DEFAULT_SCRYPT_P: u32 = 1;
+
+/// Light scrypt parameters used by "geth" ("LightScryptN", "LightScryptP").
+pub const LIGHT_SCRYPT_LOG_N: u8 = 12;
+pub const LIGHT_SCRYPT_P: u32 = 6;
+
+/// Upper bounds of the KDF parameters read from the keystore file,
+/// so that a crafted file cannot exhaust the memory (e.g., "n=2^31, r=8"
+/// allocates ~2 TiB) or hang the decryption (e.g., "c=u32::MAX").
+pub const MAX_SCRYPT_LOG_N: u8 = 20;
+pub const MAX_SCRYPT_R: u32 = 32;
+pub const MAX_SCRYPT_P: u32 = 16;
+pub const MAX_PBKDF2_C: u32 = 10_000_000;
+
+/// The size (in bytes) of the derived key.
+const DKLEN: usize = 32;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// Represents the Web3 Secret Storage (keystore JSON v3).
+/// ref. <https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Keystore {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub crypto: Crypto,
+    pub id: String,
+    pub version: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Crypto {
+    pub cipher: String,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+/// Holds the parameters for either "scrypt" or "pbkdf2".
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct KdfParams {
+    pub dklen: usize,
+    pub salt: String,
+
+    /// scrypt CPU/memory cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u64>,
+    /// scrypt block size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r: Option<u32>,
+    /// scrypt parallelization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p: Option<u32>,
+
+    /// pbkdf2 iteration count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c: Option<u32>,
+    /// pbkdf2 pseudo-random function (only "hmac-sha256" is supported).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prf: Option<String>,
+}
+
+impl Keystore {
+    /// Encrypts the private key with the password, using scrypt
+    /// with the default "geth" parameters.
+    pub fn encrypt(key: &Key, password: &str) -> io::Result<Self> {
+        Self::encrypt_with_scrypt_params(
+            key,
+            password,
+            DEFAULT_SCRYPT_LOG_N,
+            DEFAULT_SCRYPT_R,
+            DEFAULT_SCRYPT_P,
+        )
+    }
+
+    /// Encrypts the private key with the password, using scrypt
+    /// with the given parameters (e.g., "LIGHT_SCRYPT_LOG_N").
+    pub fn encrypt_with_scrypt_params(
+        key: &Key,
+        password: &str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+    ) -> io::Result<Self> {
+        let mut salt = [0u8; 32];
+        OsRng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        OsRng.fill_bytes(&mut iv);
+
+        let kdfparams = KdfParams {
+            dklen: DKLEN,
+            salt: hex::encode(salt),
+            n: Some(1 << log_n),
+            r: Some(r),
+            p: Some(p),
+            c: None,
+            prf: None,
+        };
+        let derived = derive_key(KDF_SCRYPT, &kdfparams, password)?;
+
+        let mut ciphertext = key.secret_scalar();
+        let mut cipher = Aes128Ctr::new(derived[..16].into(), &iv.into());
+        cipher.apply_keystream(ciphertext.as_mut());
+
+        let mac = compute_mac(&derived, ciphertext.as_ref());
+
+        let mut id = [0u8; 16];
+        OsRng.fill_bytes(&mut id);
+
+        Ok(Self {
+            address: Some(
+                key.to_public_key()
+                    .to_eth_address()
+                    .trim_start_matches("0x")
+                    .to_lowercase(),
+            ),
+            crypto: Crypto {
+                cipher: CIPHER_AES_128_CTR.to_string(),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                ciphertext: hex::encode(ciphertext.as_ref()),
+                kdf: KDF_SCRYPT.to_string(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+            id: uuid_v4(id),
+            version: VERSION,
+        })
+    }
+
+    /// Decrypts the private key with the password.
+    /// Returns an error if the MAC does not match (e.g., wrong password).
+    /// The scrypt parameters must satisfy "n < 2^(16r)" (RFC 7914): geth never
+    /// writes "r" other than 8, but the keystores with "r=1" and "n >= 2^16"
+    /// (e.g., the Web3 Secret Storage scrypt test vector) are rejected,
+    /// and must be re-encrypted (e.g., with geth) to be loaded.
+    /// The KDF parameters above "MAX_SCRYPT_*" or "MAX_PBKDF2_C" are rejected.
+    pub fn decrypt(&self, password: &str) -> io::Result<Key> {
+        if self.version != VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported keystore version {}", self.version),
+            ));
+        }
+        if self.crypto.cipher != CIPHER_AES_128_CTR {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported keystore cipher '{}'", self.crypto.cipher),
+            ));
+        }
+
+        let derived = derive_key(&self.crypto.kdf, &self.crypto.kdfparams, password)?;
+
+        let ciphertext = decode_hex("ciphertext", &self.crypto.ciphertext)?;
+        let expected_mac = decode_hex("mac", &self.crypto.mac)?;
+        let mac_matches: bool = compute_mac(&derived, &ciphertext)
+            .as_slice()
+            .ct_eq(expected_mac.as_slice())
+            .into();
+        if !mac_matches {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "keystore MAC mismatch (wrong password or corrupted file)",
+            ));
+        }
+
+        let iv = decode_hex("iv", &self.crypto.cipherparams.iv)?;
+        if iv.len() != 16 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid iv length {}", iv.len()),
+            ));
+        }
+
+        let mut plaintext = Zeroizing::new(ciphertext);
+        let mut cipher = Aes128Ctr::new(derived[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(plaintext.as_mut_slice());
+
+        Ok(Key::from_bytes(&plaintext)?)
+    }
+}
+
+impl Key {
+    /// Encrypts the private key to the keystore JSON v3 (e.g., geth, ethers).
+    pub fn to_keystore_json(&self, password: &str) -> io::Result<String> {
+        let ks = Keystore::encrypt(self, password)?;
+        serde_json::to_string(&ks)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+
+    /// Decrypts the private key from the keystore JSON v3 (e.g., geth, ethers).
+    /// See "Keystore::decrypt" for the unsupported scrypt parameters.
+    pub fn from_keystore_json(json: &str, password: &str) -> io::Result<Self> {
+        let ks: Keystore = serde_json::from_str(json)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {}", e)))?;
+        ks.decrypt(password)
+    }
+}
+
+fn derive_key(kdf: &str, params: &KdfParams, password: &str) -> io::Result<Zeroizing<[u8; DKLEN]>> {
+    if params.dklen != DKLEN {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unsupported dklen {}", params.dklen),
+        ));
+    }
+    let salt = decode_hex("salt", &params.salt)?;
+
+    let mut derived = Zeroizing::new([0u8; DKLEN]);
+    match kdf {
+        KDF_SCRYPT => {
+            let (n, r, p) = match (params.n, params.r, params.p) {
+                (Some(n), Some(r), Some(p)) => (n, r, p),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "missing scrypt parameters 'n', 'r', or 'p'",
+                    ));
+                }
+            };
+            if n < 2 || !n.is_power_of_two() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid scrypt parameter n {}", n),
+                ));
+            }
+            let log_n = n.trailing_zeros() as u8;
+            if log_n > MAX_SCRYPT_LOG_N || r > MAX_SCRYPT_R || p > MAX_SCRYPT_P {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "scrypt parameters n {} r {} p {} exceed the limits n {} r {} p {}",
+                        n,
+                        r,
+                        p,
+                        1u64 << MAX_SCRYPT_LOG_N,
+                        MAX_SCRYPT_R,
+                        MAX_SCRYPT_P
+                    ),
+                ));
+            }
+            // checked here for the clearer error, the "scrypt" crate
+            // enforces "n < 2^(16r)" (RFC 7914) while geth does not
+            if u32::from(log_n) >= r.saturating_mul(16) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "unsupported scrypt parameters n {} and r {} (RFC 7914 requires n < 2^(16r), re-encrypt the keystore with r {})",
+                        n, r, DEFAULT_SCRYPT_R
+                    ),
+                ));
+            }
+
+            let sparams = scrypt::Params::new(log_n, r, p).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid scrypt parameters ({})", e),
+                )
+            })?;
+            scrypt::scrypt(password.as_bytes(), &salt, &sparams, derived.as_mut())
+                .map_err(|e| Error::new(ErrorKind::Other, format!("failed scrypt ({})", e)))?;
+        }
+        KDF_PBKDF2 => {
+            let prf = params.prf.as_deref().unwrap_or(PRF_HMAC_SHA256);
+            if prf != PRF_HMAC_SHA256 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("unsupported pbkdf2 prf '{}'", prf),
+                ));
+            }
+            let c = params.c.ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, "missing pbkdf2 parameter 'c'")
+            })?;
+            if c > MAX_PBKDF2_C {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "pbkdf2 parameter c {} exceeds the limit {}",
+                        c, MAX_PBKDF2_C
+                    ),
+                ));
+            }
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), &salt, c, derived.as_mut());
+        }
+        other => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported keystore kdf '{}'", other),
+            ));
+        }
+    }
+    Ok(derived)
+}
+
+/// "Keccak256(DK[16..32] ++ ciphertext)"
+fn compute_mac(derived: &[u8; DKLEN], ciphertext: &[u8]) -> [u8; 32] {
+    let mut b = Vec::with_capacity(16 + ciphertext.len());
+    b.extend_from_slice(&derived[16..32]);
+    b.extend_from_slice(ciphertext);
+    hash::keccak256(&b).0
+}
+
+fn decode_hex(field: &str, s: &str) -> io::Result<Vec<u8>> {
+    hex::decode(s).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed hex::decode '{}' ({})", field, e),
+        )
+    })
+}
+
+/// Formats the random bytes as the version 4 UUID.
+fn uuid_v4(mut b: [u8; 16]) -> String {
+    b[6] = (b[6] & 0x0f) | 0x40;
+    b[8] = (b[8] & 0x3f) | 0x80;
+    let h = hex::encode(b);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &h[0..8],
+        &h[8..12],
+        &h[12..16],
+        &h[16..20],
+        &h[20..32]
+    )
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="keystore" -- key::secp256k1::keystore::test_keystore --exact --show-output
+#[test]
+fn test_keystore() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // ref. <https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/#test-vectors>
+    let expected = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    let pbkdf2_json = r#"{"crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"6087dab2f9fdbbfaddc31a909735c1e6"},"ciphertext":"5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46","kdf":"pbkdf2","kdfparams":{"c":262144,"dklen":32,"prf":"hmac-sha256","salt":"ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"},"mac":"517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"},"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","version":3}"#;
+    let key = Key::from_keystore_json(pbkdf2_json, "testpassword").unwrap();
+    assert_eq!(hex::encode(key.to_bytes()), expected);
+    assert!(Key::from_keystore_json(pbkdf2_json, "wrongpassword").is_err());
+
+    // the scrypt test vector uses "r=1" with "n=2^18", which violates "n < 2^(16r)" (RFC 7914)
+    let scrypt_r1_json = r#"{"crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"83dbcc02d8ccb40e466191a123791e0e"},"ciphertext":"d172bf743a674da9cdad04534d56926ef8358534d458fffccd4e6ad2fbde479c","kdf":"scrypt","kdfparams":{"dklen":32,"n":262144,"p":8,"r":1,"salt":"ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"},"mac":"2103ac29920d71da29f15d75b4a16dbe95cfd7ff8faea1056c33131d846e3097"},"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","version":3}"#;
+    let err = Key::from_keystore_json(scrypt_r1_json, "testpassword").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("RFC 7914"), "{}", err);
+
+    // generated by geth with the scrypt "r=8" and the password "foobar"
+    // ref. <https://github.com/ethereum/go-ethereum/blob/master/accounts/keystore/testdata/keystore/aaa>
+    let geth_json = r#"{"address":"f466859ead1932d743d622cb74fc058882e8648a","crypto":{"cipher":"aes-128-ctr","ciphertext":"cb664472deacb41a2e995fa7f96fe29ce744471deb8d146a0e43c7898c9ddd4d","cipherparams":{"iv":"dfd9ee70812add5f4b8f89d0811c9158"},"kdf":"scrypt","kdfparams":{"dklen":32,"n":8,"p":16,"r":8,"salt":"0d6769bf016d45c479213990d6a08d938469c4adad8a02ce507b4a4e7b7739f1"},"mac":"bac9af994b15a45dd39669fc66f9aa8a3b9dd8c22cb16e4d8d7ea089d0f1a1a9"},"id":"472e8b3d-afb6-45b5-8111-72c89895099a","version":3}"#;
+    let key = Key::from_keystore_json(geth_json, "foobar").unwrap();
+    assert_eq!(
+        hex::encode(key.to_bytes()),
+        "539f9b4106fb452408e1ee43d177077f057a8fdc1e1fad92c61e68982b4e3c4b"
+    );
+    assert_eq!(
+        hex::encode(key.to_public_key().to_h160()),
+        "f466859ead1932d743d622cb74fc058882e8648a"
+    );
+    let err = Key::from_keystore_json(geth_json, "foobaz").unwrap_err();
+    assert!(err.to_string().contains("MAC mismatch"));
+
+    assert!(Key::from_keystore_json(
+        &pbkdf2_json.replace(r#""kdf":"pbkdf2""#, r#""kdf":"unknown""#),
+        "testpassword"
+    )
+    .is_err());
+
+    // KDF parameters beyond the limits are rejected before deriving the key
+    let err = Key::from_keystore_json(
+        &pbkdf2_json.replace(r#""c":262144"#, r#""c":4294967295"#),
+        "testpassword",
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("exceeds the limit"), "{}", err);
+    for (from, to) in [
+        (r#""n":8"#, r#""n":2147483648"#),
+        (r#""r":8"#, r#""r":1024"#),
+        (r#""p":16"#, r#""p":4096"#),
+    ] {
+        let err = Key::from_keystore_json(&geth_json.replace(from, to), "foobar").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("exceed the limits"), "{}", err);
+    }
+
+    let key = Key::generate().unwrap();
+    let ks = Keystore::encrypt_with_scrypt_params(
+        &key,
+        "secret",
+        LIGHT_SCRYPT_LOG_N,
+        DEFAULT_SCRYPT_R,
+        LIGHT_SCRYPT_P,
+    )
+    .unwrap();
+    assert_eq!(ks.crypto.kdf, KDF_SCRYPT);
+    let json = serde_json::to_string(&ks).unwrap();
+    log::info!("keystore: {}", json);
+    assert!(!json.contains(&hex::encode(key.to_bytes())));
+
+    let loaded = Key::from_keystore_json(&json, "secret").unwrap();
+    assert_eq!(key, loaded);
+
+    let err = Key::from_keystore_json(&json, "not-secret").unwrap_err();
+    assert!(err.to_string().contains("MAC mismatch"));
+}
@@ -64,10 +64,17 @@ impl<'a> ethers_signers::Signer for Signer {
         tx_with_chain.set_chain_id(chain_id);
 
         let sighash = tx_with_chain.sighash();
-        self.sign_digest_with_eip155(sighash, chain_id).await
+        let sig = self.inner.sign_digest(sighash.as_ref()).await?;
+
+        // EIP-155 for legacy, y-parity for typed transactions
+        let mut sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        key::secp256k1::signature::apply_tx_v(&mut sig, &tx_with_chain, chain_id);
+        Ok(sig)
     }
 
     /// Implements "eth_signTypedData".
+    /// The returned "v" is 27 or 28 ("rsig_to_ethsig"), without EIP-155,
+    /// since the EIP-712 domain separator already commits to the chain Id.
     /// ref. <https://eips.ethereum.org/EIPS/eip-712>
     async fn sign_typed_data<T: Eip712 + Send + Sync>(
         &self,
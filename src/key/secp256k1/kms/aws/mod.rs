@@ -2,6 +2,7 @@ pub mod eth_signer;
 
 use std::{
     collections::HashMap,
+    future::Future,
     io::{self, Error, ErrorKind},
 };
 
@@ -10,7 +11,8 @@ use async_trait::async_trait;
 use aws_manager::kms;
 use aws_sdk_kms::model::{KeySpec, KeyUsageType};
 use ethers_core::k256::ecdsa::recoverable::Signature as RSig;
-use tokio::time::{sleep, Duration, Instant};
+use rand::Rng;
+use tokio::time::{sleep, Duration};
 
 /// Represents AWS KMS asymmetric elliptic curve key pair ECC_SECG_P256K1.
 /// Note that the actual private key never leaves KMS.
@@ -29,10 +31,78 @@ pub struct Cmk {
     /// Public key.
     pub public_key: key::secp256k1::public_key::Key,
 
-    /// Total duration for retries.
-    pub retry_timeout: Duration,
-    /// Interval between retries.
-    pub retry_interval: Duration,
+    /// Retries for the KMS sign API.
+    pub retry_config: RetryConfig,
+}
+
+/// Configures the retries with exponential backoff and jitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on each retry.
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Caps the exponential growth at "base_delay * 2^MAX_BACKOFF_EXP".
+const MAX_BACKOFF_EXP: u32 = 6;
+
+impl RetryConfig {
+    /// Returns the delay after the failed attempt (starting at 1):
+    /// half of "base_delay * 2^(attempt - 1)" plus a random jitter up to the other half.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(MAX_BACKOFF_EXP);
+        let delay = self.base_delay * 2_u32.pow(exp);
+
+        let half = delay / 2;
+        let jitter_nanos = rand::thread_rng().gen_range(0..=half.as_nanos() as u64);
+        half + Duration::from_nanos(jitter_nanos)
+    }
+}
+
+/// Calls "f" until it succeeds, it fails with a non-retryable error
+/// (e.g., "InvalidSignatureException"), or "max_attempts" is reached.
+async fn retry_with_backoff<T, F, Fut>(
+    retry_config: &RetryConfig,
+    mut f: F,
+) -> Result<T, aws_manager::errors::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, aws_manager::errors::Error>>,
+{
+    let mut attempt = 0_u32;
+    loop {
+        attempt += 1;
+        match f().await {
+            Ok(v) => return Ok(v),
+            Err(aerr) => {
+                log::warn!(
+                    "[attempt {attempt}] failed {} (retriable {})",
+                    aerr,
+                    aerr.is_retryable()
+                );
+                if !aerr.is_retryable() {
+                    return Err(aerr);
+                }
+                if attempt >= retry_config.max_attempts {
+                    return Err(aws_manager::errors::Error::API {
+                        message: format!("failed after {} attempts ({})", attempt, aerr),
+                        is_retryable: false,
+                    });
+                }
+                sleep(retry_config.backoff(attempt)).await;
+            }
+        }
+    }
 }
 
 impl Cmk {
@@ -93,8 +163,7 @@ impl Cmk {
                 public_key,
                 id,
                 arn: arn.to_string(),
-                retry_timeout: Duration::from_secs(90),
-                retry_interval: Duration::from_secs(10),
+                retry_config: RetryConfig::default(),
             });
         }
 
@@ -109,19 +178,28 @@ impl Cmk {
             .map_err(|e| Error::new(ErrorKind::Other, format!("failed schedule_to_delete {}", e)))
     }
 
+    /// Returns the public key, which is fetched once by "from_arn"
+    /// ("GetPublicKey") and cached for the lifetime of this "Cmk",
+    /// so this never calls the KMS API (e.g., in "eth_signer::Signer::new").
+    /// Reload with "from_arn" to pick up a different key
+    /// (e.g., after the alias is rotated to a new CMK).
     pub fn to_public_key(&self) -> key::secp256k1::public_key::Key {
         self.public_key
     }
 
     /// Converts to Info.
-    pub fn to_info(&self, network_id: u32) -> io::Result<key::secp256k1::Info> {
+    pub fn to_info(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+    ) -> io::Result<key::secp256k1::Info> {
+        let network_id = network_id.into();
         let short_addr = self.public_key.to_short_id()?;
         let eth_addr = self.public_key.to_eth_address();
         let h160_addr = self.public_key.to_h160();
 
         let mut addresses = HashMap::new();
         addresses.insert(
-            network_id,
+            network_id.as_u32(),
             key::secp256k1::ChainAddresses {
                 x: self.public_key.to_hrp_address(network_id, "X")?,
                 p: self.public_key.to_hrp_address(network_id, "P")?,
@@ -142,54 +220,19 @@ impl Cmk {
         })
     }
 
+    /// Signs the 32-byte digest with the KMS key, retrying the retryable
+    /// failures (see "RetryConfig").
+    /// Fails without calling the KMS API if the digest is not 32-byte.
     pub async fn sign_digest(&self, digest: &[u8]) -> Result<RSig, aws_manager::errors::Error> {
-        // ref. "crypto/sha256.Size"
-        assert_eq!(digest.len(), hash::SHA256_OUTPUT_LEN);
-
-        let (start, mut success) = (Instant::now(), false);
-        let mut round = 0_u32;
+        let fixed_digest = to_fixed_digest(digest)?;
 
         // DER-encoded >65-byte signature, need convert to 65-byte recoverable signature
         // ref. <https://docs.aws.amazon.com/kms/latest/APIReference/API_Sign.html#KMS-Sign-response-Signature>
-        let mut raw_der = Vec::new();
-        loop {
-            round = round + 1;
-            let elapsed = start.elapsed();
-            if elapsed.gt(&self.retry_timeout) {
-                break;
-            }
-
-            raw_der = match self
-                .kms_manager
+        let raw_der = retry_with_backoff(&self.retry_config, || {
+            self.kms_manager
                 .sign_digest_secp256k1_ecdsa_sha256(&self.id, digest)
-                .await
-            {
-                Ok(raw) => {
-                    success = true;
-                    raw
-                }
-                Err(aerr) => {
-                    log::warn!(
-                        "[round {round}] failed sign {} (retriable {})",
-                        aerr,
-                        aerr.is_retryable()
-                    );
-                    if !aerr.is_retryable() {
-                        return Err(aerr);
-                    }
-
-                    sleep(self.retry_interval).await;
-                    continue;
-                }
-            };
-            break;
-        }
-        if !success {
-            return Err(aws_manager::errors::Error::API {
-                message: "failed sign after retries".to_string(),
-                is_retryable: false,
-            });
-        }
+        })
+        .await?;
 
         let sig = key::secp256k1::signature::decode_signature(&raw_der).map_err(|e| {
             aws_manager::errors::Error::Other {
@@ -198,8 +241,6 @@ impl Cmk {
             }
         })?;
 
-        let mut fixed_digest = [0u8; hash::SHA256_OUTPUT_LEN];
-        fixed_digest.copy_from_slice(digest);
         Ok(
             key::secp256k1::signature::rsig_from_digest_bytes_trial_recovery(
                 &sig,
@@ -210,6 +251,24 @@ impl Cmk {
     }
 }
 
+/// Checks the digest length, as a non-retryable error so that
+/// "retry_with_backoff" never retries it.
+fn to_fixed_digest(
+    digest: &[u8],
+) -> Result<[u8; hash::SHA256_OUTPUT_LEN], aws_manager::errors::Error> {
+    // ref. "crypto/sha256.Size"
+    <[u8; hash::SHA256_OUTPUT_LEN]>::try_from(digest).map_err(|_| {
+        aws_manager::errors::Error::Other {
+            message: format!(
+                "invalid digest length {} (expected {})",
+                digest.len(),
+                hash::SHA256_OUTPUT_LEN
+            ),
+            is_retryable: false,
+        }
+    })
+}
+
 #[async_trait]
 impl key::secp256k1::SignOnly for Cmk {
     type Error = aws_manager::errors::Error;
@@ -218,6 +277,10 @@ impl key::secp256k1::SignOnly for Cmk {
         Err(Error::new(ErrorKind::Other, "not implemented"))
     }
 
+    fn public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+        Ok(self.to_public_key())
+    }
+
     async fn sign_digest(&self, msg: &[u8]) -> Result<[u8; 65], aws_manager::errors::Error> {
         let sig = self.sign_digest(msg).await?;
 
@@ -255,3 +318,93 @@ impl key::secp256k1::ReadOnly for Cmk {
         self.to_public_key().to_h160()
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="kms_aws" -- key::secp256k1::kms::aws::test_retry_with_backoff --exact --show-output
+#[test]
+fn test_retry_with_backoff() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let retry_config = RetryConfig {
+        max_attempts: 5,
+        base_delay: Duration::from_millis(1),
+    };
+
+    // fails twice with throttling, then succeeds
+    let calls = &AtomicU32::new(0);
+    let ret = ab!(retry_with_backoff(&retry_config, || async move {
+        if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+            return Err(aws_manager::errors::Error::API {
+                message: "ThrottlingException".to_string(),
+                is_retryable: true,
+            });
+        }
+        Ok(vec![1u8])
+    }));
+    assert_eq!(ret.unwrap(), vec![1u8]);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // permanent failures are not retried
+    let calls = &AtomicU32::new(0);
+    let ret: Result<(), _> = ab!(retry_with_backoff(&retry_config, || async move {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Err(aws_manager::errors::Error::API {
+            message: "InvalidSignatureException".to_string(),
+            is_retryable: false,
+        })
+    }));
+    assert!(ret.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // gives up after max attempts
+    let calls = &AtomicU32::new(0);
+    let ret: Result<(), _> = ab!(retry_with_backoff(&retry_config, || async move {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Err(aws_manager::errors::Error::API {
+            message: "ThrottlingException".to_string(),
+            is_retryable: true,
+        })
+    }));
+    assert!(!ret.unwrap_err().is_retryable());
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+
+    // backoff grows exponentially within the jitter range
+    let retry_config = RetryConfig {
+        max_attempts: 10,
+        base_delay: Duration::from_millis(100),
+    };
+    for attempt in 1..=10 {
+        let exp = (attempt - 1).min(MAX_BACKOFF_EXP);
+        let delay = Duration::from_millis(100) * 2_u32.pow(exp);
+        let backoff = retry_config.backoff(attempt);
+        assert!(backoff >= delay / 2 && backoff <= delay);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="kms_aws" -- key::secp256k1::kms::aws::test_to_fixed_digest --exact --show-output
+#[test]
+fn test_to_fixed_digest() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let digest = hash::sha256(b"hello");
+    assert_eq!(to_fixed_digest(&digest).unwrap()[..], digest[..]);
+
+    for len in [0, 1, 31, 33, 64] {
+        let err = to_fixed_digest(&vec![1u8; len]).unwrap_err();
+        assert!(!err.is_retryable());
+        assert!(err.to_string().contains("invalid digest length"), "{}", err);
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use crate::{
+    hash,
+    key::{self, secp256k1::signature::Sig},
+};
+use async_trait::async_trait;
+use base64::Engine;
+use ethers_core::{
+    k256::ecdsa::recoverable::Signature as RSig,
+    types::{
+        transaction::{eip2718::TypedTransaction, eip712::Eip712},
+        Address, Signature,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// Azure Key Vault REST API version.
+/// ref. <https://learn.microsoft.com/en-us/rest/api/keyvault/keys/sign/sign>
+pub const API_VERSION: &str = "7.4";
+
+/// Provides the OAuth2 access token for the "https://vault.azure.net" resource
+/// (e.g., "azure_identity::DefaultAzureCredential").
+#[async_trait]
+pub trait TokenProvider: Send + Sync + fmt::Debug {
+    async fn token(&self) -> io::Result<String>;
+}
+
+/// ref. <https://learn.microsoft.com/en-us/rest/api/keyvault/keys/get-key/get-key>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+struct KeyBundle {
+    key: JsonWebKey,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+struct JsonWebKey {
+    kid: String,
+    kty: String,
+    crv: Option<String>,
+    x: Option<String>,
+    y: Option<String>,
+}
+
+/// ref. <https://learn.microsoft.com/en-us/rest/api/keyvault/keys/sign/sign>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+struct KeyOperationParameters {
+    alg: String,
+    value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+struct KeyOperationResult {
+    kid: String,
+    value: String,
+}
+
+const CURVE_SECP256K1: &str = "P-256K";
+const ALGORITHM_ES256K: &str = "ES256K";
+
+/// Implements "ethers_signers::Signer" with the Azure Key Vault
+/// elliptic curve key (P-256K). Note that the actual private key
+/// never leaves Key Vault.
+/// ref. <https://learn.microsoft.com/en-us/azure/key-vault/keys/about-keys-details>
+#[derive(Clone, Debug)]
+pub struct Signer {
+    pub http_client: reqwest::Client,
+    pub token_provider: Arc<dyn TokenProvider>,
+
+    /// Key identifier with its version
+    /// (e.g., "https://{vault}.vault.azure.net/keys/{name}/{version}").
+    pub kid: String,
+    /// Public key.
+    pub public_key: key::secp256k1::public_key::Key,
+
+    pub chain_id: primitive_types::U256,
+    pub address: Address,
+}
+
+impl Signer {
+    /// Loads the latest version of the key from the vault
+    /// (e.g., "https://{vault}.vault.azure.net").
+    pub async fn new(
+        http_client: reqwest::Client,
+        token_provider: Arc<dyn TokenProvider>,
+        vault_url: &str,
+        key_name: &str,
+        chain_id: primitive_types::U256,
+    ) -> io::Result<Self> {
+        let url = format!(
+            "{}/keys/{}?api-version={}",
+            vault_url.trim_end_matches('/'),
+            key_name,
+            API_VERSION
+        );
+        let body = send(
+            &http_client,
+            token_provider.as_ref(),
+            reqwest::Method::GET,
+            &url,
+            None,
+        )
+        .await?;
+        let bundle: KeyBundle = serde_json::from_str(&body).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to parse get key response {}", e),
+            )
+        })?;
+        let public_key = bundle.key.to_public_key()?;
+        log::info!(
+            "fetched Key Vault public key with ETH address '{}'",
+            public_key.to_eth_address(),
+        );
+
+        let address: Address = public_key.to_h160().into();
+        Ok(Self {
+            http_client,
+            token_provider,
+            kid: bundle.key.kid,
+            public_key,
+            chain_id,
+            address,
+        })
+    }
+
+    pub fn to_public_key(&self) -> key::secp256k1::public_key::Key {
+        self.public_key
+    }
+
+    /// Signs the 32-byte digest with "ES256K", and reconstructs the recovery Id
+    /// by matching the recovered public key against the known one.
+    pub async fn sign_digest(&self, digest: &[u8]) -> io::Result<Sig> {
+        // ref. "crypto/sha256.Size"
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid digest length {} (expected {})",
+                    digest.len(),
+                    hash::SHA256_OUTPUT_LEN
+                ),
+            ));
+        }
+
+        let req = KeyOperationParameters {
+            alg: ALGORITHM_ES256K.to_string(),
+            value: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest),
+        };
+        let req = serde_json::to_string(&req)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))?;
+
+        let url = format!("{}/sign?api-version={}", self.kid, API_VERSION);
+        let body = send(
+            &self.http_client,
+            self.token_provider.as_ref(),
+            reqwest::Method::POST,
+            &url,
+            Some(req),
+        )
+        .await?;
+        let resp: KeyOperationResult = serde_json::from_str(&body).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to parse sign response {}", e),
+            )
+        })?;
+
+        // 64-byte "r || s" (not DER-encoded)
+        let raw = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(resp.value.trim_end_matches('='))
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("failed to decode signature {}", e),
+                )
+            })?;
+        let sig = k256::ecdsa::Signature::try_from(raw.as_slice()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to load signature {}", e),
+            )
+        })?;
+
+        let mut fixed_digest = [0u8; hash::SHA256_OUTPUT_LEN];
+        fixed_digest.copy_from_slice(digest);
+        key::secp256k1::signature::sig_from_digest_trial_recovery(
+            &sig,
+            &fixed_digest,
+            &self.public_key,
+        )
+    }
+
+    /// Signs the digest and converts to the "ethers-core" recoverable signature.
+    async fn sign_digest_rsig(&self, digest: ethers_core::types::H256) -> io::Result<RSig> {
+        let sig = self.sign_digest(digest.as_ref()).await?;
+        RSig::try_from(sig.to_bytes().as_ref()).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to convert recoverable signature {}", e),
+            )
+        })
+    }
+
+    async fn sign_digest_with_eip155(
+        &self,
+        digest: ethers_core::types::H256,
+        chain_id: u64,
+    ) -> io::Result<Signature> {
+        let sig = self.sign_digest_rsig(digest).await?;
+
+        let mut sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        key::secp256k1::signature::apply_eip155(&mut sig, chain_id);
+        Ok(sig)
+    }
+}
+
+impl JsonWebKey {
+    /// Converts the JSON web key "x" and "y" coordinates to the public key.
+    fn to_public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+        if self.kty != "EC" && self.kty != "EC-HSM" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unexpected key type '{}'", self.kty),
+            ));
+        }
+        if self.crv.as_deref() != Some(CURVE_SECP256K1) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "unexpected curve '{:?}' (expected '{}')",
+                    self.crv, CURVE_SECP256K1
+                ),
+            ));
+        }
+
+        // uncompressed SEC1 "0x04 || x || y"
+        let mut b = vec![0x04];
+        for (name, coord) in [("x", &self.x), ("y", &self.y)] {
+            let coord = coord
+                .as_ref()
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("missing '{}'", name)))?;
+            let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(coord.trim_end_matches('='))
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("failed to decode '{}' {}", name, e),
+                    )
+                })?;
+            if decoded.len() != 32 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid '{}' length {}", name, decoded.len()),
+                ));
+            }
+            b.extend_from_slice(&decoded);
+        }
+        key::secp256k1::public_key::Key::from_sec1_bytes(&b)
+    }
+}
+
+/// Sends the request with the bearer token, and returns the response body.
+async fn send(
+    http_client: &reqwest::Client,
+    token_provider: &dyn TokenProvider,
+    method: reqwest::Method,
+    url: &str,
+    body: Option<String>,
+) -> io::Result<String> {
+    let token = token_provider.token().await?;
+
+    let mut req = http_client
+        .request(method, url)
+        .bearer_auth(token)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    if let Some(b) = body {
+        req = req.body(b);
+    }
+
+    let resp = req
+        .send()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed {} {}", url, e)))?;
+    let status = resp.status();
+    let text = resp
+        .text()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to read response {}", e)))?;
+    if !status.is_success() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("failed {} {} '{}'", url, status, text),
+        ));
+    }
+    Ok(text)
+}
+
+#[async_trait]
+impl ethers_signers::Signer for Signer {
+    type Error = io::Error;
+
+    /// Implements "eth_sign" using "ethers_core::utils::hash_message".
+    /// ref. <https://eips.ethereum.org/EIPS/eip-191>
+    /// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_sign>
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        let message = message.as_ref();
+        let message_hash = ethers_core::utils::hash_message(message);
+
+        self.sign_digest_with_eip155(message_hash, self.chain_id.as_u64())
+            .await
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        let mut tx_with_chain = tx.clone();
+        let chain_id = tx_with_chain
+            .chain_id()
+            .map(|id| id.as_u64())
+            .unwrap_or(self.chain_id.as_u64());
+        tx_with_chain.set_chain_id(chain_id);
+
+        let sighash = tx_with_chain.sighash();
+        let sig = self.sign_digest_rsig(sighash).await?;
+
+        // EIP-155 for legacy, y-parity for typed transactions
+        let mut sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        key::secp256k1::signature::apply_tx_v(&mut sig, &tx_with_chain, chain_id);
+        Ok(sig)
+    }
+
+    /// Implements "eth_signTypedData".
+    /// ref. <https://eips.ethereum.org/EIPS/eip-712>
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let digest = payload
+            .encode_eip712()
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed encode_eip712 {}", e)))?;
+
+        let sig = self.sign_digest_rsig(digest.into()).await?;
+        let sig = key::secp256k1::signature::rsig_to_ethsig(&sig);
+        Ok(sig)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id.as_u64()
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        let chain_id: u64 = chain_id.into();
+        self.chain_id = primitive_types::U256::from(chain_id);
+        self
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="kms_azure" -- key::secp256k1::kms::azure::test_json_web_key --exact --show-output
+#[test]
+fn test_json_web_key() {
+    let pk = key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+    let b = pubkey.to_uncompressed_bytes();
+
+    let jwk: JsonWebKey = serde_json::from_str(&format!(
+        "{{\"kid\":\"https://test.vault.azure.net/keys/test/1\",\"kty\":\"EC-HSM\",\"crv\":\"P-256K\",\"x\":\"{}\",\"y\":\"{}\"}}",
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&b[1..33]),
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&b[33..]),
+    ))
+    .unwrap();
+    assert_eq!(jwk.to_public_key().unwrap(), pubkey);
+
+    let mut wrong_curve = jwk.clone();
+    wrong_curve.crv = Some("P-256".to_string());
+    assert!(wrong_curve.to_public_key().is_err());
+
+    let mut missing = jwk;
+    missing.y = None;
+    assert!(missing.to_public_key().is_err());
+}
@@ -0,0 +1,386 @@
+pub mod eth_signer;
+
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use crate::{
+    hash,
+    ids::short,
+    key::{self, secp256k1::signature::Sig},
+};
+use async_trait::async_trait;
+use base64::Engine;
+use google_cloud_token::TokenSource;
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Google Cloud KMS REST API endpoint.
+/// ref. <https://cloud.google.com/kms/docs/reference/rest>
+pub const ENDPOINT: &str = "https://cloudkms.googleapis.com/v1";
+
+/// Represents Google Cloud KMS asymmetric elliptic curve key version EC_SIGN_SECP256K1_SHA256.
+/// Note that the actual private key never leaves KMS.
+/// Private key signing operation must be done via Cloud KMS API.
+/// ref. <https://cloud.google.com/kms/docs/algorithms#elliptic_curve_signing_algorithms>
+#[derive(Debug, Clone)]
+pub struct KmsKey {
+    /// HTTP client for the Cloud KMS REST API.
+    pub http_client: reqwest::Client,
+    /// Provides the OAuth2 access token (e.g., "google-cloud-auth").
+    pub token_source: Arc<dyn TokenSource>,
+
+    /// Key version resource name
+    /// (e.g., "projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*").
+    pub name: String,
+
+    /// Public key.
+    pub public_key: key::secp256k1::public_key::Key,
+
+    /// Total duration for retries.
+    pub retry_timeout: Duration,
+    /// Interval between retries.
+    pub retry_interval: Duration,
+}
+
+/// ref. <https://cloud.google.com/kms/docs/reference/rest/v1/projects.locations.keyRings.cryptoKeys.cryptoKeyVersions/getPublicKey>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeyResponse {
+    pem: String,
+    algorithm: String,
+}
+
+/// ref. <https://cloud.google.com/kms/docs/reference/rest/v1/projects.locations.keyRings.cryptoKeys.cryptoKeyVersions/asymmetricSign>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AsymmetricSignRequest {
+    digest: Digest,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Digest {
+    sha256: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AsymmetricSignResponse {
+    signature: String,
+}
+
+const ALGORITHM_SECP256K1: &str = "EC_SIGN_SECP256K1_SHA256";
+
+impl KmsKey {
+    /// Loads the KMS key from its key version resource name.
+    pub async fn from_name(
+        http_client: reqwest::Client,
+        token_source: Arc<dyn TokenSource>,
+        name: &str,
+    ) -> io::Result<Self> {
+        let retry_timeout = Duration::from_secs(90);
+        let retry_interval = Duration::from_secs(10);
+
+        // derives the public key from its private key
+        let url = format!("{}/{}/publicKey", ENDPOINT, name);
+        let body = send(
+            &http_client,
+            token_source.as_ref(),
+            (retry_timeout, retry_interval),
+            reqwest::Method::GET,
+            &url,
+            None,
+        )
+        .await?;
+        let resp: PublicKeyResponse = serde_json::from_str(&body).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to parse getPublicKey response {}", e),
+            )
+        })?;
+        if resp.algorithm != ALGORITHM_SECP256K1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "unexpected key algorithm '{}' (expected '{}')",
+                    resp.algorithm, ALGORITHM_SECP256K1
+                ),
+            ));
+        }
+
+        let der = decode_pem(&resp.pem)?;
+        let public_key = key::secp256k1::public_key::Key::from_public_key_der(&der)?;
+        log::info!(
+            "fetched KMS public key with ETH address '{}'",
+            public_key.to_eth_address(),
+        );
+
+        Ok(Self {
+            http_client,
+            token_source,
+            name: name.to_string(),
+            public_key,
+            retry_timeout,
+            retry_interval,
+        })
+    }
+
+    pub fn to_public_key(&self) -> key::secp256k1::public_key::Key {
+        self.public_key
+    }
+
+    /// Converts to Info.
+    pub fn to_info(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+    ) -> io::Result<key::secp256k1::Info> {
+        let network_id = network_id.into();
+        let short_addr = self.public_key.to_short_id()?;
+        let eth_addr = self.public_key.to_eth_address();
+        let h160_addr = self.public_key.to_h160();
+
+        let mut addresses = HashMap::new();
+        addresses.insert(
+            network_id.as_u32(),
+            key::secp256k1::ChainAddresses {
+                x: self.public_key.to_hrp_address(network_id, "X")?,
+                p: self.public_key.to_hrp_address(network_id, "P")?,
+            },
+        );
+
+        Ok(key::secp256k1::Info {
+            id: Some(self.name.clone()),
+            key_type: key::secp256k1::KeyType::GcpKms,
+
+            addresses,
+
+            short_address: short_addr,
+            eth_address: eth_addr,
+            h160_address: h160_addr,
+
+            ..Default::default()
+        })
+    }
+
+    /// Signs the 32-byte SHA256 output message with "asymmetricSign",
+    /// and converts its DER-encoded signature to the recoverable signature.
+    pub async fn sign_digest(&self, digest: &[u8]) -> io::Result<Sig> {
+        // ref. "crypto/sha256.Size"
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid digest length {} (expected {})",
+                    digest.len(),
+                    hash::SHA256_OUTPUT_LEN
+                ),
+            ));
+        }
+
+        let req = AsymmetricSignRequest {
+            digest: Digest {
+                sha256: base64::engine::general_purpose::STANDARD.encode(digest),
+            },
+        };
+        let req = serde_json::to_string(&req)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))?;
+
+        let url = format!("{}/{}:asymmetricSign", ENDPOINT, self.name);
+        let body = send(
+            &self.http_client,
+            self.token_source.as_ref(),
+            (self.retry_timeout, self.retry_interval),
+            reqwest::Method::POST,
+            &url,
+            Some(req),
+        )
+        .await?;
+        let resp: AsymmetricSignResponse = serde_json::from_str(&body).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to parse asymmetricSign response {}", e),
+            )
+        })?;
+
+        // DER-encoded >65-byte signature, need convert to 65-byte recoverable signature
+        let raw_der = base64::engine::general_purpose::STANDARD
+            .decode(resp.signature)
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("failed to decode signature {}", e),
+                )
+            })?;
+        let sig = k256::ecdsa::Signature::from_der(&raw_der).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed Signature::from_der {}", e),
+            )
+        })?;
+
+        let mut fixed_digest = [0u8; hash::SHA256_OUTPUT_LEN];
+        fixed_digest.copy_from_slice(digest);
+        key::secp256k1::signature::sig_from_digest_trial_recovery(
+            &sig,
+            &fixed_digest,
+            &self.public_key,
+        )
+    }
+}
+
+/// Sends the request with retries on throttling and server errors,
+/// and returns the response body.
+/// The token source must return the "Authorization" header value
+/// (e.g., "Bearer ..." from "google-cloud-auth").
+async fn send(
+    http_client: &reqwest::Client,
+    token_source: &dyn TokenSource,
+    (retry_timeout, retry_interval): (Duration, Duration),
+    method: reqwest::Method,
+    url: &str,
+    body: Option<String>,
+) -> io::Result<String> {
+    let (start, mut round) = (Instant::now(), 0_u32);
+    loop {
+        round += 1;
+        if start.elapsed().gt(&retry_timeout) {
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                format!("failed {} after retries", url),
+            ));
+        }
+
+        let token = token_source
+            .token()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to get token {}", e)))?;
+
+        let mut req = http_client
+            .request(method.clone(), url)
+            .header(reqwest::header::AUTHORIZATION, token)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(b) = &body {
+            req = req.body(b.clone());
+        }
+
+        let resp = match req.send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                log::warn!("[round {round}] failed {url} {}", e);
+                sleep(retry_interval).await;
+                continue;
+            }
+        };
+
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to read response {}", e)))?;
+        if status.is_success() {
+            return Ok(text);
+        }
+
+        let retryable = status.as_u16() == 429 || status.is_server_error();
+        log::warn!("[round {round}] failed {url} {status} (retriable {retryable})");
+        if !retryable {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("failed {} {} '{}'", url, status, text),
+            ));
+        }
+        sleep(retry_interval).await;
+    }
+}
+
+/// Decodes the PEM-encoded public key ("-----BEGIN PUBLIC KEY-----") to DER.
+fn decode_pem(pem: &str) -> io::Result<Vec<u8>> {
+    let b64: String = pem
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("-----"))
+        .collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(b64)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to decode PEM {}", e),
+            )
+        })
+}
+
+#[async_trait]
+impl key::secp256k1::SignOnly for KmsKey {
+    type Error = io::Error;
+
+    fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+        Err(Error::new(ErrorKind::Other, "not implemented"))
+    }
+
+    fn public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+        Ok(self.to_public_key())
+    }
+
+    async fn sign_digest(&self, msg: &[u8]) -> Result<[u8; 65], io::Error> {
+        let sig = self.sign_digest(msg).await?;
+        Ok(sig.to_bytes())
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/book/ch10-02-traits.html>
+impl key::secp256k1::ReadOnly for KmsKey {
+    fn key_type(&self) -> key::secp256k1::KeyType {
+        key::secp256k1::KeyType::GcpKms
+    }
+
+    fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
+        self.to_public_key()
+            .to_hrp_address(network_id, chain_id_alias)
+    }
+
+    fn short_address(&self) -> io::Result<short::Id> {
+        self.to_public_key().to_short_id()
+    }
+
+    fn short_address_bytes(&self) -> io::Result<Vec<u8>> {
+        self.to_public_key().to_short_bytes()
+    }
+
+    fn eth_address(&self) -> String {
+        self.to_public_key().to_eth_address()
+    }
+
+    fn h160_address(&self) -> primitive_types::H160 {
+        self.to_public_key().to_h160()
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="kms_gcp" -- key::secp256k1::kms::gcp::test_decode_pem --exact --show-output
+#[test]
+fn test_decode_pem() {
+    let pk = key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+
+    let der = {
+        use k256::pkcs8::EncodePublicKey;
+        pubkey.0.to_public_key_der().unwrap().as_bytes().to_vec()
+    };
+    let b64 = base64::engine::general_purpose::STANDARD.encode(&der);
+    let mut pem = String::from("-----BEGIN PUBLIC KEY-----\n");
+    for chunk in b64.as_bytes().chunks(64) {
+        pem.push_str(std::str::from_utf8(chunk).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str("-----END PUBLIC KEY-----\n");
+
+    let decoded = decode_pem(&pem).unwrap();
+    assert_eq!(decoded, der);
+    assert_eq!(
+        key::secp256k1::public_key::Key::from_public_key_der(&decoded).unwrap(),
+        pubkey
+    );
+}
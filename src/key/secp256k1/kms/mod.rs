@@ -1,2 +1,8 @@
 #[cfg(feature = "kms_aws")]
 pub mod aws;
+
+#[cfg(feature = "kms_azure")]
+pub mod azure;
+
+#[cfg(feature = "kms_gcp")]
+pub mod gcp;
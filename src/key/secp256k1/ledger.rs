@@ -0,0 +1,345 @@
+//! Ledger hardware wallet signer, using the Ledger Avalanche app
+//! over the USB HID APDU transport.
+//!
+//! On Linux, the device is only accessible with the Ledger udev rules:
+//!
+//! ```text
+//! wget -q -O - https://raw.githubusercontent.com/LedgerHQ/udev-rules/master/add_udev_rules.sh | sudo bash
+//! ```
+//!
+//! Or add the following to "/etc/udev/rules.d/20-hw1.rules" and reload
+//! with "sudo udevadm control --reload-rules && sudo udevadm trigger":
+//!
+//! ```text
+//! SUBSYSTEMS=="usb", ATTRS{idVendor}=="2c97", MODE="0660", GROUP="plugdev"
+//! KERNEL=="hidraw*", ATTRS{idVendor}=="2c97", MODE="0660", GROUP="plugdev"
+//! ```
+//!
+//! The user must be in the "plugdev" group, and the Avalanche app
+//! must be open on the unlocked device.
+//!
+//! ref. <https://github.com/LedgerHQ/udev-rules>
+//! ref. <https://github.com/Zondax/ledger-avalanche>
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use crate::{
+    hash,
+    ids::short,
+    key::{
+        self,
+        secp256k1::{extended_key, public_key::Key as PublicKey, signature::Sig},
+    },
+};
+use async_trait::async_trait;
+use ledger_apdu::APDUCommand;
+use ledger_transport_hid::{hidapi::HidApi, TransportNativeHID};
+
+/// APDU class of the Ledger Avalanche app.
+pub const CLA: u8 = 0x80;
+
+const INS_GET_ADDR: u8 = 0x02;
+const INS_SIGN_HASH: u8 = 0x04;
+
+const P1_ONLY_RETRIEVE: u8 = 0x00;
+
+const P1_SIGN_FIRST: u8 = 0x00;
+const P1_SIGN_LAST: u8 = 0x02;
+
+/// The status word for success.
+const RETCODE_OK: u16 = 0x9000;
+
+/// The number of derivation path components shared by all signing paths
+/// (e.g., "m/44'/9000'/0'"), sent in the first APDU.
+const PATH_PREFIX_LEN: usize = 3;
+
+/// Represents the secp256k1 key on the Ledger device.
+/// Note that the actual private key never leaves the device.
+/// Each signing operation must be confirmed on the device.
+#[derive(Clone)]
+pub struct LedgerKey {
+    transport: Arc<TransportNativeHID>,
+
+    /// BIP32 derivation path (e.g., "m/44'/9000'/0'/0/0").
+    pub derivation_path: String,
+
+    /// Public key.
+    pub public_key: PublicKey,
+}
+
+/// Omits the device handle.
+impl fmt::Debug for LedgerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LedgerKey")
+            .field("derivation_path", &self.derivation_path)
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+impl LedgerKey {
+    /// Connects to the first Ledger device and loads the public key
+    /// at the derivation path (e.g., "m/44'/9000'/0'/0/0").
+    pub fn new(derivation_path: &str) -> io::Result<Self> {
+        let path = extended_key::parse_path(derivation_path)?;
+        if path.len() <= PATH_PREFIX_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "derivation path '{}' must have more than {} components",
+                    derivation_path, PATH_PREFIX_LEN
+                ),
+            ));
+        }
+
+        let api = HidApi::new()
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed HidApi::new {}", e)))?;
+        let transport = TransportNativeHID::new(&api).map_err(|e| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("failed to open Ledger device {} (check udev rules)", e),
+            )
+        })?;
+        let transport = Arc::new(transport);
+
+        // empty HRP and chain Id to only retrieve the public key
+        let mut data = vec![0u8, 0u8];
+        data.extend_from_slice(&serialize_path(&path));
+        let resp = exchange(
+            &transport,
+            APDUCommand {
+                cla: CLA,
+                ins: INS_GET_ADDR,
+                p1: P1_ONLY_RETRIEVE,
+                p2: 0x00,
+                data,
+            },
+        )?;
+        if resp.len() < key::secp256k1::public_key::LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unexpected public key response length {}", resp.len()),
+            ));
+        }
+        let public_key = PublicKey::from_sec1_bytes(&resp[..key::secp256k1::public_key::LEN])?;
+        log::info!(
+            "fetched Ledger public key with ETH address '{}' at '{}'",
+            public_key.to_eth_address(),
+            derivation_path
+        );
+
+        Ok(Self {
+            transport,
+            derivation_path: derivation_path.to_string(),
+            public_key,
+        })
+    }
+
+    pub fn to_public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Converts to Info.
+    pub fn to_info(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+    ) -> io::Result<key::secp256k1::Info> {
+        let network_id = network_id.into();
+        let short_addr = self.public_key.to_short_id()?;
+        let eth_addr = self.public_key.to_eth_address();
+        let h160_addr = self.public_key.to_h160();
+
+        let mut addresses = HashMap::new();
+        addresses.insert(
+            network_id.as_u32(),
+            key::secp256k1::ChainAddresses {
+                x: self.public_key.to_hrp_address(network_id, "X")?,
+                p: self.public_key.to_hrp_address(network_id, "P")?,
+            },
+        );
+
+        Ok(key::secp256k1::Info {
+            id: Some(self.derivation_path.clone()),
+            key_type: key::secp256k1::KeyType::Ledger,
+
+            addresses,
+
+            short_address: short_addr,
+            eth_address: eth_addr,
+            h160_address: h160_addr,
+
+            ..Default::default()
+        })
+    }
+
+    /// Forwards the 32-byte SHA256 output message to the device for signing,
+    /// and returns its 65-byte recoverable signature.
+    /// Blocks until the user confirms (or rejects) on the device.
+    pub async fn sign_digest(&self, digest: &[u8]) -> io::Result<Sig> {
+        // ref. "crypto/sha256.Size"
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid digest length {} (expected {})",
+                    digest.len(),
+                    hash::SHA256_OUTPUT_LEN
+                ),
+            ));
+        }
+
+        let mut fixed_digest = [0u8; hash::SHA256_OUTPUT_LEN];
+        fixed_digest.copy_from_slice(digest);
+
+        let path = extended_key::parse_path(&self.derivation_path)?;
+        let transport = self.transport.clone();
+
+        // device I/O blocks until the user confirms
+        let resp = tokio::task::spawn_blocking(move || {
+            // the first message carries the path prefix and the digest
+            let mut data = serialize_path(&path[..PATH_PREFIX_LEN]);
+            data.extend_from_slice(&fixed_digest);
+            exchange(
+                &transport,
+                APDUCommand {
+                    cla: CLA,
+                    ins: INS_SIGN_HASH,
+                    p1: P1_SIGN_FIRST,
+                    p2: 0x00,
+                    data,
+                },
+            )?;
+
+            // the last message carries the signing path suffix
+            exchange(
+                &transport,
+                APDUCommand {
+                    cla: CLA,
+                    ins: INS_SIGN_HASH,
+                    p1: P1_SIGN_LAST,
+                    p2: 0x00,
+                    data: serialize_path(&path[PATH_PREFIX_LEN..]),
+                },
+            )
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed spawn_blocking {}", e)))??;
+
+        if resp.len() < key::secp256k1::signature::LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unexpected signature response length {}", resp.len()),
+            ));
+        }
+        let sig = Sig::from_bytes(&resp[..key::secp256k1::signature::LEN])?;
+
+        if !self.public_key.verify_digest(&fixed_digest, &sig) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Ledger signature does not match the public key",
+            ));
+        }
+        Ok(sig)
+    }
+}
+
+/// Serializes the derivation path as the number of components
+/// followed by each big-endian index (with the hardened bit).
+fn serialize_path(path: &[(u32, bool)]) -> Vec<u8> {
+    let mut b = Vec::with_capacity(1 + 4 * path.len());
+    b.push(path.len() as u8);
+    for (index, hardened) in path {
+        let index = if *hardened {
+            index | extended_key::HARDENED_OFFSET
+        } else {
+            *index
+        };
+        b.extend_from_slice(&index.to_be_bytes());
+    }
+    b
+}
+
+/// Sends the APDU command and returns the response data,
+/// or an error if the status word is not success.
+fn exchange(transport: &TransportNativeHID, command: APDUCommand<Vec<u8>>) -> io::Result<Vec<u8>> {
+    let answer = transport
+        .exchange(&command)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed Ledger exchange {}", e)))?;
+
+    let retcode = answer.retcode();
+    if retcode != RETCODE_OK {
+        let msg = match answer.error_code() {
+            Ok(code) => code.description(),
+            Err(_) => "unknown error".to_string(),
+        };
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("Ledger returned 0x{:04x} ({})", retcode, msg),
+        ));
+    }
+    Ok(answer.data().to_vec())
+}
+
+#[async_trait]
+impl key::secp256k1::SignOnly for LedgerKey {
+    type Error = io::Error;
+
+    fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+        Err(Error::new(ErrorKind::Other, "not implemented"))
+    }
+
+    fn public_key(&self) -> io::Result<PublicKey> {
+        Ok(self.to_public_key())
+    }
+
+    async fn sign_digest(&self, msg: &[u8]) -> Result<[u8; 65], io::Error> {
+        let sig = self.sign_digest(msg).await?;
+        Ok(sig.to_bytes())
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/book/ch10-02-traits.html>
+impl key::secp256k1::ReadOnly for LedgerKey {
+    fn key_type(&self) -> key::secp256k1::KeyType {
+        key::secp256k1::KeyType::Ledger
+    }
+
+    fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
+        self.to_public_key()
+            .to_hrp_address(network_id, chain_id_alias)
+    }
+
+    fn short_address(&self) -> io::Result<short::Id> {
+        self.to_public_key().to_short_id()
+    }
+
+    fn short_address_bytes(&self) -> io::Result<Vec<u8>> {
+        self.to_public_key().to_short_bytes()
+    }
+
+    fn eth_address(&self) -> String {
+        self.to_public_key().to_eth_address()
+    }
+
+    fn h160_address(&self) -> primitive_types::H160 {
+        self.to_public_key().to_h160()
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="ledger" -- key::secp256k1::ledger::test_serialize_path --exact --show-output
+#[test]
+fn test_serialize_path() {
+    let path = extended_key::parse_path("m/44'/9000'/0'/0/3").unwrap();
+    assert_eq!(
+        serialize_path(&path[..PATH_PREFIX_LEN]),
+        vec![3, 0x80, 0, 0, 44, 0x80, 0, 0x23, 0x28, 0x80, 0, 0, 0]
+    );
+    assert_eq!(
+        serialize_path(&path[PATH_PREFIX_LEN..]),
+        vec![2, 0, 0, 0, 0, 0, 0, 0, 3]
+    );
+}
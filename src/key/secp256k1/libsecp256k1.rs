@@ -1,7 +1,7 @@
 use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    constants, formatting, hash,
+    formatting, hash,
     ids::short,
     key::{self, secp256k1::address},
 };
@@ -76,16 +76,8 @@ impl PrivateKey {
     /// ref. "avalanchego/utils/crypto.PrivateKeySECP256K1R.SignHash"
     /// ref. <https://github.com/rust-bitcoin/rust-secp256k1/blob/master/src/ecdsa/recovery.rs>
     pub fn sign_digest(&self, digest: &[u8]) -> io::Result<key::secp256k1::signature::Sig> {
-        // ref. "crypto/sha256.Size"
-        assert_eq!(digest.len(), hash::SHA256_OUTPUT_LEN);
-
         let secp = libsecp256k1::Secp256k1::new();
-        let m = libsecp256k1::Message::from_slice(digest).map_err(|e| {
-            Error::new(
-                ErrorKind::Other,
-                format!("failed libsecp256k1::Message::from_slice {}", e),
-            )
-        })?;
+        let m = to_message(digest)?;
 
         // "github.com/decred/dcrd/dcrec/secp256k1/v3/ecdsa.SignCompact" outputs
         // 65-byte signature
@@ -102,6 +94,50 @@ impl PrivateKey {
     }
 }
 
+/// Checks the digest length and converts it to the message to sign or verify.
+fn to_message(digest: &[u8]) -> io::Result<libsecp256k1::Message> {
+    // ref. "crypto/sha256.Size"
+    if digest.len() != hash::SHA256_OUTPUT_LEN {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "invalid digest length {} (expected {})",
+                digest.len(),
+                hash::SHA256_OUTPUT_LEN
+            ),
+        ));
+    }
+    libsecp256k1::Message::from_slice(digest).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed libsecp256k1::Message::from_slice {}", e),
+        )
+    })
+}
+
+/// Converts the 65-byte signature with the recovery Id in the last byte
+/// to the libsecp256k1 recoverable signature.
+fn to_recoverable_signature(
+    sig: &key::secp256k1::signature::Sig,
+) -> io::Result<libsecp256k1::ecdsa::RecoverableSignature> {
+    let b = sig.to_bytes();
+    let rec_id = libsecp256k1::ecdsa::RecoveryId::from_i32(b[64] as i32).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("failed libsecp256k1::ecdsa::RecoveryId::from_i32 {}", e),
+        )
+    })?;
+    libsecp256k1::ecdsa::RecoverableSignature::from_compact(&b[..64], rec_id).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "failed libsecp256k1::ecdsa::RecoverableSignature::from_compact {}",
+                e
+            ),
+        )
+    })
+}
+
 impl From<libsecp256k1::SecretKey> for PrivateKey {
     fn from(s: libsecp256k1::SecretKey) -> Self {
         Self(s)
@@ -142,6 +178,51 @@ impl std::fmt::Display for PrivateKey {
 pub struct PublicKey(libsecp256k1::PublicKey);
 
 impl PublicKey {
+    /// Loads the public key from the compressed or uncompressed SEC1 bytes.
+    pub fn from_sec1_bytes(b: &[u8]) -> io::Result<Self> {
+        let pubkey = libsecp256k1::PublicKey::from_slice(b).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed libsecp256k1::PublicKey::from_slice {}", e),
+            )
+        })?;
+        Ok(Self(pubkey))
+    }
+
+    /// Recovers the public key from the 32-byte SHA256 output message and
+    /// its 65-byte recoverable signature.
+    /// Same as "key::secp256k1::signature::Sig::recover_public_key" but with libsecp256k1.
+    /// ref. <https://docs.rs/secp256k1/latest/secp256k1/struct.Secp256k1.html#method.recover_ecdsa>
+    pub fn recover(digest: &[u8], sig: &key::secp256k1::signature::Sig) -> io::Result<Self> {
+        let secp = libsecp256k1::Secp256k1::new();
+        let m = to_message(digest)?;
+        let rsig = to_recoverable_signature(sig)?;
+        let pubkey = secp.recover_ecdsa(&m, &rsig).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed libsecp256k1::Secp256k1::recover_ecdsa {}", e),
+            )
+        })?;
+        Ok(Self(pubkey))
+    }
+
+    /// Verifies the signature against the 32-byte SHA256 output message.
+    /// Returns false for the high-S signatures, as libsecp256k1 only accepts
+    /// the normalized (low-S) ones.
+    pub fn verify_digest(&self, digest: &[u8], sig: &key::secp256k1::signature::Sig) -> bool {
+        let secp = libsecp256k1::Secp256k1::new();
+        let (m, rsig) = match (to_message(digest), to_recoverable_signature(sig)) {
+            (Ok(m), Ok(rsig)) => (m, rsig),
+            _ => return false,
+        };
+        secp.verify_ecdsa(&m, &rsig.to_standard(), &self.0).is_ok()
+    }
+
+    /// Converts to the "k256" public key.
+    pub fn to_k256_public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+        key::secp256k1::public_key::Key::from_sec1_bytes(&self.to_compressed_bytes())
+    }
+
     /// Converts the public key to compressed bytes.
     pub fn to_compressed_bytes(&self) -> [u8; key::secp256k1::public_key::LEN] {
         let bb = self.0.serialize();
@@ -192,11 +273,12 @@ impl PublicKey {
         address::h160_to_eth_address(&self.to_h160(), None)
     }
 
-    pub fn to_hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
-        let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
-            Some(v) => v,
-            None => constants::FALLBACK_HRP,
-        };
+    pub fn to_hrp_address(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+        chain_id_alias: &str,
+    ) -> io::Result<String> {
+        let hrp = network_id.into().hrp();
         // ref. "pk.PublicKey().Address().Bytes()"
         let short_address_bytes = self.to_short_bytes()?;
 
@@ -299,3 +381,59 @@ fn test_key() {
     assert_eq!(pk2, pk3);
     assert_eq!(pk3, pk4);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="libsecp256k1" -- key::secp256k1::libsecp256k1::test_cross_backend --exact --show-output
+#[test]
+fn test_cross_backend() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    for k256_key in key::secp256k1::TEST_KEYS.iter() {
+        let libsecp_key = k256_key.to_libsecp256k1().unwrap();
+
+        let pubkey = libsecp_key.to_public_key();
+        assert_eq!(
+            pubkey.to_compressed_bytes(),
+            k256_key.to_public_key().to_compressed_bytes()
+        );
+        assert_eq!(
+            pubkey.to_k256_public_key().unwrap(),
+            k256_key.to_public_key()
+        );
+        assert_eq!(
+            PublicKey::from_sec1_bytes(&pubkey.to_uncompressed_bytes()).unwrap(),
+            pubkey
+        );
+        assert_eq!(
+            pubkey.to_eth_address(),
+            k256_key.to_public_key().to_eth_address()
+        );
+
+        for _ in 0..5 {
+            let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+            let digest = hash::sha256(&msg);
+
+            // both use the RFC 6979 nonce and normalize to low-S,
+            // thus the same signature and recovery Id
+            let libsecp_sig = libsecp_key.sign_digest(&digest).unwrap();
+            let k256_sig = k256_key.sign_digest(&digest).unwrap();
+            assert_eq!(libsecp_sig.to_bytes(), k256_sig.to_bytes());
+
+            assert_eq!(PublicKey::recover(&digest, &k256_sig).unwrap(), pubkey);
+            assert!(pubkey.verify_digest(&digest, &k256_sig));
+
+            let (recovered, _) = libsecp_sig.recover_public_key(&digest).unwrap();
+            assert_eq!(recovered, k256_key.to_public_key());
+
+            // a different digest recovers to a different key
+            let other = hash::sha256(&digest);
+            assert_ne!(PublicKey::recover(&other, &k256_sig).unwrap(), pubkey);
+            assert!(!pubkey.verify_digest(&other, &k256_sig));
+        }
+    }
+
+    let libsecp_key = key::secp256k1::TEST_KEYS[0].to_libsecp256k1().unwrap();
+    assert!(libsecp_key.sign_digest(&[0u8; 31]).is_err());
+}
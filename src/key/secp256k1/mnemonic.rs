@@ -1,7 +1,8 @@
 use std::io::{self, Error, ErrorKind};
 
 use bip32::{DerivationPath, Language, Mnemonic, XPrv};
-use rand_core::OsRng;
+use rand_core::{OsRng, RngCore};
+use zeroize::Zeroizing;
 
 /// ref. <https://github.com/ava-labs/avax-js-cli-tools/blob/3e3f714e4227aca83dc3978fcb6a4fd698e09065/address_gen.js>
 pub const AVAX_ACCOUNT_DERIV_PATH: &str = "m/44'/9000'/0'";
@@ -11,6 +12,13 @@ pub const AVAX_ACCOUNT_DERIV_PATH_0: &str = "m/44'/9000'/0'/0/0";
 pub const AVAX_ACCOUNT_EXT_PUB_KEY_DERIV_PATH: &str = "m/44'/9000'/0'";
 pub const ETH_ACCOUNT_EXT_PUB_KEY_DERIV_PATH: &str = "m/44'/60'/0'/0/0";
 
+/// Word counts allowed by BIP39 (128 to 256 bits of entropy).
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki#generating-the-mnemonic>
+pub const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// The size (in bytes) of the BIP39 seed.
+pub const SEED_LEN: usize = 64;
+
 /// Only supports "English" for now.
 /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki>
 /// ref. <https://github.com/rust-bitcoin/rust-bitcoin/blob/master/src/util/bip32.rs>
@@ -23,6 +31,82 @@ pub fn gen_24() -> String {
     String::from(s)
 }
 
+/// Generates a random English mnemonic phrase with the given number of words.
+/// The word count must be one of "VALID_WORD_COUNTS".
+/// The entropy and the returned phrase are zeroed out on drop.
+pub fn generate(word_count: usize) -> io::Result<Zeroizing<String>> {
+    if !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "invalid mnemonic word count {} (expected one of {:?})",
+                word_count, VALID_WORD_COUNTS
+            ),
+        ));
+    }
+
+    // e.g., 12 words encode 128-bit entropy + 4-bit checksum
+    let mut entropy = Zeroizing::new(vec![0u8; word_count * 4 / 3]);
+    OsRng.fill_bytes(&mut entropy);
+    entropy_to_phrase(&entropy).map(Zeroizing::new)
+}
+
+/// Encodes the entropy to the English mnemonic phrase.
+/// The entropy must be 16, 20, 24, 28, or 32 bytes.
+pub fn entropy_to_phrase(entropy: &[u8]) -> io::Result<String> {
+    let m = bip39::Mnemonic::from_entropy(entropy).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid mnemonic entropy length {} ({})", entropy.len(), e),
+        )
+    })?;
+    Ok(m.to_string())
+}
+
+/// Parses the English mnemonic phrase, validating the word count, each word,
+/// and the checksum. The phrase is NFKD-normalized as BIP39 requires.
+fn parse_phrase(phrase: &str) -> io::Result<bip39::Mnemonic> {
+    let word_count = phrase.split_whitespace().count();
+    if !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "invalid mnemonic word count {} (expected one of {:?})",
+                word_count, VALID_WORD_COUNTS
+            ),
+        ));
+    }
+    bip39::Mnemonic::parse_in(bip39::Language::English, phrase).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid mnemonic phrase ({})", e),
+        )
+    })
+}
+
+/// Decodes the English mnemonic phrase to its entropy,
+/// validating the word count, each word, and the checksum.
+pub fn phrase_to_entropy(phrase: &str) -> io::Result<Vec<u8>> {
+    Ok(parse_phrase(phrase)?.to_entropy())
+}
+
+/// Derives the 64-byte BIP39 seed from the mnemonic phrase and the optional passphrase.
+/// Both are NFKD-normalized first, so that the non-ASCII passphrase
+/// (e.g., typed with the composed or decomposed accents) derives the same seed
+/// as the other BIP39 wallets.
+/// ref. <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki#from-mnemonic-to-seed>
+pub fn phrase_to_seed(phrase: &str, passphrase: &str) -> io::Result<[u8; SEED_LEN]> {
+    Ok(parse_phrase(phrase)?.to_seed(passphrase))
+}
+
+impl crate::key::secp256k1::extended_key::ExtendedKey {
+    /// Derives the master extended key from the BIP39 mnemonic phrase and passphrase.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> io::Result<Self> {
+        let seed = phrase_to_seed(phrase, passphrase)?;
+        Self::from_seed(&seed)
+    }
+}
+
 impl crate::key::secp256k1::private_key::Key {
     /// Loads the private key from the mnemonic phrase.
     pub fn from_mnemonic_phrase<S>(phrase: S, derive_path: S) -> io::Result<Self>
@@ -53,6 +137,130 @@ impl crate::key::secp256k1::private_key::Key {
         })?;
 
         let pk = child_xprv.private_key().to_bytes();
-        Self::from_bytes(&pk)
+        Ok(Self::from_bytes(&pk)?)
+    }
+
+    /// Loads the private key from the BIP39 mnemonic phrase and passphrase,
+    /// following the BIP32 derivation path (e.g., "AVAX_ACCOUNT_DERIV_PATH_0").
+    /// Supports 12, 15, 18, 21, and 24-word phrases.
+    /// The normalized phrase is kept with the key for "to_info"
+    /// (the passphrase and the derivation path are not).
+    pub fn from_mnemonic(
+        phrase: &str,
+        passphrase: &str,
+        derivation_path: &str,
+    ) -> io::Result<Self> {
+        let m = parse_phrase(phrase)?;
+        let xkey =
+            crate::key::secp256k1::extended_key::ExtendedKey::from_seed(&m.to_seed(passphrase))?;
+        let child = xkey.derive_path(derivation_path)?;
+        Ok(child
+            .private_key()
+            .clone()
+            .with_mnemonic_phrase(m.to_string()))
+    }
+
+    /// Generates a random mnemonic phrase with the given number of words,
+    /// and derives the private key at "AVAX_ACCOUNT_DERIV_PATH_0" with an empty passphrase.
+    /// The returned phrase is zeroed out on drop, as is the copy kept with the key.
+    pub fn generate_mnemonic(word_count: usize) -> io::Result<(Zeroizing<String>, Self)> {
+        let phrase = generate(word_count)?;
+        let key = Self::from_mnemonic(&phrase, "", AVAX_ACCOUNT_DERIV_PATH_0)?;
+        Ok((phrase, key))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="mnemonic" -- key::secp256k1::mnemonic::test_mnemonic --exact --show-output
+#[test]
+fn test_mnemonic() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // ref. <https://github.com/trezor/python-mnemonic/blob/master/vectors.json>
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    assert_eq!(phrase_to_entropy(phrase).unwrap(), vec![0u8; 16]);
+    assert_eq!(entropy_to_phrase(&[0u8; 16]).unwrap(), phrase);
+    assert_eq!(
+        hex::encode(phrase_to_seed(phrase, "TREZOR").unwrap()),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+
+    // non-ASCII passphrases are NFKD-normalized before PBKDF2, so the composed
+    // ("\u{e8}") and decomposed ("e\u{300}") accents derive the same seed
+    // ref. <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki#from-mnemonic-to-seed>
+    let expected = "6021a8cfcd2724cb0db7f3d512d8524064e4cc9e7848ddd9f7a428a73ada0f8b998f557c835ebac454923b1af5a8b9c75ae8f0a233251b327a05098761ceeeb2";
+    assert_eq!(
+        hex::encode(phrase_to_seed(phrase, "Cr\u{e8}me br\u{fb}l\u{e9}e").unwrap()),
+        expected
+    );
+    assert_eq!(
+        hex::encode(phrase_to_seed(phrase, "Cre\u{300}me bru\u{302}le\u{301}e").unwrap()),
+        expected
+    );
+    // compatibility decomposition maps the fullwidth "\u{ff50}\u{ff41}\u{ff53}\u{ff53}" to "pass"
+    assert_eq!(
+        phrase_to_seed(phrase, "\u{ff50}\u{ff41}\u{ff53}\u{ff53}").unwrap(),
+        phrase_to_seed(phrase, "pass").unwrap()
+    );
+    assert_eq!(
+        hex::encode(phrase_to_seed(phrase, "pass").unwrap()),
+        "7cc7cba21f43c43b9b7a205ca693540d241fac0eb99b379050f7f5a691b4f2044881aa2c52368d68698996f6130fd080555f8694efc5dcb44c6ec3d3f36448cd"
+    );
+
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will";
+    assert_eq!(phrase_to_entropy(phrase).unwrap(), vec![0x7f; 24]);
+
+    // bad checksum
+    assert!(phrase_to_entropy(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"
+    )
+    .is_err());
+    // bad word count
+    assert!(phrase_to_entropy("abandon abandon about").is_err());
+    assert!(generate(13).is_err());
+
+    // must match the "bip32" crate derivation ("from_mnemonic_phrase" uses "password")
+    let phrase = gen_24();
+    assert_eq!(
+        crate::key::secp256k1::private_key::Key::from_mnemonic(
+            &phrase,
+            "password",
+            AVAX_ACCOUNT_DERIV_PATH_0
+        )
+        .unwrap(),
+        crate::key::secp256k1::private_key::Key::from_mnemonic_phrase(
+            phrase.as_str(),
+            AVAX_ACCOUNT_DERIV_PATH_0
+        )
+        .unwrap()
+    );
+
+    for word_count in VALID_WORD_COUNTS {
+        let (phrase, key) =
+            crate::key::secp256k1::private_key::Key::generate_mnemonic(word_count).unwrap();
+        assert_eq!(phrase.split(' ').count(), word_count);
+
+        let key2 = crate::key::secp256k1::private_key::Key::from_mnemonic(
+            &phrase,
+            "",
+            AVAX_ACCOUNT_DERIV_PATH_0,
+        )
+        .unwrap();
+        assert_eq!(key, key2);
+
+        let key3 = crate::key::secp256k1::private_key::Key::from_cb58(key.to_cb58()).unwrap();
+        assert_eq!(key, key3);
+
+        assert_eq!(
+            key.to_info(1u32).unwrap().mnemonic_phrase,
+            Some(phrase.to_string())
+        );
+        assert_eq!(
+            key2.to_info(1u32).unwrap().mnemonic_phrase,
+            Some(phrase.to_string())
+        );
+        assert_eq!(key3.to_info(1u32).unwrap().mnemonic_phrase, None);
     }
 }
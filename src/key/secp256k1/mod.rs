@@ -1,17 +1,48 @@
-pub mod address;
-pub mod keychain;
-pub mod kms;
+pub mod error;
 pub mod private_key;
 pub mod public_key;
 pub mod signature;
+
+#[cfg(feature = "std")]
+pub mod address;
+#[cfg(feature = "std")]
+pub mod extended_key;
+#[cfg(feature = "std")]
+pub mod keychain;
+#[cfg(feature = "std")]
+pub mod kms;
+#[cfg(feature = "std")]
 pub mod txs;
 
+#[cfg(any(feature = "evm", feature = "wallet_evm"))]
+pub mod eth_signer;
+
+#[cfg(feature = "keystore")]
+pub mod keystore;
+
+#[cfg(feature = "ledger")]
+pub mod ledger;
+
 #[cfg(feature = "libsecp256k1")]
 pub mod libsecp256k1;
+#[cfg(all(test, feature = "libsecp256k1"))]
+mod backend_parity;
 
 #[cfg(feature = "mnemonic")]
 pub mod mnemonic;
 
+#[cfg(feature = "multi_signer")]
+pub mod multi_signer;
+#[cfg(feature = "multi_signer")]
+pub use multi_signer::MultiSigner;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "yubihsm")]
+pub mod yubihsm;
+
+#[cfg(feature = "std")]
 use std::{
     collections::HashMap,
     fmt,
@@ -20,22 +51,41 @@ use std::{
     path::Path,
 };
 
+#[cfg(feature = "std")]
 use crate::{codec::serde::hex_0x_primitive_types_h160::Hex0xH160, ids::short};
+#[cfg(feature = "std")]
 use async_trait::async_trait;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use rust_embed::RustEmbed;
+#[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
 use serde_with::{serde_as, DisplayFromStr};
 
 /// Key interface that "only" allows "sign" operations.
 /// Trait is used here to limit access to the underlying private/secret key.
 /// or to enable secure remote key management service integration (e.g., KMS ECC_SECG_P256K1).
+#[cfg(feature = "std")]
 #[async_trait]
 pub trait SignOnly {
     type Error: std::error::Error;
 
     fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey>;
 
+    /// Returns the public key of the signer, so that the signer behind
+    /// a trait object can be mapped to its address without downcasting.
+    /// Defaults to deriving from "signing_key", thus the remote signers
+    /// (e.g., KMS, Ledger) that do not expose the private key must
+    /// return their cached public key instead.
+    fn public_key(&self) -> io::Result<public_key::Key> {
+        let signing_key = self.signing_key()?;
+        Ok(public_key::Key::from_verifying_key(
+            &signing_key.verifying_key(),
+        ))
+    }
+
     /// Signs the 32-byte SHA256 output message with the ECDSA private key and the recoverable code.
     /// "github.com/decred/dcrd/dcrec/secp256k1/v3/ecdsa.SignCompact" outputs 65-byte signature.
     /// ref. "avalanchego/utils/crypto.PrivateKeySECP256K1R.SignHash"
@@ -44,21 +94,44 @@ pub trait SignOnly {
     /// ref. <https://docs.rs/secp256k1/latest/secp256k1/struct.Message.html>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], Self::Error>;
+
+    /// Signs each 32-byte SHA256 output message, in order.
+    /// Defaults to calling "sign_digest" for each digest.
+    async fn sign_digests(&self, digests: &[[u8; 32]]) -> Result<Vec<[u8; 65]>, Self::Error>
+    where
+        Self: Sync,
+    {
+        let mut sigs = Vec::with_capacity(digests.len());
+        for digest in digests {
+            sigs.push(self.sign_digest(digest).await?);
+        }
+        Ok(sigs)
+    }
 }
 
 /// Key interface that "only" allows "read" operations.
+#[cfg(feature = "std")]
 pub trait ReadOnly {
     fn key_type(&self) -> KeyType;
     /// Implements "crypto.PublicKeySECP256K1R.Address()" and "formatting.FormatAddress".
     /// "human readable part" (hrp) must be valid output from "constants.GetHRP(networkID)".
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants>
     fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String>;
+    /// Same as "hrp_address" but with the given HRP instead of the one mapped
+    /// from the network Id (e.g., "custom" or "local" for private subnets).
+    /// Fails if the HRP is not lowercase and bech32-legal.
+    fn hrp_address_with_hrp(&self, hrp: &str, chain_id_alias: &str) -> io::Result<String> {
+        crate::formatting::check_hrp(hrp)?;
+        let short_address_bytes = self.short_address_bytes()?;
+        crate::formatting::address(chain_id_alias, hrp, &short_address_bytes)
+    }
     fn short_address(&self) -> io::Result<short::Id>;
     fn short_address_bytes(&self) -> io::Result<Vec<u8>>;
     fn eth_address(&self) -> String;
     fn h160_address(&self) -> primitive_types::H160;
 }
 
+#[cfg(feature = "std")]
 lazy_static! {
     /// Test keys generated by "avalanchego/utils/crypto.FactorySECP256K1R".
     pub static ref TEST_KEYS: Vec<crate::key::secp256k1::private_key::Key> = {
@@ -115,6 +188,7 @@ fn test_keys() {
 // and make sure both generate the same addresses
 // use "avalanche-rust/avalanchego-conformance/key/secp256k1"
 // to generate keys and addresses with "avalanchego"
+#[cfg(feature = "std")]
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -144,12 +218,14 @@ pub struct Info {
     pub h160_address: primitive_types::H160,
 }
 
+#[cfg(feature = "std")]
 impl Default for Info {
     fn default() -> Self {
         Self::default()
     }
 }
 
+#[cfg(feature = "std")]
 impl Info {
     pub fn default() -> Self {
         Info {
@@ -166,12 +242,14 @@ impl Info {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<&crate::key::secp256k1::private_key::Key> for Info {
     fn from(sk: &crate::key::secp256k1::private_key::Key) -> Self {
-        sk.to_info(1).unwrap()
+        sk.to_info(1u32).unwrap()
     }
 }
 
+#[cfg(feature = "std")]
 impl Info {
     pub fn load(file_path: &str) -> io::Result<Self> {
         log::info!("loading Info from {}", file_path);
@@ -217,6 +295,7 @@ impl Info {
 /// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
 /// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
 /// Use "Self.to_string()" to directly invoke this
+#[cfg(feature = "std")]
 impl fmt::Display for Info {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = serde_yaml::to_string(&self).unwrap();
@@ -225,6 +304,7 @@ impl fmt::Display for Info {
 }
 
 /// Defines the key type.
+#[cfg(feature = "std")]
 #[derive(
     Deserialize,
     Serialize,
@@ -241,21 +321,33 @@ pub enum KeyType {
     Hot,
     #[serde(rename = "aws-kms")]
     AwsKms,
+    #[serde(rename = "gcp-kms")]
+    GcpKms,
+    #[serde(rename = "ledger")]
+    Ledger,
+    #[serde(rename = "yubihsm")]
+    YubiHsm,
     Unknown(String),
 }
 
+#[cfg(feature = "std")]
 impl std::convert::From<&str> for KeyType {
     fn from(s: &str) -> Self {
         match s {
             "hot" => KeyType::Hot,
             "aws-kms" => KeyType::AwsKms,
             "aws_kms" => KeyType::AwsKms,
+            "gcp-kms" => KeyType::GcpKms,
+            "gcp_kms" => KeyType::GcpKms,
+            "ledger" => KeyType::Ledger,
+            "yubihsm" => KeyType::YubiHsm,
 
             other => KeyType::Unknown(other.to_owned()),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::str::FromStr for KeyType {
     type Err = std::convert::Infallible;
 
@@ -267,18 +359,23 @@ impl std::str::FromStr for KeyType {
 /// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
 /// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
 /// Use "Self.to_string()" to directly invoke this
+#[cfg(feature = "std")]
 impl std::fmt::Display for KeyType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+#[cfg(feature = "std")]
 impl KeyType {
     /// Returns the `&str` value of the enum member.
     pub fn as_str(&self) -> &str {
         match self {
             KeyType::Hot => "hot",
             KeyType::AwsKms => "aws-kms",
+            KeyType::GcpKms => "gcp-kms",
+            KeyType::Ledger => "ledger",
+            KeyType::YubiHsm => "yubihsm",
 
             KeyType::Unknown(s) => s.as_ref(),
         }
@@ -289,16 +386,21 @@ impl KeyType {
         &[
             "hot",     //
             "aws-kms", //
+            "gcp-kms", //
+            "ledger",  //
+            "yubihsm", //
         ]
     }
 }
 
+#[cfg(feature = "std")]
 impl AsRef<str> for KeyType {
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChainAddresses {
@@ -342,20 +444,20 @@ fn test_keys_address() {
             let pubkey = sk.to_public_key();
 
             assert_eq!(
-                pubkey.to_hrp_address(1, "X").unwrap(),
+                pubkey.to_hrp_address(1u32, "X").unwrap(),
                 ki.addresses.get(&1).unwrap().x
             );
             assert_eq!(
-                pubkey.to_hrp_address(1, "P").unwrap(),
+                pubkey.to_hrp_address(1u32, "P").unwrap(),
                 ki.addresses.get(&1).unwrap().p
             );
 
             assert_eq!(
-                pubkey.to_hrp_address(9999, "X").unwrap(),
+                pubkey.to_hrp_address(9999u32, "X").unwrap(),
                 ki.addresses.get(&9999).unwrap().x
             );
             assert_eq!(
-                pubkey.to_hrp_address(9999, "P").unwrap(),
+                pubkey.to_hrp_address(9999u32, "P").unwrap(),
                 ki.addresses.get(&9999).unwrap().p
             );
 
@@ -364,3 +466,39 @@ fn test_keys_address() {
         }
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::test_sign_only_public_key --exact --show-output
+#[test]
+fn test_sign_only_public_key() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    /// Only implements the required methods, to test the default "public_key".
+    struct SigningKeyOnly(crate::key::secp256k1::private_key::Key);
+
+    #[async_trait]
+    impl SignOnly for SigningKeyOnly {
+        type Error = io::Error;
+
+        fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+            Ok(self.0.signing_key())
+        }
+
+        async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], io::Error> {
+            let sig = self.0.sign_digest(digest)?;
+            Ok(sig.to_bytes())
+        }
+    }
+
+    for k in TEST_KEYS.iter() {
+        let signers: Vec<Box<dyn SignOnly<Error = io::Error>>> =
+            vec![Box::new(k.clone()), Box::new(SigningKeyOnly(k.clone()))];
+        for signer in signers.iter() {
+            let pubkey = signer.public_key().unwrap();
+            assert_eq!(pubkey, k.to_public_key());
+            assert_eq!(pubkey.to_h160(), k.h160_address());
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::key;
+use async_trait::async_trait;
+use primitive_types::H160;
+
+/// Signer with the error converted to "io::Error", so that the signers
+/// with different error types (e.g., AWS KMS) can be held as the same trait object.
+pub type BoxedSigner = Box<dyn key::secp256k1::SignOnly<Error = io::Error> + Send + Sync>;
+
+/// Collects the signatures over the same digest from multiple keys
+/// (e.g., local keys, KMS keys, and Ledger), for threshold/multisig flows
+/// (e.g., "secp256k1fx.OutputOwners" with "threshold > 1").
+pub struct MultiSigner {
+    /// Signers sorted by their addresses from "SignOnly::public_key".
+    signers: Vec<(H160, BoxedSigner)>,
+}
+
+impl MultiSigner {
+    pub fn new() -> Self {
+        Self {
+            signers: Vec::new(),
+        }
+    }
+
+    /// Adds the signer. Fails if the signer with the same address already exists.
+    pub fn add<K>(self, signer: K) -> io::Result<Self>
+    where
+        K: key::secp256k1::SignOnly + Send + Sync + 'static,
+    {
+        self.add_boxed(Box::new(IoSigner(signer)))
+    }
+
+    /// Adds the signer that is already boxed.
+    pub fn add_boxed(mut self, signer: BoxedSigner) -> io::Result<Self> {
+        let address = signer.public_key()?.to_h160();
+        self.insert(address, signer)?;
+        Ok(self)
+    }
+
+    fn insert(&mut self, address: H160, signer: BoxedSigner) -> io::Result<()> {
+        match self.signers.binary_search_by(|(a, _)| a.cmp(&address)) {
+            Ok(_) => Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!("signer {:?} already exists", address),
+            )),
+            Err(idx) => {
+                self.signers.insert(idx, (address, signer));
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the signer addresses in ascending order.
+    pub fn addresses(&self) -> Vec<H160> {
+        self.signers.iter().map(|(a, _)| *a).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.signers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signers.is_empty()
+    }
+
+    /// Signs the 32-byte digest with all signers concurrently, and returns
+    /// the 65-byte recoverable signatures ordered by the signer address
+    /// (same order as "addresses"). Fails if any signer fails.
+    pub async fn sign_digest_all(&self, digest: &[u8]) -> io::Result<Vec<[u8; 65]>> {
+        if digest.len() != 32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("digest must be 32-byte, got {}", digest.len()),
+            ));
+        }
+
+        let futs = self.signers.iter().map(|(address, signer)| async move {
+            signer.sign_digest(digest).await.map_err(|e| {
+                Error::new(
+                    e.kind(),
+                    format!("failed to sign digest with {:?} ({})", address, e),
+                )
+            })
+        });
+        futures::future::try_join_all(futs).await
+    }
+}
+
+impl Default for MultiSigner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Converts the signer error to "io::Error".
+struct IoSigner<K>(K);
+
+#[async_trait]
+impl<K> key::secp256k1::SignOnly for IoSigner<K>
+where
+    K: key::secp256k1::SignOnly + Send + Sync,
+{
+    type Error = io::Error;
+
+    fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+        self.0.signing_key()
+    }
+
+    fn public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+        self.0.public_key()
+    }
+
+    async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], io::Error> {
+        self.0
+            .sign_digest(digest)
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="multi_signer" -- key::secp256k1::multi_signer::test_multi_signer --exact --show-output
+#[test]
+fn test_multi_signer() {
+    use std::fmt;
+
+    use crate::hash;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    /// Mocks the remote KMS signer with its own error type.
+    #[derive(Debug)]
+    struct MockKmsError(String);
+    impl fmt::Display for MockKmsError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "mock KMS error: {}", self.0)
+        }
+    }
+    impl std::error::Error for MockKmsError {}
+
+    struct MockKms {
+        inner: key::secp256k1::private_key::Key,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl key::secp256k1::SignOnly for MockKms {
+        type Error = MockKmsError;
+
+        fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+            Err(Error::new(ErrorKind::Other, "not implemented"))
+        }
+
+        fn public_key(&self) -> io::Result<key::secp256k1::public_key::Key> {
+            Ok(self.inner.to_public_key())
+        }
+
+        async fn sign_digest(&self, digest: &[u8]) -> Result<[u8; 65], MockKmsError> {
+            if self.fail {
+                return Err(MockKmsError("throttled".to_string()));
+            }
+            let sig = self
+                .inner
+                .sign_digest(digest)
+                .map_err(|e| MockKmsError(e.to_string()))?;
+            Ok(sig.to_bytes())
+        }
+    }
+
+    let local = key::secp256k1::private_key::Key::generate().unwrap();
+    let remote = key::secp256k1::private_key::Key::generate().unwrap();
+    let signer = MultiSigner::new()
+        .add(local.clone())
+        .unwrap()
+        .add(MockKms {
+            inner: remote.clone(),
+            fail: false,
+        })
+        .unwrap();
+    assert_eq!(signer.len(), 2);
+
+    let mut expected = vec![
+        local.to_public_key().to_h160(),
+        remote.to_public_key().to_h160(),
+    ];
+    expected.sort();
+    assert_eq!(signer.addresses(), expected);
+
+    // duplicate signer
+    assert!(MultiSigner::new()
+        .add(local.clone())
+        .unwrap()
+        .add(local.clone())
+        .is_err());
+
+    let digest = hash::sha256(b"hello");
+    let sigs = ab!(signer.sign_digest_all(&digest)).unwrap();
+    assert_eq!(sigs.len(), 2);
+    for (address, sig) in signer.addresses().iter().zip(sigs.iter()) {
+        let sig = key::secp256k1::signature::Sig::from_bytes(sig).unwrap();
+        let (recovered, _) = sig.recover_public_key(&digest).unwrap();
+        assert_eq!(recovered.to_h160(), *address);
+    }
+
+    assert!(ab!(signer.sign_digest_all(&digest[..31])).is_err());
+
+    let failing = MultiSigner::new()
+        .add(local)
+        .unwrap()
+        .add(MockKms {
+            inner: remote,
+            fail: true,
+        })
+        .unwrap();
+    let err = ab!(failing.sign_digest_all(&digest)).unwrap_err();
+    assert!(err.to_string().contains("throttled"));
+}
@@ -1,19 +1,24 @@
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
 use std::io::{self, Error, ErrorKind};
 
+use crate::key::secp256k1::signature::Sig;
+#[cfg(feature = "std")]
 use crate::{
-    constants, formatting, hash,
+    formatting, hash,
     ids::short,
-    key::{
-        self,
-        secp256k1::{address, signature::Sig},
-    },
+    key::{self, secp256k1::address},
 };
 use k256::{
     ecdsa::{signature::hazmat::PrehashVerifier, VerifyingKey},
-    pkcs8::DecodePublicKey,
     PublicKey,
 };
 
+#[cfg(feature = "std")]
+use k256::pkcs8::{DecodePublicKey, EncodePublicKey, LineEnding};
+#[cfg(feature = "std")]
+use spki::{der::pem, ObjectIdentifier};
+
 /// The size (in bytes) of a public key.
 /// ref. "secp256k1::constants::PUBLIC_KEY_SIZE"
 pub const LEN: usize = 33;
@@ -22,6 +27,16 @@ pub const LEN: usize = 33;
 /// ref. "secp256k1::constants::UNCOMPRESSED_PUBLIC_KEY_SIZE"
 pub const UNCOMPRESSED_LEN: usize = 65;
 
+/// The "id-ecPublicKey" algorithm OID of the EC keys.
+/// ref. <https://www.rfc-editor.org/rfc/rfc5480#section-2.1.1>
+#[cfg(feature = "std")]
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// The named curve OID of secp256k1.
+/// ref. <https://www.secg.org/sec2-v2.pdf> (section A.2.1)
+#[cfg(feature = "std")]
+const SECP256K1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+
 /// Represents "k256::PublicKey" and "k256::ecdsa::VerifyingKey".
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Key(pub PublicKey);
@@ -29,11 +44,27 @@ pub struct Key(pub PublicKey);
 impl Key {
     /// Decodes compressed or uncompressed public key bytes with Elliptic-Curve-Point-to-Octet-String
     /// encoding described in SEC 1: Elliptic Curve Cryptography (Version 2.0) section 2.3.3 (page 10).
+    /// Accepts either the 33-byte compressed or the 65-byte uncompressed form,
+    /// and fails if the point is not on the curve or is the identity.
     /// ref. <http://www.secg.org/sec1-v2.pdf>
+    #[cfg(feature = "std")]
     pub fn from_sec1_bytes(b: &[u8]) -> io::Result<Self> {
+        if b.len() != LEN && b.len() != UNCOMPRESSED_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid SEC1 public key length {} (expected {} or {})",
+                    b.len(),
+                    LEN,
+                    UNCOMPRESSED_LEN
+                ),
+            ));
+        }
+
+        // "k256::PublicKey" is never the identity and always on the curve
         let pubkey = PublicKey::from_sec1_bytes(b).map_err(|e| {
             Error::new(
-                ErrorKind::Other,
+                ErrorKind::InvalidInput,
                 format!("failed PublicKey::from_sec1_bytes {}", e),
             )
         })?;
@@ -41,6 +72,7 @@ impl Key {
     }
 
     /// Decodes ASN.1 DER-encoded public key bytes.
+    #[cfg(feature = "std")]
     pub fn from_public_key_der(b: &[u8]) -> io::Result<Self> {
         let pubkey = PublicKey::from_public_key_der(b).map_err(|e| {
             Error::new(
@@ -51,10 +83,59 @@ impl Key {
         Ok(Self(pubkey))
     }
 
-    /// Loads the public key from a message and its recoverable signature.
+    /// Encodes the public key in PEM with the SPKI "PUBLIC KEY" label
+    /// (e.g., "openssl ec -pubout").
+    /// ref. <https://www.rfc-editor.org/rfc/rfc5480>
+    #[cfg(feature = "std")]
+    pub fn to_pem(&self) -> io::Result<String> {
+        self.0.to_public_key_pem(LineEnding::LF).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed PublicKey::to_public_key_pem {}", e),
+            )
+        })
+    }
+
+    /// Decodes the PEM-encoded public key with the SPKI "PUBLIC KEY" label.
+    /// Fails if the key is not an EC key on secp256k1 (e.g., NIST P-256).
+    #[cfg(feature = "std")]
+    pub fn from_pem(pem: &str) -> io::Result<Self> {
+        let (label, der) = pem::decode_vec(pem.trim().as_bytes()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to decode PEM {}", e),
+            )
+        })?;
+        if label != "PUBLIC KEY" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unsupported PEM label '{}' (expected 'PUBLIC KEY')", label),
+            ));
+        }
+
+        let spk = spki::SubjectPublicKeyInfo::try_from(der.as_slice()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to load spki::SubjectPublicKeyInfo {}", e),
+            )
+        })?;
+        check_ec_algorithm(&spk.algorithm)?;
+
+        let pubkey = PublicKey::from_public_key_der(&der).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed PublicKey::from_public_key_der {}", e),
+            )
+        })?;
+        Ok(Self(pubkey))
+    }
+
+    /// Recovers the public key from the 32-byte SHA256 output message and its
+    /// recoverable signature, using the recovery Id embedded in the signature.
+    /// Fails if the recovery Id does not lead to a valid secp256k1 point.
     /// ref. "fx.SECPFactory.RecoverHashPublicKey"
-    pub fn from_signature(digest: &[u8], sig: &[u8]) -> io::Result<Self> {
-        let sig = Sig::from_bytes(sig)?;
+    #[cfg(feature = "std")]
+    pub fn from_signature(digest: &[u8; 32], sig: &Sig) -> io::Result<Self> {
         let (pubkey, _) = sig.recover_public_key(digest)?;
         Ok(pubkey)
     }
@@ -69,6 +150,7 @@ impl Key {
     }
 
     /// Verifies the message and the validity of its signature with recoverable code.
+    #[cfg(feature = "std")]
     pub fn verify(&self, digest: &[u8], sig: &[u8]) -> io::Result<bool> {
         let sig = Sig::from_bytes(sig)?;
 
@@ -80,6 +162,33 @@ impl Key {
         Ok(*self == recovered_pubkey)
     }
 
+    /// Returns true if the signature is valid for the 32-byte digest under this public key.
+    pub fn verify_digest(&self, digest: &[u8; 32], sig: &Sig) -> bool {
+        self.to_verifying_key()
+            .verify_prehash(digest, &sig.0 .0)
+            .is_ok()
+    }
+
+    /// Returns true if the signature is valid for the message under this public key,
+    /// where the message is hashed with the EIP-191 prefix
+    /// ("\x19Ethereum Signed Message:\n" + len(message)) as in "eth_sign".
+    /// ref. <https://eips.ethereum.org/EIPS/eip-191>
+    #[cfg(feature = "std")]
+    pub fn verify_message(&self, msg: &[u8], sig: &Sig) -> bool {
+        let digest = ethers_core::utils::hash_message(msg);
+        self.verify_digest(&digest.0, sig)
+    }
+
+    /// Recovers the signer public key of the message signed with the EIP-191
+    /// prefix (e.g., "private_key::Key::sign_eth_message" or "eth_sign").
+    /// The recovery Id of the signature must be 0 or 1 (Ethereum "v" minus 27).
+    /// ref. <https://eips.ethereum.org/EIPS/eip-191>
+    #[cfg(feature = "std")]
+    pub fn recover_eth_message(msg: &[u8], sig: &Sig) -> io::Result<Self> {
+        let digest = ethers_core::utils::hash_message(msg);
+        Self::from_signature(&digest.0, sig)
+    }
+
     /// Converts the public key to compressed bytes.
     pub fn to_compressed_bytes(&self) -> [u8; LEN] {
         let vkey: VerifyingKey = self.0.into();
@@ -100,7 +209,7 @@ impl Key {
         let p = vkey.to_encoded_point(false);
 
         let mut b = [0u8; UNCOMPRESSED_LEN];
-        b.copy_from_slice(&p.to_bytes());
+        b.copy_from_slice(p.as_bytes());
         b
     }
 
@@ -109,6 +218,13 @@ impl Key {
     /// ref. "pk.PublicKey().Address().Bytes()"
     ///
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#PubkeyBytesToAddress>
+    ///
+    /// Note that this is not the node ID, even though both are "sha256" and
+    /// "ripemd160" hashes. The node ID is hashed from the DER-encoded staking
+    /// TLS certificate (RSA or ECDSA P-256), not from any secp256k1 key.
+    /// Use "ids::node::Id::from_cert_der_bytes" instead.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/node#Node.Initialize>
+    #[cfg(feature = "std")]
     pub fn to_short_id(&self) -> io::Result<crate::ids::short::Id> {
         let compressed = self.to_compressed_bytes();
         short::Id::from_public_key_bytes(&compressed)
@@ -117,11 +233,13 @@ impl Key {
     /// "hashing.PubkeyBytesToAddress" and "ids.ToShortID"
     ///
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#PubkeyBytesToAddress>
+    #[cfg(feature = "std")]
     pub fn to_short_bytes(&self) -> io::Result<Vec<u8>> {
         let compressed = self.to_compressed_bytes();
         hash::sha256_ripemd160(&compressed)
     }
 
+    #[cfg(feature = "std")]
     pub fn to_h160(&self) -> primitive_types::H160 {
         let uncompressed = self.to_uncompressed_bytes();
 
@@ -132,19 +250,28 @@ impl Key {
         primitive_types::H160::from_slice(digest_h256)
     }
 
-    /// Encodes the public key in ETH address format.
+    /// Encodes the public key in ETH address format,
+    /// in the EIP-55 mixed-case checksummed form (as shown by Etherscan).
+    /// Use "formatting::is_valid_eip55" to validate the addresses from
+    /// other sources before comparing.
     /// Make sure to not double-hash.
+    /// ref. <https://eips.ethereum.org/EIPS/eip-55>
     /// ref. <https://pkg.go.dev/github.com/ethereum/go-ethereum/crypto#PubkeyToAddress>
     /// ref. <https://pkg.go.dev/github.com/ethereum/go-ethereum/common#Address.Hex>
+    #[cfg(feature = "std")]
     pub fn to_eth_address(&self) -> String {
         address::h160_to_eth_address(&self.to_h160(), None)
     }
 
-    pub fn to_hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
-        let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
-            Some(v) => v,
-            None => constants::FALLBACK_HRP,
-        };
+    /// Takes the raw network Id (e.g., "5") or the typed one
+    /// (e.g., "NetworkId::Fuji").
+    #[cfg(feature = "std")]
+    pub fn to_hrp_address(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+        chain_id_alias: &str,
+    ) -> io::Result<String> {
+        let hrp = network_id.into().hrp();
 
         // ref. "pk.PublicKey().Address().Bytes()"
         let short_address_bytes = self.to_short_bytes()?;
@@ -152,6 +279,31 @@ impl Key {
         // ref. "formatting.FormatAddress(chainIDAlias, hrp, pubBytes)"
         formatting::address(chain_id_alias, hrp, &short_address_bytes)
     }
+
+    /// Same as "to_hrp_address" but with the given HRP instead of the one
+    /// mapped from the network Id (e.g., "custom" or "local" for private subnets).
+    /// Fails if the HRP is not lowercase and bech32-legal.
+    #[cfg(feature = "std")]
+    pub fn to_hrp_address_with_hrp(&self, hrp: &str, chain_id_alias: &str) -> io::Result<String> {
+        formatting::check_hrp(hrp)?;
+        let short_address_bytes = self.to_short_bytes()?;
+        formatting::address(chain_id_alias, hrp, &short_address_bytes)
+    }
+}
+
+/// Orders the public keys by their compressed SEC1 bytes, so that the signer
+/// lists are deterministic. Note that X/P-chain multisig outputs are ordered
+/// by the short addresses (see "ids::short::Id" ordering), not by public keys.
+impl Ord for Key {
+    fn cmp(&self, other: &Key) -> Ordering {
+        self.to_compressed_bytes().cmp(&other.to_compressed_bytes())
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Key) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl From<PublicKey> for Key {
@@ -183,13 +335,29 @@ impl From<Key> for VerifyingKey {
 /// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
 ///
 /// Use "Self.to_string()" to directly invoke this
+#[cfg(feature = "std")]
 impl std::fmt::Display for Key {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", hex::encode(self.to_compressed_bytes()))
     }
 }
 
+/// Derives from the arbitrary private key, so that the point is always on the curve.
+/// NOT for production key generation.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Key {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let pk = <key::secp256k1::private_key::Key as arbitrary::Arbitrary>::arbitrary(u)?;
+        Ok(pk.to_public_key())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <key::secp256k1::private_key::Key as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 /// ref. <https://doc.rust-lang.org/book/ch10-02-traits.html>
+#[cfg(feature = "std")]
 impl key::secp256k1::ReadOnly for Key {
     fn key_type(&self) -> key::secp256k1::KeyType {
         key::secp256k1::KeyType::Hot
@@ -245,8 +413,10 @@ fn test_public_key() {
     let sig1 = pk1.sign_digest(&hashed).unwrap();
     assert_eq!(sig1.to_bytes().len(), crate::key::secp256k1::signature::LEN);
 
-    let pubkey4 = Key::from_signature(&hashed, &sig1.to_bytes()).unwrap();
+    let digest = <[u8; 32]>::try_from(hashed.as_slice()).unwrap();
+    let pubkey4 = Key::from_signature(&digest, &sig1).unwrap();
     assert_eq!(pubkey3, pubkey4);
+    assert_eq!(pk1.to_public_key(), pubkey4);
 
     assert!(pubkey1.verify(&hashed, &sig1.to_bytes()).unwrap());
     assert!(pubkey2.verify(&hashed, &sig1.to_bytes()).unwrap());
@@ -263,14 +433,279 @@ fn test_public_key() {
     let eth_to_h160 = H160::from_str(&eth_addr.trim_start_matches("0x")).unwrap();
     assert_eq!(eth_to_h160, pubkey1.to_h160());
 
-    let x_avax_addr = pubkey1.to_hrp_address(1, "X").unwrap();
-    let p_avax_addr = pubkey1.to_hrp_address(1, "P").unwrap();
+    // already in the EIP-55 checksummed form
+    assert!(crate::formatting::is_valid_eip55(&eth_addr));
+    assert_eq!(
+        crate::formatting::eip55_checksum(&format!("{:x}", pubkey1.to_h160())).unwrap(),
+        eth_addr
+    );
+
+    let x_avax_addr = pubkey1.to_hrp_address(1u32, "X").unwrap();
+    let p_avax_addr = pubkey1.to_hrp_address(1u32, "P").unwrap();
     log::info!("AVAX X address: {}", x_avax_addr);
     log::info!("AVAX P address: {}", p_avax_addr);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_from_sec1_bytes --exact --show-output
+#[test]
+fn test_from_sec1_bytes() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pubkey = crate::key::secp256k1::private_key::Key::generate()
+        .unwrap()
+        .to_public_key();
+
+    let compressed = pubkey.to_compressed_bytes();
+    let uncompressed = pubkey.to_uncompressed_bytes();
+    assert!(compressed[0] == 0x02 || compressed[0] == 0x03);
+    assert_eq!(uncompressed[0], 0x04);
+    assert_eq!(compressed[1..], uncompressed[1..LEN]);
+
+    // the addresses do not depend on which form is decoded
+    let from_compressed = Key::from_sec1_bytes(&compressed).unwrap();
+    let from_uncompressed = Key::from_sec1_bytes(&uncompressed).unwrap();
+    assert_eq!(from_compressed, from_uncompressed);
+    assert_eq!(from_compressed.to_eth_address(), pubkey.to_eth_address());
+    assert_eq!(from_uncompressed.to_eth_address(), pubkey.to_eth_address());
+    assert_eq!(from_compressed.to_compressed_bytes(), compressed);
+    assert_eq!(from_uncompressed.to_uncompressed_bytes(), uncompressed);
+
+    // off the curve
+    let mut off_curve = uncompressed;
+    off_curve[UNCOMPRESSED_LEN - 1] ^= 0x01;
+    assert!(Key::from_sec1_bytes(&off_curve).is_err());
+
+    // identity
+    assert!(Key::from_sec1_bytes(&[0x00]).is_err());
+
+    assert!(Key::from_sec1_bytes(&compressed[..LEN - 1]).is_err());
+    assert!(Key::from_sec1_bytes(&[]).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_sort --exact --show-output
+#[test]
+fn test_sort() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let mut pubkeys = Vec::new();
+    for _ in 0..5 {
+        pubkeys.push(
+            crate::key::secp256k1::private_key::Key::generate()
+                .unwrap()
+                .to_public_key(),
+        );
+    }
+
+    let mut sorted1 = pubkeys.clone();
+    sorted1.sort();
+    for w in sorted1.windows(2) {
+        assert!(w[0].to_compressed_bytes() < w[1].to_compressed_bytes());
+    }
+
+    // same order regardless of the input order
+    let mut sorted2 = pubkeys.clone();
+    sorted2.reverse();
+    sorted2.sort();
+    assert_eq!(sorted1, sorted2);
+
+    // multisig owners are sorted by the short addresses
+    let mut addrs: Vec<short::Id> = pubkeys.iter().map(|pk| pk.to_short_id().unwrap()).collect();
+    addrs.sort();
+    for w in addrs.windows(2) {
+        assert!(w[0] < w[1]);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_from_signature --exact --show-output
+#[test]
+fn test_from_signature() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    for _ in 0..10 {
+        let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+
+        let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+        let digest = <[u8; 32]>::try_from(hash::sha256(&msg).as_slice()).unwrap();
+
+        let sig = pk.sign_digest(&digest).unwrap();
+        let recovered = Key::from_signature(&digest, &sig).unwrap();
+        assert_eq!(recovered, pk.to_public_key());
+
+        // flipping the recovery Id recovers a different point, if any
+        let mut b = sig.to_bytes();
+        b[64] ^= 1;
+        let flipped = Sig::from_bytes(&b).unwrap();
+        if let Ok(other) = Key::from_signature(&digest, &flipped) {
+            assert_ne!(other, pk.to_public_key());
+        }
+
+        // recovery Id must be in range
+        b[64] = 4;
+        assert!(Sig::from_bytes(&b).is_err());
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_verify --exact --show-output
+#[test]
+fn test_verify() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk1 = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let pk2 = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey1 = pk1.to_public_key();
+    let pubkey2 = pk2.to_public_key();
+
+    let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+    let digest = <[u8; 32]>::try_from(hash::sha256(&msg).as_slice()).unwrap();
+
+    let sig = pk1.sign_digest(&digest).unwrap();
+    assert!(pubkey1.verify_digest(&digest, &sig));
+    assert!(!pubkey2.verify_digest(&digest, &sig));
+
+    let mut tampered = digest;
+    tampered[0] ^= 0x01;
+    assert!(!pubkey1.verify_digest(&tampered, &sig));
+
+    // EIP-191 "personal_sign"
+    let msg_digest = ethers_core::utils::hash_message(&msg);
+    let msg_sig = pk1.sign_digest(msg_digest.as_bytes()).unwrap();
+    assert!(pubkey1.verify_message(&msg, &msg_sig));
+    assert!(!pubkey2.verify_message(&msg, &msg_sig));
+    assert!(!pubkey1.verify_message(&msg[1..], &msg_sig));
+
+    // raw digest signature does not verify as a prefixed message
+    assert!(!pubkey1.verify_message(&digest, &sig));
+}
+
+/// Fails if the SPKI or PKCS#8 algorithm is not "id-ecPublicKey" on secp256k1.
+#[cfg(feature = "std")]
+pub(crate) fn check_ec_algorithm(algorithm: &spki::AlgorithmIdentifier<'_>) -> io::Result<()> {
+    if algorithm.oid != EC_PUBLIC_KEY_OID {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "not an EC key (algorithm OID {}, expected {})",
+                algorithm.oid, EC_PUBLIC_KEY_OID
+            ),
+        ));
+    }
+    let curve = algorithm.parameters_oid().map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("missing EC curve parameters {}", e),
+        )
+    })?;
+    check_curve_oid(curve)
+}
+
+/// Fails if the named curve is not secp256k1 (e.g., "1.2.840.10045.3.1.7" for NIST P-256).
+#[cfg(feature = "std")]
+pub(crate) fn check_curve_oid(curve: ObjectIdentifier) -> io::Result<()> {
+    if curve != SECP256K1_OID {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "wrong curve OID {} (expected secp256k1 {})",
+                curve, SECP256K1_OID
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_to_hrp_address_with_hrp --exact --show-output
+#[test]
+fn test_to_hrp_address_with_hrp() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pubkey = crate::key::secp256k1::private_key::Key::generate()
+        .unwrap()
+        .to_public_key();
+
+    // same as the network-mapped HRP
+    assert_eq!(
+        pubkey.to_hrp_address_with_hrp("avax", "X").unwrap(),
+        pubkey.to_hrp_address(1u32, "X").unwrap()
+    );
+    assert_eq!(
+        pubkey.to_hrp_address_with_hrp("custom", "P").unwrap(),
+        pubkey.to_hrp_address(1337u32, "P").unwrap()
+    );
+
+    let local = pubkey.to_hrp_address_with_hrp("local", "X").unwrap();
+    assert!(local.starts_with("X-local1"));
+    let (hrp, short_bytes) = address::avax_address_to_short_bytes("X", &local).unwrap();
+    assert_eq!(hrp, "local");
+    assert_eq!(short_bytes, pubkey.to_short_bytes().unwrap());
+
+    assert!(pubkey.to_hrp_address_with_hrp("Local", "X").is_err());
+    assert!(pubkey.to_hrp_address_with_hrp("", "X").is_err());
+    assert!(pubkey.to_hrp_address_with_hrp("my subnet", "X").is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::public_key::test_pem --exact --show-output
+#[test]
+fn test_pem() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+
+    let pem = pubkey.to_pem().unwrap();
+    assert!(pem.starts_with("-----BEGIN PUBLIC KEY-----"));
+    assert_eq!(Key::from_pem(&pem).unwrap(), pubkey);
+
+    // "openssl ec -pubout" of a secp256k1 key
+    let openssl_pem = "-----BEGIN PUBLIC KEY-----
+MFYwEAYHKoZIzj0CAQYFK4EEAAoDQgAEYOu1E5LG428m9h2X4JLLYK5f8z1+ecTb
+MTqrWj47t5/eBHo5XauXMiAS+zIeWiMxRW7exggbDMzi/cUVNVLnyw==
+-----END PUBLIC KEY-----
+";
+    let loaded = Key::from_pem(openssl_pem).unwrap();
+    assert_eq!(loaded.to_pem().unwrap().trim(), openssl_pem.trim());
+    assert_eq!(
+        loaded,
+        crate::key::secp256k1::private_key::Key::from_hex(
+            "0x3266a806fb8fcf5ec63d22f27de6a82a5f719a2e6646170a83b78e16c1e8419d"
+        )
+        .unwrap()
+        .to_public_key()
+    );
+
+    // "openssl ec -pubout" of a NIST P-256 key
+    let p256_pem = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEd9kiZGyz+pvMXTtLuMXcu4XFkt3E
+NcODvslKma4PKr4FKzuyoG7LnrnVj8ByM2Kecqlom+cFskzcau5EdnKQSw==
+-----END PUBLIC KEY-----
+";
+    let err = Key::from_pem(p256_pem).unwrap_err();
+    assert!(err.to_string().contains("wrong curve"), "{}", err);
+
+    assert!(Key::from_pem(&pem.replace("PUBLIC KEY", "EC PUBLIC KEY")).is_err());
+    assert!(Key::from_pem("not a pem").is_err());
+}
+
 /// Same as "from_public_key_der".
 /// ref. <https://github.com/gakonst/ethers-rs/tree/master/ethers-signers/src/aws> "decode_pubkey"
+#[cfg(feature = "std")]
 pub fn load_ecdsa_verifying_key_from_public_key(b: &[u8]) -> io::Result<VerifyingKey> {
     let spk = spki::SubjectPublicKeyInfo::try_from(b).map_err(|e| {
         Error::new(
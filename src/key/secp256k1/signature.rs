@@ -1,10 +1,23 @@
-use std::io::{self, Error, ErrorKind};
+#[cfg(feature = "std")]
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+    str::FromStr,
+};
+
+use k256::{
+    ecdsa::{RecoveryId, Signature},
+    elliptic_curve::scalar::IsHigh,
+};
 
+#[cfg(feature = "std")]
 use ethers_core::k256::ecdsa::{
     recoverable::{Id as RId, Signature as RSig},
     Signature as KSig,
 };
-use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+#[cfg(feature = "std")]
+use k256::{ecdsa::VerifyingKey, FieldBytes, NonZeroScalar};
+#[cfg(feature = "std")]
 use zerocopy::AsBytes;
 
 /// The length of recoverable ECDSA signature.
@@ -21,6 +34,7 @@ pub struct Sig(pub (Signature, RecoveryId));
 
 impl Sig {
     /// Loads the recoverable signature from the bytes.
+    #[cfg(feature = "std")]
     pub fn from_bytes(b: &[u8]) -> io::Result<Self> {
         if b.len() != LEN {
             return Err(Error::new(
@@ -50,11 +64,13 @@ impl Sig {
         let bb = self.0 .0.to_bytes();
 
         let mut b = [0u8; LEN];
-        b.copy_from_slice(&[&bb[..], &[u8::from(self.0 .1)]].concat());
+        b[..LEN - 1].copy_from_slice(&bb);
+        b[LEN - 1] = u8::from(self.0 .1);
         b
     }
 
     /// Recovers the public key from the 32-byte SHA256 output message using its signature.
+    #[cfg(feature = "std")]
     pub fn recover_public_key(
         &self,
         digest: &[u8],
@@ -62,23 +78,146 @@ impl Sig {
         recover_pubkeys(&self.0 .0, self.0 .1, digest)
     }
 
+    /// Returns true if the public key recovered from the 32-byte digest
+    /// derives the expected ETH address (e.g., the signer of a relay request).
+    /// Returns false if the recovery fails.
+    #[cfg(feature = "std")]
+    pub fn recovers_to(&self, digest: &[u8; 32], expected: primitive_types::H160) -> bool {
+        match self.recover_public_key(digest) {
+            Ok((pubkey, _)) => pubkey.to_h160() == expected,
+            Err(_) => false,
+        }
+    }
+
+    /// Same as "recovers_to" but for the message signed with the EIP-191 prefix
+    /// ("\x19Ethereum Signed Message:\n" + len(message)) as in "eth_sign".
+    /// ref. <https://eips.ethereum.org/EIPS/eip-191>
+    #[cfg(feature = "std")]
+    pub fn recovers_to_message(&self, msg: &[u8], expected: primitive_types::H160) -> bool {
+        let digest = ethers_core::utils::hash_message(msg);
+        self.recovers_to(&digest.0, expected)
+    }
+
+    /// Loads the recoverable signature from its big-endian "r" and "s"
+    /// and the recovery Id (e.g., "v" minus 27 for Ethereum signatures).
+    /// Both "r" and "s" must be in the range "[1, n)".
+    #[cfg(feature = "std")]
+    pub fn from_rsv(r: [u8; 32], s: [u8; 32], v: u8) -> io::Result<Self> {
+        for (name, b) in [("r", r), ("s", s)] {
+            let scalar: Option<NonZeroScalar> =
+                NonZeroScalar::from_repr(FieldBytes::from(b)).into();
+            if scalar.is_none() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("signature '{}' out of range [1, n)", name),
+                ));
+            }
+        }
+
+        let sig = Signature::from_scalars(r, s).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed Signature::from_scalars {}", e),
+            )
+        })?;
+        let recid = RecoveryId::try_from(v).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to create recovery Id {}", e),
+            )
+        })?;
+        Ok(Self((sig, recid)))
+    }
+
+    #[cfg(feature = "std")]
     pub fn r(&self) -> primitive_types::U256 {
-        let b = self.0 .0.to_vec();
-        primitive_types::U256::from_big_endian(&b[0..32])
+        primitive_types::U256::from_big_endian(&self.r_bytes())
     }
 
+    #[cfg(feature = "std")]
     pub fn s(&self) -> primitive_types::U256 {
-        let b = self.0 .0.to_vec();
-        primitive_types::U256::from_big_endian(&b[32..64])
+        primitive_types::U256::from_big_endian(&self.s_bytes())
+    }
+
+    /// Returns the big-endian "r" (the first 32 bytes of "to_bytes").
+    pub fn r_bytes(&self) -> [u8; 32] {
+        self.0 .0.r().to_bytes().into()
+    }
+
+    /// Returns the big-endian "s" (the next 32 bytes of "to_bytes").
+    pub fn s_bytes(&self) -> [u8; 32] {
+        self.0 .0.s().to_bytes().into()
+    }
+
+    /// Returns the recovery Id (the last byte of "to_bytes").
+    pub fn recovery_id(&self) -> u8 {
+        u8::from(self.0 .1)
     }
 
     /// Returns the recovery Id.
     pub fn v(&self) -> u64 {
         // ref. <https://github.com/RustCrypto/elliptic-curves/blob/p384/v0.11.2/k256/src/ecdsa/recoverable.rs> "recovery_id"
-        u8::from(self.0 .1) as u64
+        self.recovery_id() as u64
+    }
+
+    /// Encodes the "r" and "s" of the signature in ASN.1 DER,
+    /// as defined by ANS X9.62–2005 and RFC 3279 Section 2.2.3.
+    /// The recovery Id is dropped.
+    #[cfg(feature = "std")]
+    pub fn to_der(&self) -> Vec<u8> {
+        self.0 .0.to_der().as_bytes().to_vec()
+    }
+
+    /// Loads the recoverable signature from the DER-encoded "r" and "s".
+    /// DER does not carry the recovery Id, so it must be provided
+    /// (e.g., "v" minus 27 for Ethereum signatures).
+    /// Use "decode_signature" for the non-recoverable signature.
+    #[cfg(feature = "std")]
+    pub fn from_der(b: &[u8], recovery_id: Option<u8>) -> io::Result<Self> {
+        let recovery_id = recovery_id.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "recovery Id is required to load the recoverable signature from DER",
+            )
+        })?;
+
+        let sig = Signature::from_der(b).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed Signature::from_der {}", e),
+            )
+        })?;
+        let recid = RecoveryId::try_from(recovery_id).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to create recovery Id {}", e),
+            )
+        })?;
+        Ok(Self((sig, recid)))
+    }
+
+    /// Returns true if "s" is in the lower half of the curve order.
+    /// Avalanche and Ethereum (EIP-2) reject high-S signatures.
+    /// ref. <https://eips.ethereum.org/EIPS/eip-2>
+    pub fn is_low_s(&self) -> bool {
+        !bool::from(self.0 .0.s().is_high())
+    }
+
+    /// Flips the signature to its low-S form ("s" to "n - s"), if not already,
+    /// and flips the y-parity of the recovery Id so that the same public key
+    /// is still recovered.
+    pub fn normalize_s(&mut self) {
+        if let Some(normalized) = self.0 .0.normalize_s() {
+            let recid = self.0 .1;
+            self.0 = (
+                normalized,
+                RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced()),
+            );
+        }
     }
 }
 
+#[cfg(feature = "std")]
 fn recover_pubkeys(
     rsig: &Signature,
     recid: RecoveryId,
@@ -96,6 +235,95 @@ fn recover_pubkeys(
     Ok((vkey.into(), vkey))
 }
 
+/// Converts the non-recoverable signature (e.g., from a remote KMS) to the
+/// recoverable one, by normalizing "s" and then trying each recovery Id
+/// until the recovered public key matches the expected one.
+#[cfg(feature = "std")]
+pub fn sig_from_digest_trial_recovery(
+    sig: &Signature,
+    digest: &[u8; 32],
+    public_key: &crate::key::secp256k1::public_key::Key,
+) -> io::Result<Sig> {
+    // EIP-2, "s" needs to be smaller than half of the curve
+    let sig = sig.normalize_s().unwrap_or(*sig);
+    for recid in 0..2u8 {
+        let rsig = Sig((sig, RecoveryId::try_from(recid).expect("valid recovery Id")));
+        if let Ok(recovered) = crate::key::secp256k1::public_key::Key::from_signature(digest, &rsig)
+        {
+            if recovered == *public_key {
+                return Ok(rsig);
+            }
+        }
+    }
+    Err(Error::new(
+        ErrorKind::InvalidData,
+        "no recovery Id matches the public key",
+    ))
+}
+
+/// Batch-verifies the (public key, 32-byte digest, signature) triples,
+/// returning the result of each triple in the same order.
+/// This is NOT the batch verification that amortizes the field inversions
+/// across the triples: "k256" does not support it for ECDSA, so each triple
+/// is verified on its own (same cost as "verify_digest" per triple),
+/// in parallel if the "parallel" feature is enabled.
+/// The parallel speedup is at most the number of cores, and the first call
+/// spawns the "rayon" global thread pool (see "private_key::generate_n").
+/// Run "examples/key_secp256k1_verify_all.rs" with the "parallel" feature
+/// to measure the speedup on the target host.
+#[cfg(feature = "std")]
+pub fn batch_verify(
+    items: &[(crate::key::secp256k1::public_key::Key, [u8; 32], Sig)],
+) -> Vec<bool> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items
+            .par_iter()
+            .map(|(pubkey, digest, sig)| pubkey.verify_digest(digest, sig))
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items
+            .iter()
+            .map(|(pubkey, digest, sig)| pubkey.verify_digest(digest, sig))
+            .collect()
+    }
+}
+
+/// Returns true if all triples are valid, verifying each triple on its own
+/// (see "batch_verify"). Stops at the first invalid triple.
+#[cfg(feature = "std")]
+pub fn batch_verify_all(items: &[(crate::key::secp256k1::public_key::Key, [u8; 32], Sig)]) -> bool {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items
+            .par_iter()
+            .all(|(pubkey, digest, sig)| pubkey.verify_digest(digest, sig))
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items
+            .iter()
+            .all(|(pubkey, digest, sig)| pubkey.verify_digest(digest, sig))
+    }
+}
+
+/// Same as "batch_verify", named after what it does since no work
+/// is amortized across the triples.
+#[cfg(feature = "std")]
+pub fn verify_each(items: &[(crate::key::secp256k1::public_key::Key, [u8; 32], Sig)]) -> Vec<bool> {
+    batch_verify(items)
+}
+
+/// Same as "batch_verify_all".
+#[cfg(feature = "std")]
+pub fn verify_all(items: &[(crate::key::secp256k1::public_key::Key, [u8; 32], Sig)]) -> bool {
+    batch_verify_all(items)
+}
+
 impl From<Sig> for Signature {
     fn from(sig: Sig) -> Self {
         sig.0 .0
@@ -108,6 +336,62 @@ impl From<Sig> for [u8; LEN] {
     }
 }
 
+/// Encodes "to_bytes" in "0x"-prefixed hex (130 hex characters),
+/// with the recovery Id in the last byte.
+/// ref. <https://doc.rust-lang.org/std/fmt/trait.Display.html>
+/// Use "Self.to_string()" to directly invoke this
+#[cfg(feature = "std")]
+impl fmt::Display for Sig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.to_bytes()))
+    }
+}
+
+/// Parses the hex-encoded signature with or without the "0x" prefix,
+/// as encoded by "Display".
+/// ref. <https://doc.rust-lang.org/std/str/trait.FromStr.html>
+#[cfg(feature = "std")]
+impl FromStr for Sig {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let hex_sig = s.strip_prefix("0x").unwrap_or(s);
+        if hex_sig.len() != LEN * 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid signature hex length {} (expected {})",
+                    hex_sig.len(),
+                    LEN * 2
+                ),
+            ));
+        }
+        let b = hex::decode(hex_sig).map_err(|e| {
+            Error::new(ErrorKind::InvalidInput, format!("failed hex::decode {}", e))
+        })?;
+        Self::from_bytes(&b)
+    }
+}
+
+/// Signs the arbitrary 32-byte digest with the arbitrary private key,
+/// so that the signature is always valid and recoverable (low-S).
+/// Use "Sig::from_bytes" on raw fuzzer input to fuzz the malformed signatures.
+/// NOT for production signing.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Sig {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let pk = <crate::key::secp256k1::private_key::Key as arbitrary::Arbitrary>::arbitrary(u)?;
+        let digest: [u8; 32] = u.arbitrary()?;
+        pk.sign_digest(&digest)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (crate::key::secp256k1::private_key::LEN + 32, None)
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_signature --exact --show-output
 #[test]
 fn test_signature() {
@@ -130,10 +414,248 @@ fn test_signature() {
     assert_eq!(pubkey, recovered_pubkey);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_recovers_to --exact --show-output
+#[test]
+fn test_recovers_to() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let other = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let expected = pk.to_public_key().to_h160();
+    let unexpected = other.to_public_key().to_h160();
+
+    let digest = <[u8; 32]>::try_from(crate::hash::sha256(b"hello").as_slice()).unwrap();
+    let sig = pk.sign_digest(&digest).unwrap();
+    assert!(sig.recovers_to(&digest, expected));
+    assert!(!sig.recovers_to(&digest, unexpected));
+
+    let mut tampered = digest;
+    tampered[0] ^= 0x01;
+    assert!(!sig.recovers_to(&tampered, expected));
+
+    let msg = b"relay request";
+    let msg_sig = pk.sign_eth_message(msg).unwrap();
+    assert!(msg_sig.recovers_to_message(msg, expected));
+    assert!(!msg_sig.recovers_to_message(msg, unexpected));
+    assert!(!msg_sig.recovers_to_message(b"other request", expected));
+
+    // the message variant hashes with the EIP-191 prefix
+    assert!(!sig.recovers_to_message(&digest, expected));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_normalize_s --exact --show-output
+#[test]
+fn test_normalize_s() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::from_hex(
+        "0x7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d",
+    )
+    .unwrap();
+    let pubkey = pk.to_public_key();
+    let digest = <[u8; 32]>::try_from(crate::hash::sha256(b"hello world").as_slice()).unwrap();
+
+    // "k256" always signs in the low-S form
+    let sig = pk.sign_digest(&digest).unwrap();
+    assert!(sig.is_low_s());
+
+    let mut low_s = sig.clone();
+    low_s.normalize_s();
+    assert_eq!(low_s, sig);
+
+    // "s" to "n - s" with the flipped y-parity
+    let neg_s = -*sig.0 .0.s();
+    let high_s = Signature::from_scalars(sig.0 .0.r().to_bytes(), neg_s.to_bytes()).unwrap();
+    let recid = sig.0 .1;
+    let mut high = Sig((
+        high_s,
+        RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced()),
+    ));
+    assert!(!high.is_low_s());
+    assert_ne!(high.to_bytes(), sig.to_bytes());
+    assert!(!pubkey.verify_digest(&digest, &high));
+
+    high.normalize_s();
+    assert!(high.is_low_s());
+    assert_eq!(high, sig);
+    assert!(pubkey.verify_digest(&digest, &high));
+    assert_eq!(
+        crate::key::secp256k1::public_key::Key::from_signature(&digest, &high).unwrap(),
+        pubkey
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_der --exact --show-output
+#[test]
+fn test_der() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+
+    let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+    let digest = crate::hash::sha256(&msg);
+
+    let sig = pk.sign_digest(&digest).unwrap();
+    let der = sig.to_der();
+    assert_eq!(der[0], 0x30); // ASN.1 SEQUENCE
+
+    let recid = u8::from(sig.0 .1);
+    let decoded = Sig::from_der(&der, Some(recid)).unwrap();
+    assert_eq!(decoded, sig);
+    assert!(Sig::from_der(&der, None).is_err());
+    assert!(Sig::from_der(&der, Some(4)).is_err());
+    assert!(Sig::from_der(&der[1..], Some(recid)).is_err());
+
+    // same DER as the one decoded for KMS
+    assert_eq!(
+        decode_signature(&der).unwrap().to_bytes().as_slice(),
+        &sig.to_bytes()[..64]
+    );
+
+    // "r" and "s" are preserved
+    let rsig = RSig::try_from(&sig.to_bytes()[..]).unwrap();
+    let ethsig = rsig_to_ethsig(&rsig);
+
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    ethsig.r.to_big_endian(&mut r);
+    ethsig.s.to_big_endian(&mut s);
+    let decoded_bytes = decoded.to_bytes();
+    assert_eq!(r, decoded_bytes[..32]);
+    assert_eq!(s, decoded_bytes[32..64]);
+    assert_eq!(ethsig.v, decoded.v() + 27);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_rsv --exact --show-output
+#[test]
+fn test_rsv() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+
+    let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+    let digest = crate::hash::sha256(&msg);
+
+    let sig = pk.sign_digest(&digest).unwrap();
+    let b = sig.to_bytes();
+    assert_eq!(sig.r_bytes(), b[..32]);
+    assert_eq!(sig.s_bytes(), b[32..64]);
+    assert_eq!(sig.r(), primitive_types::U256::from_big_endian(&b[..32]));
+    assert_eq!(sig.s(), primitive_types::U256::from_big_endian(&b[32..64]));
+    assert_eq!(sig.recovery_id(), b[64]);
+    assert_eq!(sig.v(), b[64] as u64);
+
+    let loaded = Sig::from_rsv(sig.r_bytes(), sig.s_bytes(), sig.recovery_id()).unwrap();
+    assert_eq!(loaded, sig);
+    assert_eq!(loaded.to_bytes(), b);
+
+    // secp256k1 curve order "n"
+    let n = <[u8; 32]>::try_from(
+        hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+            .unwrap()
+            .as_slice(),
+    )
+    .unwrap();
+    let mut n_minus_1 = n;
+    n_minus_1[31] -= 1;
+
+    assert!(Sig::from_rsv([0u8; 32], sig.s_bytes(), 0).is_err());
+    assert!(Sig::from_rsv(sig.r_bytes(), [0u8; 32], 0).is_err());
+    assert!(Sig::from_rsv(n, sig.s_bytes(), 0).is_err());
+    assert!(Sig::from_rsv(sig.r_bytes(), n, 0).is_err());
+    assert!(Sig::from_rsv(sig.r_bytes(), [0xff; 32], 0).is_err());
+    assert!(Sig::from_rsv(n_minus_1, n_minus_1, 0).is_ok());
+    assert!(Sig::from_rsv(sig.r_bytes(), sig.s_bytes(), 4).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_batch_verify --exact --show-output
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="parallel" -- key::secp256k1::signature::test_batch_verify --exact --show-output
+#[test]
+fn test_batch_verify() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let mut items = Vec::new();
+    for _ in 0..20 {
+        let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+
+        let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+        let digest = <[u8; 32]>::try_from(crate::hash::sha256(&msg).as_slice()).unwrap();
+
+        let sig = pk.sign_digest(&digest).unwrap();
+        items.push((pk.to_public_key(), digest, sig));
+    }
+    assert!(batch_verify(&[]).is_empty());
+    assert!(batch_verify_all(&[]));
+    assert_eq!(batch_verify(&items), vec![true; items.len()]);
+    assert!(batch_verify_all(&items));
+    assert_eq!(verify_each(&items), batch_verify(&items));
+    assert!(verify_all(&items));
+
+    // tamper the digest of the 3rd, and swap the public key of the 8th
+    items[3].1[0] ^= 0x01;
+    items[8].0 = items[9].0;
+
+    let results = batch_verify(&items);
+    for (i, ok) in results.iter().enumerate() {
+        assert_eq!(*ok, i != 3 && i != 8, "unexpected result at {}", i);
+    }
+    assert!(!batch_verify_all(&items));
+    assert!(!verify_all(&items));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_sig_from_digest_trial_recovery --exact --show-output
+#[test]
+fn test_sig_from_digest_trial_recovery() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let other = crate::key::secp256k1::private_key::Key::generate().unwrap();
+
+    for _ in 0..10 {
+        let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+        let digest = <[u8; 32]>::try_from(crate::hash::sha256(&msg).as_slice()).unwrap();
+
+        let sig = pk.sign_digest(&digest).unwrap();
+        let recovered =
+            sig_from_digest_trial_recovery(&sig.0 .0, &digest, &pk.to_public_key()).unwrap();
+        assert_eq!(recovered, sig);
+
+        // high-S signatures (e.g., from KMS) are normalized first
+        let neg_s = -*sig.0 .0.s();
+        let high_s = Signature::from_scalars(sig.0 .0.r().to_bytes(), neg_s.to_bytes()).unwrap();
+        let recovered =
+            sig_from_digest_trial_recovery(&high_s, &digest, &pk.to_public_key()).unwrap();
+        assert_eq!(recovered, sig);
+
+        assert!(
+            sig_from_digest_trial_recovery(&sig.0 .0, &digest, &other.to_public_key()).is_err()
+        );
+    }
+}
+
 /// Loads the recoverable signature from the DER-encoded bytes,
 /// as defined by ANS X9.62–2005 and RFC 3279 Section 2.2.3.
 /// ref. <https://docs.aws.amazon.com/kms/latest/APIReference/API_Sign.html#KMS-Sign-response-Signature>
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "decode_signature"
+#[cfg(feature = "std")]
 pub fn decode_signature(b: &[u8]) -> io::Result<KSig> {
     let sig = KSig::from_der(b)
         .map_err(|e| Error::new(ErrorKind::Other, format!("failed KSig::from_der {}", e)))?;
@@ -146,6 +668,7 @@ pub fn decode_signature(b: &[u8]) -> io::Result<KSig> {
 
 /// Converts to recoverable signature of 65-byte.
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "rsig_from_digest_bytes_trial_recovery"
+#[cfg(feature = "std")]
 pub fn rsig_from_digest_bytes_trial_recovery(
     sig: &KSig,
     digest: [u8; 32],
@@ -166,6 +689,7 @@ pub fn rsig_from_digest_bytes_trial_recovery(
 /// Checks whether the specified recoverable signature can derive
 /// the expected verifying key from the digest bytes.
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "check_candidate"
+#[cfg(feature = "std")]
 fn check_candidate(sig: &RSig, digest: [u8; 32], vk: &k256::ecdsa::VerifyingKey) -> bool {
     if let Ok(old_k256_recovered_vk) =
         sig.recover_verifying_key_from_digest_bytes(digest.as_ref().into())
@@ -179,6 +703,7 @@ fn check_candidate(sig: &RSig, digest: [u8; 32], vk: &k256::ecdsa::VerifyingKey)
 /// Converts a recoverable signature to an ethers signature.
 /// Combine signature with recovery ID to recover the public key of the signer later.
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "rsig_to_ethsig"
+#[cfg(feature = "std")]
 pub fn rsig_to_ethsig(sig: &RSig) -> ethers_core::types::Signature {
     let v: u8 = sig.recovery_id().into();
     let v = (v + 27) as u64;
@@ -192,9 +717,350 @@ pub fn rsig_to_ethsig(sig: &RSig) -> ethers_core::types::Signature {
     ethers_core::types::Signature { r, s, v }
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_rsig_to_ethsig_eip712 --exact --show-output
+#[test]
+fn test_rsig_to_ethsig_eip712() {
+    use ethers_core::types::transaction::eip712::{Eip712, TypedData};
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // ref. <https://eips.ethereum.org/EIPS/eip-712> "Example"
+    let typed_data: TypedData = serde_json::from_str(
+        r#"{
+  "types": {
+    "EIP712Domain": [
+      {"name": "name", "type": "string"},
+      {"name": "version", "type": "string"},
+      {"name": "chainId", "type": "uint256"},
+      {"name": "verifyingContract", "type": "address"}
+    ],
+    "Person": [
+      {"name": "name", "type": "string"},
+      {"name": "wallet", "type": "address"}
+    ],
+    "Mail": [
+      {"name": "from", "type": "Person"},
+      {"name": "to", "type": "Person"},
+      {"name": "contents", "type": "string"}
+    ]
+  },
+  "primaryType": "Mail",
+  "domain": {
+    "name": "Ether Mail",
+    "version": "1",
+    "chainId": 1,
+    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+  },
+  "message": {
+    "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+    "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+    "contents": "Hello, Bob!"
+  }
+}"#,
+    )
+    .unwrap();
+    let digest = typed_data.encode_eip712().unwrap();
+    assert_eq!(
+        hex::encode(digest),
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+    );
+
+    for _ in 0..10 {
+        let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+        let address: ethers_core::types::Address = pk.to_public_key().to_h160().into();
+
+        let sig = pk.sign_digest(&digest).unwrap();
+
+        // KMS may return either the low-S or the high-S form in DER,
+        // which "decode_signature" normalizes to the low-S
+        let neg_s = -*sig.0 .0.s();
+        let high_s = Signature::from_scalars(sig.0 .0.r().to_bytes(), neg_s.to_bytes()).unwrap();
+        for der in [sig.to_der(), high_s.to_der().as_bytes().to_vec()] {
+            let ksig = decode_signature(&der).unwrap();
+            let rsig = rsig_from_digest_bytes_trial_recovery(
+                &ksig,
+                digest,
+                &pk.to_public_key().to_verifying_key(),
+            );
+
+            let ethsig = rsig_to_ethsig(&rsig);
+            assert!(ethsig.v == 27 || ethsig.v == 28);
+            assert_eq!(ethsig.v, sig.v() + 27);
+            assert_eq!(
+                ethsig
+                    .recover(ethers_core::types::H256::from(digest))
+                    .unwrap(),
+                address
+            );
+        }
+    }
+
+    // "n/2" is the largest low "s", and "n/2 + 1" the smallest high "s"
+    // whose low-S form is "n/2" (the curve order "n" is odd)
+    let half_n =
+        hex::decode("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0").unwrap();
+    let half_n_plus_one =
+        hex::decode("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1").unwrap();
+
+    // any valid "r" works, since the signer is recovered from the signature below
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let r = pk.sign_digest(&digest).unwrap().0 .0.r().to_bytes();
+    let boundary = Signature::from_scalars(r, FieldBytes::clone_from_slice(&half_n)).unwrap();
+    let high_s =
+        Signature::from_scalars(r, FieldBytes::clone_from_slice(&half_n_plus_one)).unwrap();
+
+    let (pubkey, vkey) =
+        recover_pubkeys(&boundary, RecoveryId::new(false, false), &digest).unwrap();
+    let address: ethers_core::types::Address = pubkey.to_h160().into();
+
+    // "s == n/2" is low-S, so stays unchanged
+    let mut sig = Sig((boundary, RecoveryId::new(false, false)));
+    assert!(sig.is_low_s());
+    sig.normalize_s();
+    assert_eq!(sig.0 .0, boundary);
+    assert!(!sig.0 .1.is_y_odd());
+    assert!(sig.recovers_to(&digest, pubkey.to_h160()));
+
+    let ksig = decode_signature(boundary.to_der().as_bytes()).unwrap();
+    let ethsig = rsig_to_ethsig(&rsig_from_digest_bytes_trial_recovery(&ksig, digest, &vkey));
+    assert_eq!(ethsig.s, ethers_core::types::U256::from_big_endian(&half_n));
+    assert_eq!(ethsig.v, 27);
+    assert_eq!(
+        ethsig
+            .recover(ethers_core::types::H256::from(digest))
+            .unwrap(),
+        address
+    );
+
+    // "s == n/2 + 1" is high-S, and its low-S form only recovers
+    // the same signer with the flipped y-parity
+    let mut sig = Sig((high_s, RecoveryId::new(true, false)));
+    assert!(!sig.is_low_s());
+    sig.normalize_s();
+    assert_eq!(sig.0 .0, boundary);
+    assert!(!sig.0 .1.is_y_odd());
+    assert!(sig.recovers_to(&digest, pubkey.to_h160()));
+
+    let ksig = decode_signature(high_s.to_der().as_bytes()).unwrap();
+    let ethsig = rsig_to_ethsig(&rsig_from_digest_bytes_trial_recovery(&ksig, digest, &vkey));
+    assert_eq!(ethsig.s, ethers_core::types::U256::from_big_endian(&half_n));
+    assert_eq!(ethsig.v, 27);
+    assert_eq!(
+        ethsig
+            .recover(ethers_core::types::H256::from(digest))
+            .unwrap(),
+        address
+    );
+}
+
 /// Modify the v value of a signature to conform to eip155
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "apply_eip155"
 pub fn apply_eip155(sig: &mut ethers_core::types::Signature, chain_id: u64) {
     let v = (chain_id * 2 + 35) + ((sig.v - 1) % 2);
     sig.v = v;
 }
+
+/// Returns the chain Id encoded in the EIP-155 "v" ("chain_id * 2 + 35 + y-parity"),
+/// or None if the "v" is not replay-protected (e.g., 27 or 28, or the y-parity).
+/// Inverse of "apply_eip155".
+/// ref. <https://eips.ethereum.org/EIPS/eip-155>
+pub fn recover_chain_id(v: u64) -> Option<u64> {
+    if v >= 35 {
+        Some((v - 35) / 2)
+    } else {
+        None
+    }
+}
+
+/// Normalizes the "v" value of a signature to 27 or 28 (as in "rsig_to_ethsig"),
+/// stripping the chain Id from the EIP-155 "v" and converting the plain
+/// y-parity (0 or 1) of the typed transactions.
+/// Other values are left as is, which fail the recovery.
+/// ref. <https://eips.ethereum.org/EIPS/eip-155>
+pub fn normalize_v(sig: &mut ethers_core::types::Signature) {
+    sig.v = match sig.v {
+        0 | 1 => sig.v + 27,
+        v if v >= 35 => 27 + (v - 35) % 2,
+        v => v,
+    };
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_recover_chain_id --exact --show-output
+#[test]
+fn test_recover_chain_id() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let address: ethers_core::types::Address = pk.to_public_key().to_h160().into();
+
+    let digest = <[u8; 32]>::try_from(crate::hash::sha256(b"hello").as_slice()).unwrap();
+    let sig = pk.sign_digest(&digest).unwrap();
+    let rsig = RSig::try_from(&sig.to_bytes()[..]).unwrap();
+    let ethsig = rsig_to_ethsig(&rsig);
+
+    // ethereum mainnet, goerli, C-chain mainnet/fuji, and a large subnet-evm chain Id
+    for chain_id in [1_u64, 5, 43114, 43113, 99999, u32::MAX as u64] {
+        let mut eip155 = ethsig;
+        apply_eip155(&mut eip155, chain_id);
+        assert_eq!(eip155.v, chain_id * 2 + 35 + sig.v());
+        assert_eq!(recover_chain_id(eip155.v), Some(chain_id));
+
+        normalize_v(&mut eip155);
+        assert_eq!(eip155, ethsig);
+        assert_eq!(
+            eip155
+                .recover(ethers_core::types::H256::from(digest))
+                .unwrap(),
+            address
+        );
+    }
+
+    // e.g., "v" 37 and 38 for chain Id 1
+    assert_eq!(recover_chain_id(37), Some(1));
+    assert_eq!(recover_chain_id(38), Some(1));
+    assert_eq!(recover_chain_id(35), Some(0));
+    assert_eq!(recover_chain_id(27), None);
+    assert_eq!(recover_chain_id(28), None);
+    assert_eq!(recover_chain_id(0), None);
+
+    for (v, normalized) in [
+        (0, 27),
+        (1, 28),
+        (27, 27),
+        (28, 28),
+        (37, 27),
+        (38, 28),
+        (86263, 27),
+        (86264, 28),
+    ] {
+        let mut s = ethsig;
+        s.v = v;
+        normalize_v(&mut s);
+        assert_eq!(s.v, normalized, "unexpected normalized v for {}", v);
+    }
+}
+
+/// Modify the v value of a signature (27 or 28 from "rsig_to_ethsig") for the transaction type.
+/// Legacy transactions use EIP-155 ("chain_id * 2 + 35 + y-parity"), whereas typed
+/// transactions (EIP-2930, EIP-1559) use the plain y-parity (0 or 1).
+/// ref. <https://eips.ethereum.org/EIPS/eip-155>
+/// ref. <https://eips.ethereum.org/EIPS/eip-2718>
+pub fn apply_tx_v(
+    sig: &mut ethers_core::types::Signature,
+    tx: &ethers_core::types::transaction::eip2718::TypedTransaction,
+    chain_id: u64,
+) {
+    use ethers_core::types::transaction::eip2718::TypedTransaction;
+    match tx {
+        TypedTransaction::Legacy(_) => apply_eip155(sig, chain_id),
+        TypedTransaction::Eip2930(_) | TypedTransaction::Eip1559(_) => sig.v = (sig.v - 1) % 2,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_apply_tx_v --exact --show-output
+#[test]
+fn test_apply_tx_v() {
+    use ethers_core::types::{
+        transaction::eip2718::TypedTransaction, Eip1559TransactionRequest, TransactionRequest,
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let address: ethers_core::types::Address = pk.to_public_key().to_h160().into();
+    let to = ethers_core::types::Address::random();
+    let chain_id = 43114_u64;
+
+    let legacy: TypedTransaction = TransactionRequest::new()
+        .to(to)
+        .value(1)
+        .nonce(3)
+        .gas(21000)
+        .gas_price(25)
+        .chain_id(chain_id)
+        .into();
+    let eip2930: TypedTransaction = TypedTransaction::Eip2930(
+        TransactionRequest::new()
+            .to(to)
+            .value(1)
+            .nonce(3)
+            .gas(21000)
+            .gas_price(25)
+            .chain_id(chain_id)
+            .with_access_list(Default::default()),
+    );
+    let eip1559: TypedTransaction = Eip1559TransactionRequest::new()
+        .to(to)
+        .value(1)
+        .nonce(3)
+        .gas(21000)
+        .max_fee_per_gas(50)
+        .max_priority_fee_per_gas(2)
+        .chain_id(chain_id)
+        .into();
+
+    for tx in [legacy, eip2930, eip1559] {
+        let sighash = tx.sighash();
+        let sig = pk.sign_digest(sighash.as_bytes()).unwrap();
+        let rsig = RSig::try_from(&sig.to_bytes()[..]).unwrap();
+
+        let mut ethsig = rsig_to_ethsig(&rsig);
+        apply_tx_v(&mut ethsig, &tx, chain_id);
+        match tx {
+            TypedTransaction::Legacy(_) => assert_eq!(ethsig.v, chain_id * 2 + 35 + sig.v()),
+            _ => assert_eq!(ethsig.v, sig.v()),
+        }
+
+        let recovered = ethsig.recover(sighash).unwrap();
+        assert_eq!(recovered, address);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_signature_string --exact --show-output
+#[test]
+fn test_signature_string() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    for i in 0..10 {
+        let digest = crate::hash::sha256(format!("message {}", i));
+        let sig = pk.sign_digest(&digest).unwrap();
+
+        let s = sig.to_string();
+        assert!(s.starts_with("0x"));
+        assert_eq!(s.len(), 2 + LEN * 2);
+        assert_eq!(sig, s.parse().unwrap());
+        assert_eq!(sig, Sig::from_str(s.trim_start_matches("0x")).unwrap());
+        assert_eq!(sig, Sig::from_str(&format!(" {}\n", s)).unwrap());
+    }
+
+    let s = pk
+        .sign_digest(&crate::hash::sha256("message"))
+        .unwrap()
+        .to_string();
+
+    // wrong length
+    assert!(Sig::from_str(&s[..s.len() - 2]).is_err());
+    assert!(Sig::from_str(&format!("{}00", s)).is_err());
+    assert!(Sig::from_str("").is_err());
+
+    // invalid recovery Id
+    let invalid_recid = format!("{}ff", &s[..s.len() - 2]);
+    assert!(Sig::from_str(&invalid_recid).is_err());
+
+    // invalid hex
+    let invalid_hex = format!("0x{}", "zz".repeat(LEN));
+    assert!(Sig::from_str(&invalid_hex).is_err());
+}
@@ -0,0 +1,41 @@
+//! "proptest" strategies for the keys and addresses, to write the property tests
+//! (e.g., encoding round trips) without hand-rolling the generators.
+//! NOT for production key generation.
+//! ref. <https://docs.rs/proptest/latest/proptest/strategy/trait.Strategy.html>
+use crate::key::secp256k1::{address, private_key};
+use primitive_types::H160;
+use proptest::prelude::*;
+
+/// Generates the valid private keys, uniformly distributed over "[1, n)",
+/// by rejection-sampling the 32-byte scalars (rejects with the negligible
+/// probability of ~2^-128).
+pub fn any_key() -> impl Strategy<Value = private_key::Key> {
+    any::<[u8; private_key::LEN]>().prop_filter_map("scalar not in [1, n)", |b| {
+        private_key::Key::from_bytes(&b).ok()
+    })
+}
+
+/// Generates the EIP-55 checksummed ETH addresses (e.g., "0x8db97C7c..."),
+/// uniformly distributed over the 20-byte address space.
+pub fn any_eth_address() -> impl Strategy<Value = String> {
+    any::<[u8; 20]>().prop_map(|b| address::h160_to_eth_address(&H160::from(b), None))
+}
+
+proptest! {
+    /// RUST_LOG=debug cargo test --package avalanche-types --lib --features="proptest" -- key::secp256k1::strategies::test_key_cb58_round_trip --exact --show-output
+    #[test]
+    fn test_key_cb58_round_trip(k in any_key()) {
+        let encoded = k.to_cb58();
+        prop_assert!(encoded.starts_with(private_key::CB58_ENCODE_PREFIX));
+        prop_assert_eq!(private_key::Key::from_cb58(&encoded).unwrap(), k);
+    }
+
+    /// RUST_LOG=debug cargo test --package avalanche-types --lib --features="proptest" -- key::secp256k1::strategies::test_eth_address_checksum --exact --show-output
+    #[test]
+    fn test_eth_address_checksum(addr in any_eth_address()) {
+        use std::str::FromStr;
+
+        let h160 = H160::from_str(addr.trim_start_matches("0x")).unwrap();
+        prop_assert_eq!(address::h160_to_eth_address(&h160, None), addr);
+    }
+}
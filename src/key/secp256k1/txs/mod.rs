@@ -235,6 +235,8 @@ impl PartialEq for SigIndices {
 pub struct OutputOwners {
     pub locktime: u64,
     pub threshold: u32,
+    /// Must be sorted in ascending order of the short ids and unique,
+    /// otherwise X/P-chain reject the output ("addresses not sorted and unique").
     pub addresses: Vec<short::Id>,
 }
 
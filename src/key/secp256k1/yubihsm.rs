@@ -0,0 +1,279 @@
+//! YubiHSM2 signer, using the "yubihsm-connector" over HTTP.
+//!
+//! The YubiHSM2 closes an authenticated session after 30 seconds of
+//! inactivity (and on connector restarts). The client is opened with
+//! "reconnect" enabled, so the next operation after such a timeout
+//! transparently opens a new session with the same credentials.
+//! Operations in flight when the session drops return an error
+//! and are not retried.
+//!
+//! ref. <https://developers.yubico.com/YubiHSM2/Component_Reference/yubihsm-connector/>
+//! ref. <https://docs.rs/yubihsm>
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{
+    hash,
+    ids::short,
+    key::{
+        self,
+        secp256k1::{public_key::Key as PublicKey, signature::Sig},
+    },
+};
+use async_trait::async_trait;
+use yubihsm::{asymmetric, Client, Connector, Credentials, HttpConfig};
+
+/// Represents the secp256k1 key (asymmetric object "ec-k256") on the YubiHSM2.
+/// Note that the actual private key never leaves the device.
+#[derive(Clone)]
+pub struct YubiKey {
+    client: Client,
+
+    /// Object Id of the asymmetric key.
+    pub object_id: u16,
+
+    /// Public key.
+    pub public_key: PublicKey,
+}
+
+/// Omits the client session.
+impl fmt::Debug for YubiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("YubiKey")
+            .field("object_id", &self.object_id)
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+impl YubiKey {
+    /// Connects to the "yubihsm-connector" (e.g., "http://127.0.0.1:12345"),
+    /// authenticates with the authentication key Id and its password,
+    /// and loads the public key of the asymmetric key object.
+    pub fn connect(
+        connector_url: &str,
+        auth_key_id: u16,
+        password: &str,
+        object_id: u16,
+    ) -> io::Result<Self> {
+        let u = url::Url::parse(connector_url).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid connector URL '{}' ({})", connector_url, e),
+            )
+        })?;
+        let addr = u.host_str().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("no host in connector URL '{}'", connector_url),
+            )
+        })?;
+        let connector = Connector::http(&HttpConfig {
+            addr: addr.to_string(),
+            port: u.port().unwrap_or(12345),
+            ..Default::default()
+        });
+
+        let credentials = Credentials::from_password(auth_key_id, password.as_bytes());
+        let client = Client::open(connector, credentials, true).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to open YubiHSM session {}", e),
+            )
+        })?;
+
+        let pubkey = client.get_public_key(object_id).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed get_public_key for object {} {}", object_id, e),
+            )
+        })?;
+        if pubkey.algorithm != asymmetric::Algorithm::EcK256 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "object {} is {:?}, not secp256k1",
+                    object_id, pubkey.algorithm
+                ),
+            ));
+        }
+
+        let public_key = public_key_from_raw(pubkey.as_ref())?;
+        log::info!(
+            "fetched YubiHSM public key with ETH address '{}' (object {})",
+            public_key.to_eth_address(),
+            object_id
+        );
+
+        Ok(Self {
+            client,
+            object_id,
+            public_key,
+        })
+    }
+
+    pub fn to_public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Converts to Info.
+    pub fn to_info(
+        &self,
+        network_id: impl Into<key::network::NetworkId>,
+    ) -> io::Result<key::secp256k1::Info> {
+        let network_id = network_id.into();
+        let short_addr = self.public_key.to_short_id()?;
+        let eth_addr = self.public_key.to_eth_address();
+        let h160_addr = self.public_key.to_h160();
+
+        let mut addresses = HashMap::new();
+        addresses.insert(
+            network_id.as_u32(),
+            key::secp256k1::ChainAddresses {
+                x: self.public_key.to_hrp_address(network_id, "X")?,
+                p: self.public_key.to_hrp_address(network_id, "P")?,
+            },
+        );
+
+        Ok(key::secp256k1::Info {
+            id: Some(self.object_id.to_string()),
+            key_type: key::secp256k1::KeyType::YubiHsm,
+
+            addresses,
+
+            short_address: short_addr,
+            eth_address: eth_addr,
+            h160_address: h160_addr,
+
+            ..Default::default()
+        })
+    }
+
+    /// Signs the 32-byte SHA256 output message on the device, and converts
+    /// its DER-encoded signature to the recoverable signature.
+    pub async fn sign_digest(&self, digest: &[u8]) -> io::Result<Sig> {
+        // ref. "crypto/sha256.Size"
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid digest length {} (expected {})",
+                    digest.len(),
+                    hash::SHA256_OUTPUT_LEN
+                ),
+            ));
+        }
+
+        let mut fixed_digest = [0u8; hash::SHA256_OUTPUT_LEN];
+        fixed_digest.copy_from_slice(digest);
+
+        // the connector client blocks on HTTP
+        let client = self.client.clone();
+        let object_id = self.object_id;
+        let raw_der = tokio::task::spawn_blocking(move || {
+            client.sign_ecdsa_prehash_raw(object_id, &fixed_digest)
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed spawn_blocking {}", e)))?
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed sign_ecdsa_prehash_raw {}", e),
+            )
+        })?;
+
+        let sig = k256::ecdsa::Signature::from_der(&raw_der).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed Signature::from_der {}", e),
+            )
+        })?;
+        key::secp256k1::signature::sig_from_digest_trial_recovery(
+            &sig,
+            &fixed_digest,
+            &self.public_key,
+        )
+    }
+}
+
+/// Converts the raw "x || y" EC point returned by the device
+/// (without the SEC1 tag) to the public key.
+fn public_key_from_raw(b: &[u8]) -> io::Result<PublicKey> {
+    if b.len() != 64 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unexpected EC public key length {} (expected 64)", b.len()),
+        ));
+    }
+    let mut sec1 = Vec::with_capacity(65);
+    sec1.push(0x04);
+    sec1.extend_from_slice(b);
+    PublicKey::from_sec1_bytes(&sec1)
+}
+
+#[async_trait]
+impl key::secp256k1::SignOnly for YubiKey {
+    type Error = io::Error;
+
+    fn signing_key(&self) -> io::Result<k256::ecdsa::SigningKey> {
+        Err(Error::new(ErrorKind::Other, "not implemented"))
+    }
+
+    fn public_key(&self) -> io::Result<PublicKey> {
+        Ok(self.to_public_key())
+    }
+
+    async fn sign_digest(&self, msg: &[u8]) -> Result<[u8; 65], io::Error> {
+        let sig = self.sign_digest(msg).await?;
+        Ok(sig.to_bytes())
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/book/ch10-02-traits.html>
+impl key::secp256k1::ReadOnly for YubiKey {
+    fn key_type(&self) -> key::secp256k1::KeyType {
+        key::secp256k1::KeyType::YubiHsm
+    }
+
+    fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
+        self.to_public_key()
+            .to_hrp_address(network_id, chain_id_alias)
+    }
+
+    fn short_address(&self) -> io::Result<short::Id> {
+        self.to_public_key().to_short_id()
+    }
+
+    fn short_address_bytes(&self) -> io::Result<Vec<u8>> {
+        self.to_public_key().to_short_bytes()
+    }
+
+    fn eth_address(&self) -> String {
+        self.to_public_key().to_eth_address()
+    }
+
+    fn h160_address(&self) -> primitive_types::H160 {
+        self.to_public_key().to_h160()
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="yubihsm" -- key::secp256k1::yubihsm::test_public_key_from_raw --exact --show-output
+#[test]
+fn test_public_key_from_raw() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = key::secp256k1::private_key::Key::generate()
+        .unwrap()
+        .to_public_key();
+    let uncompressed = pk.to_uncompressed_bytes();
+    assert_eq!(public_key_from_raw(&uncompressed[1..]).unwrap(), pk);
+
+    assert!(public_key_from_raw(&uncompressed).is_err());
+    assert!(public_key_from_raw(&[0u8; 64]).is_err());
+}
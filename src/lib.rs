@@ -1,24 +1,45 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod key;
+
+#[cfg(feature = "std")]
 pub mod avm;
+#[cfg(feature = "std")]
 pub mod choices;
+#[cfg(feature = "std")]
 pub mod codec;
+#[cfg(feature = "std")]
 pub mod constants;
+#[cfg(feature = "std")]
 pub mod errors;
+#[cfg(feature = "std")]
 pub mod formatting;
+#[cfg(feature = "std")]
 pub mod hash;
+#[cfg(feature = "std")]
 pub mod ids;
+#[cfg(feature = "std")]
 pub mod jsonrpc;
-pub mod key;
+#[cfg(feature = "std")]
 pub mod node;
+#[cfg(feature = "std")]
 pub mod packer;
+#[cfg(feature = "std")]
 pub mod platformvm;
+#[cfg(feature = "std")]
 pub mod txs;
+#[cfg(feature = "std")]
 pub mod units;
+#[cfg(feature = "std")]
 pub mod verify;
 
 #[cfg(feature = "avalanchego")]
 pub mod avalanchego;
 
-#[cfg(feature = "avalanchego")]
+#[cfg(any(feature = "avalanchego", feature = "wallet"))]
 pub mod coreth;
 
 #[cfg(feature = "subnet_evm")]
@@ -1,8 +1,91 @@
 pub mod txs;
 
-use crate::ids;
+use std::{
+    io::{self, Error, ErrorKind},
+    time::Duration,
+};
+
+use crate::{
+    ids,
+    key::network::{self, NetworkId},
+};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#pkg-variables>
 pub fn chain_id() -> ids::Id {
     ids::Id::empty()
 }
+
+/// Returns the minimum and maximum staking durations of the primary network
+/// validators and delegators. Networks other than mainnet and fuji use the
+/// local network parameters.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.11/genesis/genesis_mainnet.go> "MinStakeDuration"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.11/genesis/genesis_fuji.go> "MinStakeDuration"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.11/genesis/genesis_local.go> "MinStakeDuration"
+pub fn staking_duration_bounds(network_id: impl Into<NetworkId>) -> (Duration, Duration) {
+    const DAY: u64 = 24 * 60 * 60;
+    let max = Duration::from_secs(365 * DAY);
+    match network_id.into().as_u32() {
+        network::MAINNET_ID => (Duration::from_secs(14 * DAY), max),
+        _ => (Duration::from_secs(DAY), max),
+    }
+}
+
+/// Validates the staking period (in unix seconds) against the network's
+/// minimum and maximum staking durations.
+pub fn validate_staking_duration(
+    network_id: impl Into<NetworkId>,
+    start: u64,
+    end: u64,
+) -> io::Result<()> {
+    let network_id = network_id.into();
+    if end <= start {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "staking end time {} must be after start time {}",
+                end, start
+            ),
+        ));
+    }
+
+    let (min, max) = staking_duration_bounds(network_id);
+    let duration = Duration::from_secs(end - start);
+    if duration < min || duration > max {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "staking duration {:?} is out of range [{:?}, {:?}] for network {}",
+                duration, min, max, network_id
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::test_validate_staking_duration --exact --show-output
+#[test]
+fn test_validate_staking_duration() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let day = 24 * 60 * 60;
+    let start = 1_680_000_000;
+
+    // mainnet requires at least 2 weeks
+    assert!(validate_staking_duration(1u32, start, start + 14 * day).is_ok());
+    assert!(validate_staking_duration(1u32, start, start + 13 * day).is_err());
+
+    // fuji and local networks require at least a day
+    assert!(validate_staking_duration(5u32, start, start + day).is_ok());
+    assert!(validate_staking_duration(12345u32, start, start + day - 1).is_err());
+
+    assert!(validate_staking_duration(1u32, start, start + 365 * day).is_ok());
+    assert!(validate_staking_duration(1u32, start, start + 366 * day).is_err());
+
+    // typed network Ids
+    assert!(validate_staking_duration(NetworkId::Mainnet, start, start + 13 * day).is_err());
+    assert!(validate_staking_duration(NetworkId::Fuji, start, start + day).is_ok());
+    assert!(validate_staking_duration(5u32, start, start).is_err());
+}
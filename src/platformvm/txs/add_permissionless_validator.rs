@@ -0,0 +1,680 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{codec, hash, ids, key, platformvm, txs};
+use serde::{Deserialize, Serialize};
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AddPermissionlessValidatorTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub validator: platformvm::txs::Validator,
+    /// Empty for the primary network.
+    pub subnet_id: ids::Id,
+    /// BLS key of the primary network validator.
+    /// Must be None (packed as "signer.Empty") for subnet validators.
+    pub signer: Option<platformvm::txs::ProofOfPossession>,
+    pub stake_transferable_outputs: Option<Vec<txs::transferable::Output>>,
+    pub validator_rewards_owner: key::secp256k1::txs::OutputOwners,
+    pub delegator_rewards_owner: key::secp256k1::txs::OutputOwners,
+    /// Fee charged to the delegators in percent times 10,000
+    /// (e.g., 20,000 for 2%).
+    pub delegation_shares: u32,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            validator: platformvm::txs::Validator::default(),
+            subnet_id: ids::Id::empty(),
+            signer: None,
+            stake_transferable_outputs: None,
+            validator_rewards_owner: key::secp256k1::txs::OutputOwners::default(),
+            delegator_rewards_owner: key::secp256k1::txs::OutputOwners::default(),
+            delegation_shares: 0,
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.UnsignedAddPermissionlessValidatorTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly + Clone>(
+        &mut self,
+        signers: Vec<Vec<T>>,
+    ) -> io::Result<()> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = Self::type_id();
+        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let unsigned_tx_bytes = packer.take_bytes();
+        packer.set_bytes(&unsigned_tx_bytes);
+
+        // pack the second field "validator" in the struct
+        packer.pack_bytes(self.validator.node_id.as_ref())?;
+        packer.pack_u64(self.validator.start)?;
+        packer.pack_u64(self.validator.end)?;
+        packer.pack_u64(self.validator.weight)?;
+
+        // pack the third field "subnet" in the struct
+        packer.pack_bytes(self.subnet_id.as_ref())?;
+
+        // pack the fourth field "signer" in the struct
+        // "signer.Signer" is an interface thus encode the type ID
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/signer#Signer
+        if let Some(signer) = &self.signer {
+            signer.verify_lengths()?;
+            packer.pack_u32(platformvm::txs::ProofOfPossession::type_id())?;
+
+            // fixed-size arrays, thus no length prefix
+            packer.pack_bytes(&signer.public_key)?;
+            packer.pack_bytes(&signer.proof_of_possession)?;
+        } else {
+            let empty_signer_type_id = *(codec::P_TYPES.get("signer.Empty").unwrap()) as u32;
+            packer.pack_u32(empty_signer_type_id)?;
+        }
+
+        // pack the fifth field "stake" in the struct
+        if self.stake_transferable_outputs.is_some() {
+            let stake_transferable_outputs = self.stake_transferable_outputs.as_ref().unwrap();
+            packer.pack_u32(stake_transferable_outputs.len() as u32)?;
+
+            for transferable_output in stake_transferable_outputs.iter() {
+                // "TransferableOutput.Asset" is struct and serialize:"true"
+                // but embedded inline in the struct "TransferableOutput"
+                // so no need to encode type ID
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
+                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
+
+                // fx_id is serialize:"false" thus skipping serialization
+
+                // decide the type
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+                if transferable_output.transfer_output.is_none()
+                    && transferable_output.stakeable_lock_out.is_none()
+                {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out",
+                    ));
+                }
+                let type_id_transferable_out = {
+                    if transferable_output.transfer_output.is_some() {
+                        key::secp256k1::txs::transfer::Output::type_id()
+                    } else {
+                        platformvm::txs::StakeableLockOut::type_id()
+                    }
+                };
+                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
+                packer.pack_u32(type_id_transferable_out)?;
+
+                match type_id_transferable_out {
+                    7 => {
+                        // "key::secp256k1::txs::transfer::Output"
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
+
+                        // marshal "secp256k1fx.TransferOutput.Amt" field
+                        packer.pack_u64(transfer_output.amount)?;
+
+                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
+                        // but embedded inline in the struct "TransferOutput"
+                        // so no need to encode type ID
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
+                        packer.pack_u64(transfer_output.output_owners.locktime)?;
+                        packer.pack_u32(transfer_output.output_owners.threshold)?;
+                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
+                        for addr in transfer_output.output_owners.addresses.iter() {
+                            packer.pack_bytes(addr.as_ref())?;
+                        }
+                    }
+                    22 => {
+                        // "platformvm::txs::StakeableLockOut"
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+                        let stakeable_lock_out =
+                            transferable_output.stakeable_lock_out.clone().unwrap();
+
+                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
+                        packer.pack_u64(stakeable_lock_out.locktime)?;
+
+                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
+                        // but embedded inline in the struct "StakeableLockOut"
+                        // so no need to encode type ID
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
+                        //
+                        // marshal "secp256k1fx.TransferOutput.Amt" field
+                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
+                        packer
+                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
+                        packer
+                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
+                        packer.pack_u32(
+                            stakeable_lock_out
+                                .transfer_output
+                                .output_owners
+                                .addresses
+                                .len() as u32,
+                        )?;
+                        for addr in stakeable_lock_out
+                            .transfer_output
+                            .output_owners
+                            .addresses
+                            .iter()
+                        {
+                            packer.pack_bytes(addr.as_ref())?;
+                        }
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!(
+                                "unexpected type ID {} for TransferableOutput",
+                                type_id_transferable_out
+                            ),
+                        ));
+                    }
+                }
+            }
+        } else {
+            packer.pack_u32(0_u32)?;
+        }
+
+        // pack the sixth and seventh fields "validator_rewards_owner" and
+        // "delegator_rewards_owner" in the struct
+        // "fx.Owner" is an interface thus encode struct type id
+        let output_owners_type_id = key::secp256k1::txs::OutputOwners::type_id();
+        for rewards_owner in [&self.validator_rewards_owner, &self.delegator_rewards_owner] {
+            packer.pack_u32(output_owners_type_id)?;
+            packer.pack_u64(rewards_owner.locktime)?;
+            packer.pack_u32(rewards_owner.threshold)?;
+            packer.pack_u32(rewards_owner.addresses.len() as u32)?;
+            for addr in rewards_owner.addresses.iter() {
+                packer.pack_bytes(addr.as_ref())?;
+            }
+        }
+
+        // pack the eighth field "delegation_shares" in the struct
+        packer.pack_u32(self.delegation_shares)?;
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "platformvm.UnsignedAddPermissionlessValidatorTx" unsigned tx
+        // not other fields -- only hash "platformvm.UnsignedAddPermissionlessValidatorTx.*" but not "platformvm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AddPermissionlessValidatorTx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let creds_len = signers.len() as u32;
+        // pack the fourth field in the struct
+        packer.pack_u32(creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential
+        self.creds = Vec::new();
+        for keys in signers.iter() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await.map_err(|e| {
+                    Error::new(ErrorKind::Other, format!("failed sign_digest {}", e))
+                })?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            // add a new credential to "Tx"
+            self.creds.push(cred);
+        }
+        if creds_len > 0 {
+            // pack each "cred" which is "secp256k1fx.Credential"
+            // marshal type ID for "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in self.creds.iter() {
+                // marshal type ID for "secp256k1fx.Credential"
+                packer.pack_u32(cred_type_id)?;
+
+                // marshal fields for "secp256k1fx.Credential"
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/platformvm.Tx.Sign"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_permissionless_validator::test_add_permissionless_validator_tx_serialization_with_one_signer --exact --show-output
+#[test]
+fn test_add_permissionless_validator_tx_serialization_with_one_signer() {
+    use crate::ids::{node, short};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1000000,
+            transferable_outputs: Some(vec![txs::transferable::Output {
+                asset_id: ids::Id::from_slice(&<Vec<u8>>::from([
+                    0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, //
+                    0xe6, 0x89, 0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, //
+                    0xe8, 0x5e, 0xa5, 0x74, 0xc7, 0xa1, 0x5a, 0x79, //
+                    0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, 0x80, 0x14, //
+                ])),
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: 0x2c6874d687fc000,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0x00,
+                        threshold: 0x01,
+                        addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                            0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                            0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+                        ]))],
+                    },
+                }),
+                ..txs::transferable::Output::default()
+            }]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&<Vec<u8>>::from([
+                        0x78, 0x3b, 0x22, 0xc6, 0xa8, 0xd6, 0x83, 0x4c, 0x89, 0x30, //
+                        0xae, 0xac, 0x3d, 0xb6, 0x02, 0x63, 0xc1, 0x2e, 0x98, 0x16, //
+                        0x0e, 0xf7, 0x22, 0x1b, 0x4d, 0x5e, 0x62, 0x2e, 0x87, 0x0f, //
+                        0x92, 0xd9,
+                    ])),
+                    output_index: 0,
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&<Vec<u8>>::from([
+                    0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, //
+                    0xe6, 0x89, 0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, //
+                    0xe8, 0x5e, 0xa5, 0x74, 0xc7, 0xa1, 0x5a, 0x79, //
+                    0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, 0x80, 0x14, //
+                ])),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 0x2c6891f11c9e000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        validator: platformvm::txs::Validator {
+            node_id: node::Id::from_slice(&<Vec<u8>>::from([
+                0x9c, 0xd7, 0xb3, 0xe4, 0x79, 0x04, 0xf6, 0x7c, 0xc4, 0x8e, //
+                0xb5, 0xb9, 0xaf, 0xdb, 0x03, 0xe6, 0xd1, 0x8a, 0xcf, 0x6c, //
+            ])),
+            start: 0x623d7267,
+            end: 0x63c91062,
+            weight: 0x1d1a94a2000,
+        },
+        // not verified by the codec, only by the P-chain executor
+        signer: Some(platformvm::txs::ProofOfPossession {
+            public_key: (0..48).collect(),
+            proof_of_possession: (48..144).collect(),
+        }),
+        stake_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id: ids::Id::from_slice(&<Vec<u8>>::from([
+                0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, //
+                0xe6, 0x89, 0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, //
+                0xe8, 0x5e, 0xa5, 0x74, 0xc7, 0xa1, 0x5a, 0x79, //
+                0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, 0x80, 0x14, //
+            ])),
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 0x1d1a94a2000,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0x00,
+                    threshold: 0x01,
+                    addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                        0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                        0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+                    ]))],
+                },
+            }),
+            ..txs::transferable::Output::default()
+        }]),
+        validator_rewards_owner: key::secp256k1::txs::OutputOwners {
+            locktime: 0x00,
+            threshold: 0x01,
+            addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+            ]))],
+        },
+        delegator_rewards_owner: key::secp256k1::txs::OutputOwners {
+            locktime: 0x00,
+            threshold: 0x01,
+            addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+            ]))],
+        },
+        delegation_shares: 0x4e20,
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key];
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![keys1];
+    ab!(tx.sign(signers)).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
+    assert_eq!(
+        tx.tx_id().to_string(),
+        "2NYLkZ2gSSFTSRx64pjVMP68aKAAQRC8eBRK49JcCWF4Au6yf7"
+    );
+
+    let expected_signed_bytes: &[u8] = &[
+        // codec version
+        0x00, 0x00, //
+        //
+        // platformvm.UnsignedAddPermissionlessValidatorTx type ID
+        0x00, 0x00, 0x00, 0x19, //
+        //
+        // network id
+        0x00, 0x0f, 0x42, 0x40, //
+        //
+        // blockchain id
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, //
+        //
+        // outs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "outs[0]" TransferableOutput.asset_id
+        0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, 0xe6, 0x89, //
+        0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, 0xe8, 0x5e, 0xa5, 0x74, //
+        0xc7, 0xa1, 0x5a, 0x79, 0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, //
+        0x80, 0x14, //
+        //
+        // "outs[0]" secp256k1fx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x07, //
+        //
+        // "outs[0]" amount
+        0x02, 0xc6, 0x87, 0x4d, 0x68, 0x7f, 0xc0, 0x00, //
+        //
+        // "outs[0]" locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // "outs[0]" threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "outs[0]" addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "outs[0]" addrs[0]
+        0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+        0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+        //
+        // ins.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "ins[0]" TransferableInput.utxo_id.tx_id
+        0x78, 0x3b, 0x22, 0xc6, 0xa8, 0xd6, 0x83, 0x4c, 0x89, 0x30, //
+        0xae, 0xac, 0x3d, 0xb6, 0x02, 0x63, 0xc1, 0x2e, 0x98, 0x16, //
+        0x0e, 0xf7, 0x22, 0x1b, 0x4d, 0x5e, 0x62, 0x2e, 0x87, 0x0f, //
+        0x92, 0xd9, //
+        //
+        // "ins[0]" TransferableInput.utxo_id.output_index
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // "ins[0]" TransferableInput.asset_id
+        0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, 0xe6, 0x89, //
+        0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, 0xe8, 0x5e, 0xa5, 0x74, //
+        0xc7, 0xa1, 0x5a, 0x79, 0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, //
+        0x80, 0x14, //
+        //
+        // "ins[0]" secp256k1fx.TransferInput type ID
+        0x00, 0x00, 0x00, 0x05, //
+        //
+        // "ins[0]" amount
+        0x02, 0xc6, 0x89, 0x1f, 0x11, 0xc9, 0xe0, 0x00, //
+        //
+        // "ins[0]" sig_indices.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "ins[0]" sig_indices[0]
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // memo.len()
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // Validator.validator.node_id
+        0x9c, 0xd7, 0xb3, 0xe4, 0x79, 0x04, 0xf6, 0x7c, 0xc4, 0x8e, //
+        0xb5, 0xb9, 0xaf, 0xdb, 0x03, 0xe6, 0xd1, 0x8a, 0xcf, 0x6c, //
+        //
+        // Validator.validator.start
+        0x00, 0x00, 0x00, 0x00, 0x62, 0x3d, 0x72, 0x67, //
+        //
+        // Validator.validator.end
+        0x00, 0x00, 0x00, 0x00, 0x63, 0xc9, 0x10, 0x62, //
+        //
+        // Validator.validator.weight
+        0x00, 0x00, 0x01, 0xd1, 0xa9, 0x4a, 0x20, 0x00, //
+        //
+        // subnet id (primary network)
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, //
+        //
+        // signer.ProofOfPossession type ID
+        0x00, 0x00, 0x00, 0x1c, //
+        //
+        // BLS public key
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, //
+        0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, //
+        0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, //
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, //
+        0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, //
+        //
+        // BLS proof of possession
+        0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, //
+        0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f, 0x40, 0x41, 0x42, 0x43, //
+        0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, //
+        0x4e, 0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, //
+        0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f, 0x60, 0x61, //
+        0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, //
+        0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, //
+        0x76, 0x77, 0x78, 0x79, 0x7a, 0x7b, 0x7c, 0x7d, 0x7e, 0x7f, //
+        0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, //
+        0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f, //
+        //
+        // stake_outputs.len
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // stake_outputs[0].asset_id
+        0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, 0xe6, 0x89, //
+        0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, 0xe8, 0x5e, 0xa5, 0x74, //
+        0xc7, 0xa1, 0x5a, 0x79, 0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, //
+        0x80, 0x14, //
+        //
+        // secp256k1fx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x07, //
+        //
+        // stake_outputs[0].amount
+        0x00, 0x00, 0x01, 0xd1, 0xa9, 0x4a, 0x20, 0x00, //
+        //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs[0]
+        0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+        0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+        //
+        // validator rewards owner secp256k1fx.OutputOwners type id
+        0x00, 0x00, 0x00, 0x0b, //
+        //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs[0]
+        0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+        0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+        //
+        // delegator rewards owner secp256k1fx.OutputOwners type id
+        0x00, 0x00, 0x00, 0x0b, //
+        //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // addrs[0]
+        0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+        0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+        //
+        // delegation shares
+        0x00, 0x00, 0x4e, 0x20, //
+        //
+        // number of credentials
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "secp256k1fx.Credential" type ID
+        0x00, 0x00, 0x00, 0x09, //
+        //
+        // number of signers ("fx::Credential.cred.sigs.len()")
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // first 65-byte signature
+        0xc6, 0x30, 0x8d, 0xa5, 0x48, 0xf3, 0xa6, 0xda, 0x7b, 0xb9, //
+        0x2f, 0x4e, 0x38, 0x9a, 0x4e, 0x48, 0xd5, 0x95, 0xe8, 0x31, //
+        0xb5, 0xfc, 0xf0, 0x6b, 0x6f, 0xa0, 0x08, 0xb1, 0xfe, 0xa3, //
+        0x4d, 0x86, 0x41, 0xd3, 0x99, 0x2e, 0xf4, 0x76, 0x35, 0xbd, //
+        0x58, 0x33, 0xfc, 0x28, 0x7d, 0xe9, 0x3d, 0x58, 0xcb, 0xd8, //
+        0x68, 0x78, 0xa9, 0x3e, 0x4b, 0x95, 0x48, 0x91, 0xf0, 0xf5, //
+        0x7e, 0xc4, 0x51, 0x61, 0x00,
+    ];
+    // for c in &signed_bytes {
+    //     print!("{:#02x},", *c);
+    // }
+    assert!(cmp_manager::eq_vectors(
+        expected_signed_bytes,
+        &tx_bytes_with_signatures
+    ));
+
+    // subnet validators pack "signer.Empty" instead
+    let mut empty_signer_tx = Tx {
+        signer: None,
+        ..tx.clone()
+    };
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = Vec::new();
+    ab!(empty_signer_tx.sign(signers.clone())).expect("failed to sign");
+    let empty_signer_bytes = empty_signer_tx
+        .base_tx
+        .metadata
+        .unwrap()
+        .tx_bytes_with_no_signature;
+
+    // "signer" is followed by the stake outputs (84 bytes),
+    // the two rewards owners (40 bytes each), and the delegation shares
+    let unsigned_len = expected_signed_bytes.len() - (4 + 4 + 4 + 65);
+    let signer_end = unsigned_len - (84 + 2 * 40 + 4);
+    let signer_start = signer_end - (4 + 48 + 96);
+    let mut expected = expected_signed_bytes[..signer_start].to_vec();
+    expected.extend_from_slice(&[0x00, 0x00, 0x00, 0x1b]);
+    expected.extend_from_slice(&expected_signed_bytes[signer_end..unsigned_len]);
+    assert!(cmp_manager::eq_vectors(&expected, &empty_signer_bytes));
+
+    let mut invalid_signer_tx = Tx {
+        signer: Some(platformvm::txs::ProofOfPossession {
+            public_key: vec![0; 47],
+            ..platformvm::txs::ProofOfPossession::default()
+        }),
+        ..tx
+    };
+    assert!(ab!(invalid_signer_tx.sign(signers)).is_err());
+}
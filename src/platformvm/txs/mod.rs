@@ -1,3 +1,5 @@
+pub mod add_delegator;
+pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
 pub mod create_chain;
@@ -6,7 +8,10 @@ pub mod export;
 pub mod import;
 pub mod status;
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    io::{self, Error, ErrorKind},
+};
 
 use crate::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
@@ -540,3 +545,77 @@ impl Validator {
         }
     }
 }
+
+/// BLS public key of a primary network validator and its proof of possession.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/signer#ProofOfPossession>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ProofOfPossession {
+    /// Compressed BLS public key.
+    pub public_key: Vec<u8>,
+    /// Compressed BLS signature over the compressed public key.
+    pub proof_of_possession: Vec<u8>,
+}
+
+impl Default for ProofOfPossession {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl ProofOfPossession {
+    /// ref. "avalanchego/utils/crypto/bls.PublicKeyLen"
+    pub const PUBLIC_KEY_LEN: usize = 48;
+    /// ref. "avalanchego/utils/crypto/bls.SignatureLen"
+    pub const SIGNATURE_LEN: usize = 96;
+
+    pub fn default() -> Self {
+        Self {
+            public_key: vec![0; Self::PUBLIC_KEY_LEN],
+            proof_of_possession: vec![0; Self::SIGNATURE_LEN],
+        }
+    }
+
+    /// Signs the proof of possession with the node's BLS signer key.
+    /// ref. "avalanchego/vms/platformvm/signer.NewProofOfPossession"
+    #[cfg(feature = "bls")]
+    pub fn new(signer_key: &key::bls::private_key::Key) -> Self {
+        let (public_key, sig) = signer_key.proof_of_possession();
+        Self {
+            public_key: public_key.to_compressed_bytes().to_vec(),
+            proof_of_possession: sig.to_compressed_bytes().to_vec(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "signer.ProofOfPossession".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Fails if the key or signature length does not match the compressed BLS form.
+    pub fn verify_lengths(&self) -> io::Result<()> {
+        if self.public_key.len() != Self::PUBLIC_KEY_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid BLS public key length {} (expected {})",
+                    self.public_key.len(),
+                    Self::PUBLIC_KEY_LEN
+                ),
+            ));
+        }
+        if self.proof_of_possession.len() != Self::SIGNATURE_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "invalid BLS proof of possession length {} (expected {})",
+                    self.proof_of_possession.len(),
+                    Self::SIGNATURE_LEN
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
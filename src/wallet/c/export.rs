@@ -0,0 +1,242 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    choices::status::Status,
+    coreth::atomic,
+    formatting, ids,
+    jsonrpc::client::{c as client_c, evm as client_evm},
+    key, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents C-chain "Export" transaction.
+/// ref. <https://github.com/ava-labs/coreth/blob/v0.11.5/plugin/evm/export_tx.go> "newExportTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::c::C<T>,
+
+    /// Export destination blockchain id.
+    pub destination_blockchain_id: ids::Id,
+
+    /// Amount to export in nAVAX, excluding the C-chain fee.
+    pub amount: u64,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(c: &crate::wallet::c::C<T>) -> Self {
+        Self {
+            inner: c.clone(),
+            destination_blockchain_id: ids::Id::empty(),
+            amount: 0,
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the destination blockchain Id.
+    #[must_use]
+    pub fn destination_blockchain_id(mut self, blockchain_id: ids::Id) -> Self {
+        self.destination_blockchain_id = blockchain_id;
+        self
+    }
+
+    /// Sets the export amount.
+    #[must_use]
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the export transaction that debits the wallet's EVM account
+    /// by the amount and the dynamic fee at the current base fee,
+    /// and returns the transaction Id.
+    /// ref. <https://github.com/ava-labs/coreth/blob/v0.11.5/plugin/evm/export_tx.go> "newExportTx"
+    pub async fn issue(&self) -> io::Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        let h160_address = self.inner.inner.h160_address;
+        log::info!(
+            "exporting {} AVAX from {} to {} via {}",
+            self.amount,
+            h160_address,
+            self.destination_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        // the input nonce must match the account nonce at acceptance
+        let nonce = client_evm::get_transaction_count(
+            &format!("{}/ext/bc/C/rpc", picked_http_rpc.1),
+            h160_address,
+            false,
+        )
+        .await?;
+
+        let mut tx = atomic::export::Tx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_c,
+            destination_chain_id: self.destination_blockchain_id,
+            inputs: vec![atomic::EvmInput {
+                address: h160_address,
+                amount: self.amount,
+                asset_id: self.inner.inner.avax_asset_id,
+                nonce: nonce.as_u64(),
+            }],
+            exported_outputs: vec![txs::transferable::Output {
+                asset_id: self.inner.inner.avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: self.amount,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![self.inner.inner.short_address.clone()],
+                    },
+                }),
+                ..Default::default()
+            }],
+            ..atomic::export::Tx::default()
+        };
+        let signers = vec![vec![self.inner.inner.keychain.keys[0].clone()]];
+
+        // the fee depends on the signed size, which does not change
+        // with the input amount, so sign once to measure the gas
+        tx.sign(signers.clone()).await?;
+        let base_fee = self.inner.base_fee().await?;
+        let tx_fee = atomic::calculate_dynamic_fee(tx.gas_used(), base_fee)?;
+        log::info!(
+            "exporting {} AVAX with tx fee {} (gas {}, base fee {})",
+            self.amount,
+            tx_fee,
+            tx.gas_used(),
+            base_fee
+        );
+        tx.inputs[0].amount = self.amount.checked_add(tx_fee).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "amount {} overflows with the tx fee {}",
+                    self.amount, tx_fee
+                ),
+            )
+        })?;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.tx_id());
+        }
+
+        let tx_bytes_with_signatures = tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = client_c::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("failed to issue export transaction {:?}", e),
+            ));
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm export transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp =
+                client_c::get_atomic_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "failed to check acceptance in time",
+            ));
+        }
+
+        Ok(tx_id)
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    io::{self, Error, ErrorKind},
+    time::SystemTime,
+};
+
+use crate::{
+    choices::status::Status, coreth::atomic, formatting, ids, jsonrpc::client::c as client_c, key,
+    txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Returns the gas of the signed import transaction that consumes
+/// "num_inputs" single-signature UTXOs into one EVM output.
+/// Each input packs 88 bytes and its credential 73 bytes,
+/// on top of the 142-byte unsigned skeleton and the 4-byte credential count.
+pub fn gas_used(num_inputs: usize) -> u64 {
+    atomic::gas_used(146 + 161 * num_inputs, num_inputs)
+}
+
+/// Represents C-chain "Import" transaction.
+/// ref. <https://github.com/ava-labs/coreth/blob/v0.11.5/plugin/evm/import_tx.go> "newImportTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::c::C<T>,
+
+    /// Import source blockchain id.
+    pub source_blockchain_id: ids::Id,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(c: &crate::wallet::c::C<T>) -> Self {
+        Self {
+            inner: c.clone(),
+            source_blockchain_id: ids::Id::empty(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the source blockchain Id.
+    #[must_use]
+    pub fn source_blockchain_id(mut self, blockchain_id: ids::Id) -> Self {
+        self.source_blockchain_id = blockchain_id;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the import transaction that consumes all the atomic UTXOs
+    /// exported from the source blockchain to the wallet's "C-" address,
+    /// and credits the wallet's EVM address. Returns the transaction Id.
+    /// The dynamic fee at the current base fee is deducted from the imported amount.
+    /// ref. <https://github.com/ava-labs/coreth/blob/v0.11.5/plugin/evm/import_tx.go> "newImportTx"
+    pub async fn issue(&self) -> io::Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "importing from {} to C-chain via {}",
+            self.source_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let utxos = client_c::get_atomic_utxos(
+            &picked_http_rpc.1,
+            &[self.inner.c_address()?],
+            &self.source_blockchain_id.to_string(),
+        )
+        .await?;
+        let utxos_result = utxos.result.unwrap();
+        let utxos = utxos_result.utxos.unwrap();
+        log::debug!(
+            "fetched atomic UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
+            utxos_result.num_fetched,
+            utxos_result.end_index,
+            utxos.len()
+        );
+
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let mut import_amount = 0u64;
+        let mut inputs_with_signers: Vec<(txs::transferable::Input, Vec<T>)> = Vec::new();
+        for utxo in utxos.iter() {
+            if utxo.asset_id != self.inner.inner.avax_asset_id {
+                continue;
+            }
+
+            if let Some(out) = &utxo.transfer_output {
+                let res = self.inner.inner.keychain.spend(out, now_unix);
+                if res.is_none() {
+                    // cannot spend the output, move onto next
+                    continue;
+                }
+                let (transfer_input, in_signers) = res.unwrap();
+
+                import_amount = import_amount
+                    .checked_add(transfer_input.amount)
+                    .ok_or_else(|| {
+                        Error::new(ErrorKind::InvalidData, "import amount overflows u64")
+                    })?;
+
+                inputs_with_signers.push((
+                    txs::transferable::Input {
+                        utxo_id: utxo.utxo_id.clone(),
+                        asset_id: utxo.asset_id,
+                        transfer_input: Some(transfer_input),
+                        ..txs::transferable::Input::default()
+                    },
+                    in_signers,
+                ));
+            }
+        }
+
+        if inputs_with_signers.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "no spendable funds were found",
+            ));
+        }
+
+        // coreth rejects unsorted inputs, and each credential
+        // must stay at the same index as its input
+        inputs_with_signers.sort_by(|a, b| a.0.cmp(&b.0));
+        let (import_inputs, signers): (Vec<_>, Vec<_>) = inputs_with_signers.into_iter().unzip();
+
+        let mut tx = atomic::import::Tx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_c,
+            source_chain_id: self.source_blockchain_id,
+            imported_inputs: import_inputs,
+            outputs: vec![atomic::EvmOutput {
+                address: self.inner.inner.h160_address,
+                amount: import_amount,
+                asset_id: self.inner.inner.avax_asset_id,
+            }],
+            ..atomic::import::Tx::default()
+        };
+
+        // the fee depends on the signed size, which does not change
+        // with the output amount, so sign once to measure the gas
+        tx.sign(signers.clone()).await?;
+        let base_fee = self.inner.base_fee().await?;
+        let tx_fee = atomic::calculate_dynamic_fee(tx.gas_used(), base_fee)?;
+        log::info!(
+            "importing total {} AVAX with tx fee {} (gas {}, base fee {})",
+            import_amount,
+            tx_fee,
+            tx.gas_used(),
+            base_fee
+        );
+        if import_amount <= tx_fee {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "import amount {} does not cover the tx fee {}",
+                    import_amount, tx_fee
+                ),
+            ));
+        }
+        tx.outputs[0].amount = import_amount - tx_fee;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.tx_id());
+        }
+
+        let tx_bytes_with_signatures = tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = client_c::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("failed to issue import transaction {:?}", e),
+            ));
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm import transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp =
+                client_c::get_atomic_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "failed to check acceptance in time",
+            ));
+        }
+
+        Ok(tx_id)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="wallet_evm" -- wallet::c::import::test_gas_used --exact --show-output
+#[test]
+fn test_gas_used() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .unwrap();
+    let asset_id = ids::Id::from_slice(&[0x79; 32]);
+
+    // the estimate must match the signed transaction it is made for
+    for num_inputs in 1..=3 {
+        let mut tx = atomic::import::Tx {
+            imported_inputs: (0..num_inputs)
+                .map(|i| txs::transferable::Input {
+                    utxo_id: txs::utxo::Id {
+                        tx_id: ids::Id::from_slice(&[i as u8; 32]),
+                        ..txs::utxo::Id::default()
+                    },
+                    asset_id,
+                    transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                        amount: 100,
+                        sig_indices: vec![0],
+                    }),
+                    ..txs::transferable::Input::default()
+                })
+                .collect(),
+            outputs: vec![atomic::EvmOutput {
+                address: test_key.to_public_key().to_h160(),
+                amount: 100 * num_inputs as u64,
+                asset_id,
+            }],
+            ..atomic::import::Tx::default()
+        };
+        ab!(tx.sign(vec![vec![test_key.clone()]; num_inputs])).unwrap();
+        assert_eq!(gas_used(num_inputs), tx.gas_used());
+    }
+}
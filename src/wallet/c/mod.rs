@@ -0,0 +1,112 @@
+pub mod export;
+pub mod import;
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    coreth::atomic,
+    ids,
+    jsonrpc::client::evm as client_evm,
+    key::{self, secp256k1::ReadOnly},
+    wallet,
+};
+use primitive_types::U256;
+
+impl<T> wallet::Wallet<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    /// Returns the C-chain wallet for the atomic transactions
+    /// (e.g., import and export with X/P-chain).
+    /// Use "evm" for the EVM transactions.
+    #[must_use]
+    pub fn c(&self) -> C<T> {
+        C {
+            inner: self.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct C<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::Wallet<T>,
+}
+
+impl<T> C<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    /// Returns the "C-" bech32 address of the wallet owner,
+    /// which owns the atomic UTXOs exported to C-chain.
+    pub fn c_address(&self) -> io::Result<String> {
+        self.inner.keychain.keys[0].hrp_address(self.inner.network_id, "C")
+    }
+
+    /// Fetches the estimated base fee (in wei) of the next C-chain block.
+    pub async fn base_fee(&self) -> io::Result<U256> {
+        let http_rpc = self.inner.pick_base_http_url().1;
+        client_evm::base_fee(&format!("{http_rpc}/ext/bc/C/rpc")).await
+    }
+
+    /// Estimates the fee (in nAVAX) of the import transaction that consumes
+    /// "num_inputs" single-signature atomic UTXOs, at the current base fee.
+    pub async fn estimate_import_fee(&self, num_inputs: usize) -> io::Result<u64> {
+        let base_fee = self.base_fee().await?;
+        atomic::calculate_dynamic_fee(import::gas_used(num_inputs), base_fee)
+    }
+
+    #[must_use]
+    pub fn export(&self) -> export::Tx<T> {
+        export::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn import(&self) -> import::Tx<T> {
+        import::Tx::new(self)
+    }
+
+    /// Imports all the atomic UTXOs exported from P-chain,
+    /// and waits for its acceptance.
+    pub async fn import_from_p(&self) -> io::Result<ids::Id> {
+        self.import()
+            .source_blockchain_id(self.inner.blockchain_id_p)
+            .check_acceptance(true)
+            .issue()
+            .await
+    }
+
+    /// Moves "amount" nAVAX from C-chain to P-chain: issues the export
+    /// transaction, waits for its acceptance, and imports on P-chain.
+    /// Exports the P-chain import tx fee on top of the amount, so that
+    /// exactly "amount" is credited on P-chain.
+    /// Returns the export and import transaction Ids.
+    pub async fn export_to_p(&self, amount: u64) -> io::Result<(ids::Id, ids::Id)> {
+        let import_tx_fee = self
+            .inner
+            .p()
+            .estimate_fee(wallet::fee::TxKind::Base)
+            .await?;
+        let export_amount = amount.checked_add(import_tx_fee).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "amount {} overflows with the import tx fee {}",
+                    amount, import_tx_fee
+                ),
+            )
+        })?;
+        let export_tx_id = self
+            .export()
+            .destination_blockchain_id(self.inner.blockchain_id_p)
+            .amount(export_amount)
+            .check_acceptance(true)
+            .issue()
+            .await?;
+
+        let import_tx_id = self.inner.p().import_from_c().await?;
+        Ok((export_tx_id, import_tx_id))
+    }
+}
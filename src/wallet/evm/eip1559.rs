@@ -4,11 +4,15 @@ use std::{
 };
 
 use crate::{
+    evm::{add_gas_margin, revert as evm_revert, DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT},
+    jsonrpc::client::evm as jsonrpc_client_evm,
     key,
     wallet::{self, evm},
 };
 use ethers::{prelude::Eip1559TransactionRequest, utils::Units::Gwei};
+use ethers_core::types::transaction::{eip2718::TypedTransaction, eip2930::AccessList};
 use ethers_providers::Middleware;
+use ethers_signers::Signer;
 use lazy_static::lazy_static;
 use primitive_types::{H160, H256, U256};
 use tokio::time::Duration;
@@ -33,6 +37,9 @@ lazy_static! {
     };
 }
 
+/// Reward percentile for "suggest_1559_fees" when the fee multipliers are set.
+pub const SUGGEST_FEES_REWARD_PERCENTILE: f64 = 50.0;
+
 impl<'a, T, S> evm::Evm<'a, T, S>
 where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
@@ -73,6 +80,12 @@ where
     /// None for automatically fetching the next available nonce.
     pub signer_nonce: Option<U256>,
 
+    /// Set "true" to use the nonce tracked by the wallet's "nonce_manager",
+    /// which increments locally between "submit" calls, instead of fetching
+    /// the latest nonce for every transaction.
+    /// Ignored if "signer_nonce" is set.
+    pub managed_nonce: bool,
+
     /// Maximum transaction fee as a premium.
     /// Maps to subnet-evm DynamicFeeTx "GasTipCap".
     /// ref. <https://ethereum.org/en/developers/docs/gas/>
@@ -134,18 +147,40 @@ where
     /// Arbitrary data.
     pub data: Option<Vec<u8>>,
 
-    /// Set "true" to poll transfer status after issuance for its acceptance.
+    /// Set "true" to poll the receipt after issuance with "poll_interval",
+    /// and fail with "ErrorKind::TimedOut" after "poll_timeout".
+    /// If "false" (default), "submit" still waits for the receipt,
+    /// but via the provider's pending transaction without a timeout.
     pub check_acceptance: bool,
 
     /// Initial wait duration before polling for acceptance.
     pub poll_initial_wait: Duration,
     /// Wait between each poll intervals for acceptance.
     pub poll_interval: Duration,
-    /// Maximum duration for polling.
+    /// Maximum duration for polling, after which "submit" fails with
+    /// "ErrorKind::TimedOut" and the pending transaction hash.
     pub poll_timeout: Duration,
 
     /// Set to true to return transaction Id for "issue" in dry mode.
     pub dry_mode: bool,
+
+    /// Set "true" to simulate the transaction with "eth_call" before issuance,
+    /// and abort with the revert reason (if any) without spending gas.
+    pub simulate: bool,
+
+    /// Scales the suggested "max_fee_per_gas" (e.g., 1.25 for 25% more).
+    /// Ignored if "max_fee_per_gas" is set (e.g., via "urgent").
+    pub gas_multiplier: Option<f64>,
+    /// Scales the suggested "max_priority_fee_per_gas" (e.g., 2.0 to double the tip).
+    /// Ignored if "max_priority_fee_per_gas" is set (e.g., via "urgent").
+    pub priority_multiplier: Option<f64>,
+
+    /// Safety margin in percent added to the estimated gas limit in "estimate_gas".
+    pub gas_estimate_margin_percent: u64,
+
+    /// EIP-2930 access list of the accounts and storage keys to pre-declare.
+    /// See "wallet::evm::to_access_list" for when this reduces the cost.
+    pub access_list: Option<AccessList>,
 }
 
 impl<'a, T, S> Tx<'a, T, S>
@@ -159,6 +194,7 @@ where
             inner: ev.clone(),
 
             signer_nonce: None,
+            managed_nonce: false,
 
             max_priority_fee_per_gas: None,
             max_fee_per_gas: None,
@@ -170,11 +206,20 @@ where
 
             check_acceptance: false,
 
-            poll_initial_wait: Duration::from_millis(500),
-            poll_interval: Duration::from_millis(700),
-            poll_timeout: Duration::from_secs(300),
+            poll_initial_wait: evm::DEFAULT_POLL_INITIAL_WAIT,
+            poll_interval: evm::DEFAULT_POLL_INTERVAL,
+            poll_timeout: evm::DEFAULT_POLL_TIMEOUT,
 
             dry_mode: false,
+
+            simulate: false,
+
+            gas_multiplier: None,
+            priority_multiplier: None,
+
+            gas_estimate_margin_percent: DEFAULT_GAS_ESTIMATE_MARGIN_PERCENT,
+
+            access_list: None,
         }
     }
 
@@ -184,6 +229,14 @@ where
         self
     }
 
+    /// Sets the managed nonce boolean flag.
+    /// Useful for sending multiple transactions in a row from the same wallet.
+    #[must_use]
+    pub fn managed_nonce(mut self, managed_nonce: bool) -> Self {
+        self.managed_nonce = managed_nonce;
+        self
+    }
+
     /// Same as "GasTipCap" in subnet-evm.
     #[must_use]
     pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: impl Into<U256>) -> Self {
@@ -205,6 +258,9 @@ where
     }
 
     /// Overwrites all gas and fee parameters to mark this transaction as urgent.
+    /// The fixed fees take precedence over "gas_multiplier" and "priority_multiplier".
+    /// To bump the fees relative to the network conditions instead,
+    /// use the multipliers (e.g., 2.0 for both).
     #[must_use]
     pub fn urgent(mut self) -> Self {
         self.max_priority_fee_per_gas = Some(*URGENT_MAX_PRIORITY_FEE_PER_GAS);
@@ -212,6 +268,49 @@ where
         self
     }
 
+    /// Sets the multiplier for the suggested "max_fee_per_gas"
+    /// (from "jsonrpc::client::evm::suggest_1559_fees").
+    #[must_use]
+    pub fn gas_multiplier(mut self, gas_multiplier: f64) -> Self {
+        self.gas_multiplier = Some(gas_multiplier);
+        self
+    }
+
+    /// Sets the multiplier for the suggested "max_priority_fee_per_gas"
+    /// (from "jsonrpc::client::evm::suggest_1559_fees").
+    #[must_use]
+    pub fn priority_multiplier(mut self, priority_multiplier: f64) -> Self {
+        self.priority_multiplier = Some(priority_multiplier);
+        self
+    }
+
+    /// Returns the "(max_fee_per_gas, max_priority_fee_per_gas)" to use,
+    /// scaling the suggested fees for the ones not explicitly set if the
+    /// multipliers are set. None falls back to the middleware defaults.
+    async fn fees(&self) -> io::Result<(Option<U256>, Option<U256>)> {
+        let scale_max_fee = self.max_fee_per_gas.is_none() && self.gas_multiplier.is_some();
+        let scale_tip =
+            self.max_priority_fee_per_gas.is_none() && self.priority_multiplier.is_some();
+        if !scale_max_fee && !scale_tip {
+            return Ok((self.max_fee_per_gas, self.max_priority_fee_per_gas));
+        }
+
+        let suggested = jsonrpc_client_evm::suggest_1559_fees(
+            &self.inner.chain_rpc_url,
+            SUGGEST_FEES_REWARD_PERCENTILE,
+        )
+        .await?;
+        let (scaled_max_fee, scaled_tip) = apply_fee_multipliers(
+            suggested,
+            self.gas_multiplier.unwrap_or(1.0),
+            self.priority_multiplier.unwrap_or(1.0),
+        )?;
+
+        let max_fee_per_gas = self.max_fee_per_gas.unwrap_or(scaled_max_fee);
+        let max_priority_fee_per_gas = self.max_priority_fee_per_gas.unwrap_or(scaled_tip);
+        Ok((Some(max_fee_per_gas), Some(max_priority_fee_per_gas)))
+    }
+
     #[must_use]
     pub fn recipient(mut self, to: impl Into<H160>) -> Self {
         self.recipient = Some(to.into());
@@ -230,7 +329,17 @@ where
         self
     }
 
+    /// Sets the EIP-2930 access list of (address, storage keys) pairs.
+    /// See "wallet::evm::to_access_list" for when this reduces the cost.
+    #[must_use]
+    pub fn access_list(mut self, access_list: Vec<(H160, Vec<H256>)>) -> Self {
+        self.access_list = Some(evm::to_access_list(access_list));
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
+    /// Either way, "submit" returns after the transaction receipt is available;
+    /// "true" bounds the wait by "poll_timeout".
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
         self.check_acceptance = check_acceptance;
@@ -258,6 +367,12 @@ where
         self
     }
 
+    /// Sets the maximum duration to wait for acceptance (same as "poll_timeout").
+    #[must_use]
+    pub fn acceptance_timeout(self, acceptance_timeout: Duration) -> Self {
+        self.poll_timeout(acceptance_timeout)
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -265,43 +380,63 @@ where
         self
     }
 
-    /// Issues the transaction and returns the transaction Id.
-    /// ref. "coreth,subnet-evm/internal/ethapi.SubmitTransaction"
-    pub async fn submit(&self) -> io::Result<H256> {
-        let max_priority_fee_per_gas = if let Some(v) = self.max_priority_fee_per_gas {
-            format!("{} GWEI", super::wei_to_gwei(v))
-        } else {
-            "default".to_string()
-        };
-        let max_fee_per_gas = if let Some(v) = self.max_fee_per_gas {
-            format!("{} GWEI", super::wei_to_gwei(v))
-        } else {
-            "default".to_string()
-        };
+    /// Sets the simulate boolean flag.
+    #[must_use]
+    pub fn simulate(mut self, simulate: bool) -> Self {
+        self.simulate = simulate;
+        self
+    }
+
+    /// Sets the safety margin in percent for "estimate_gas".
+    #[must_use]
+    pub fn gas_estimate_margin_percent(mut self, gas_estimate_margin_percent: u64) -> Self {
+        self.gas_estimate_margin_percent = gas_estimate_margin_percent;
+        self
+    }
 
+    /// Estimates the gas via "eth_estimateGas" with the recipient, value, and data
+    /// set so far, and sets the gas limit to the estimate plus "gas_estimate_margin_percent".
+    /// If the execution reverts, fails with the decoded revert reason.
+    /// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_estimategas>
+    pub async fn estimate_gas(mut self) -> io::Result<Self> {
+        // nonce, fees, and gas limit do not affect the estimate
+        let mut tx_request = self.tx_request(U256::zero(), None, None);
+        tx_request.nonce = None;
+        tx_request.gas = None;
+
+        let estimated = self
+            .inner
+            .provider
+            .estimate_gas(&tx_request.into(), None)
+            .await
+            .map_err(|e| match evm_revert::from_provider_error(&e) {
+                Some(reason) => Error::new(
+                    ErrorKind::Other,
+                    format!("gas estimation reverted '{}'", reason),
+                ),
+                None => Error::new(ErrorKind::Other, format!("failed estimate_gas '{}'", e)),
+            })?;
+
+        let gas_limit = add_gas_margin(estimated, self.gas_estimate_margin_percent)?;
         log::info!(
-            "submitting transaction [chain Id {}, value {:?}, from {}, recipient {:?}, chain RPC URL {}, max_priority_fee_per_gas {max_priority_fee_per_gas}, max_fee_per_gas {max_fee_per_gas}, gas_limit {:?}]",
-            self.inner.chain_id,
-            self.value,
-            self.inner.inner.h160_address,
-            self.recipient,
-            self.inner.chain_rpc_url,
-            self.gas_limit,
+            "estimated gas {} (gas limit {} with {}% margin)",
+            estimated,
+            gas_limit,
+            self.gas_estimate_margin_percent
         );
+        self.gas_limit = Some(gas_limit);
+        Ok(self)
+    }
 
-        let signer_nonce = if let Some(signer_nonce) = self.signer_nonce {
-            signer_nonce
-        } else {
-            log::info!("nonce not specified -- fetching latest");
-            self.inner
-                .middleware
-                .initialize_nonce(None)
-                .await
-                .map_err(|e| {
-                    Error::new(ErrorKind::Other, format!("failed initialize_nonce '{}'", e))
-                })?
-        };
-        log::info!("latest signer nonce {}", signer_nonce);
+    /// Builds the transaction request with the nonce and fees, where None fees
+    /// are filled by the middleware on "submit".
+    fn tx_request(
+        &self,
+        signer_nonce: U256,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+    ) -> Eip1559TransactionRequest {
+        let signer_addr = self.inner.inner.h160_address;
 
         // "from" itself is not RLP-encoded field
         // "from" can be simply derived from signature and transaction hash
@@ -311,9 +446,7 @@ where
         // ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-core/src/types/transaction/eip2718.rs>
         // ref. <https://eips.ethereum.org/EIPS/eip-2718>
         let mut tx_request = Eip1559TransactionRequest::new()
-            .from(ethers::prelude::H160::from(
-                self.inner.inner.h160_address.as_fixed_bytes(),
-            ))
+            .from(ethers::prelude::H160::from(signer_addr.as_fixed_bytes()))
             .chain_id(ethers::prelude::U64::from(self.inner.chain_id.as_u64()))
             .nonce(ethers::prelude::U256::from(signer_nonce.as_u128()));
 
@@ -326,12 +459,12 @@ where
             tx_request = tx_request.value(converted);
         }
 
-        if let Some(max_priority_fee_per_gas) = &self.max_priority_fee_per_gas {
+        if let Some(max_priority_fee_per_gas) = &max_priority_fee_per_gas {
             let converted: ethers::prelude::U256 = max_priority_fee_per_gas.into();
             tx_request = tx_request.max_priority_fee_per_gas(converted);
         }
 
-        if let Some(max_fee_per_gas) = &self.max_fee_per_gas {
+        if let Some(max_fee_per_gas) = &max_fee_per_gas {
             let converted: ethers::prelude::U256 = max_fee_per_gas.into();
             tx_request = tx_request.max_fee_per_gas(converted);
         }
@@ -345,50 +478,312 @@ where
             tx_request = tx_request.data(data.clone());
         }
 
-        let pending_tx = self
-            .inner
-            .middleware
-            .send_transaction(tx_request, None)
-            .await
-            .map_err(|e| {
-                Error::new(
-                    ErrorKind::Other,
-                    format!("failed to send_transaction '{}'", e),
-                )
-            })?;
+        if let Some(access_list) = &self.access_list {
+            tx_request = tx_request.access_list(access_list.clone());
+        }
+
+        tx_request
+    }
 
-        let tx_receipt = pending_tx.await.map_err(|e| {
+    /// Signs the transaction without any RPC (e.g., for air-gapped signing),
+    /// and returns the RLP-encoded signed transaction for "eth_sendRawTransaction"
+    /// to broadcast elsewhere. Requires "signer_nonce", "max_fee_per_gas",
+    /// "max_priority_fee_per_gas", and "gas_limit", since those are otherwise
+    /// fetched from the node. The bytes are the same as what "submit" would send
+    /// with the same inputs.
+    /// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_sendrawtransaction>
+    pub async fn build_signed_offline(&self) -> io::Result<ethers::prelude::Bytes> {
+        let missing = |field: &str| {
             Error::new(
-                ErrorKind::Other,
-                format!("failed to wait for pending tx '{}'", e),
+                ErrorKind::InvalidInput,
+                format!("'{}' is required for offline signing", field),
             )
-        })?;
-        if tx_receipt.is_none() {
-            return Err(Error::new(ErrorKind::Other, "tx dropped from mempool"));
+        };
+        let signer_nonce = self.signer_nonce.ok_or_else(|| missing("signer_nonce"))?;
+        let max_fee_per_gas = self
+            .max_fee_per_gas
+            .ok_or_else(|| missing("max_fee_per_gas"))?;
+        let max_priority_fee_per_gas = self
+            .max_priority_fee_per_gas
+            .ok_or_else(|| missing("max_priority_fee_per_gas"))?;
+        if self.gas_limit.is_none() {
+            return Err(missing("gas_limit"));
         }
-        let tx_receipt = tx_receipt.unwrap();
-        let tx_hash = H256(tx_receipt.transaction_hash.0);
 
-        let tx = self
+        let tx: TypedTransaction = self
+            .tx_request(
+                signer_nonce,
+                Some(max_fee_per_gas),
+                Some(max_priority_fee_per_gas),
+            )
+            .into();
+
+        // same signer (with the chain Id) as the "submit" middleware
+        let sig = self
             .inner
             .middleware
-            .get_transaction(tx_receipt.transaction_hash)
+            .inner()
+            .signer()
+            .sign_transaction(&tx)
             .await
-            .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e)))?;
+            .map_err(|e| {
+                Error::new(ErrorKind::Other, format!("failed sign_transaction '{}'", e))
+            })?;
+        log::info!(
+            "signed transaction '0x{:x}' offline (nonce {})",
+            tx.hash(&sig),
+            signer_nonce
+        );
+        Ok(tx.rlp_signed(&sig))
+    }
 
-        // serde_json::to_string(&tx).unwrap()
-        if let Some(inner) = &tx {
-            assert_eq!(inner.hash(), tx_receipt.transaction_hash);
-            log::info!("successfully issued transaction '0x{:x}'", inner.hash());
+    /// Issues the transaction and returns the transaction Id.
+    /// ref. "coreth,subnet-evm/internal/ethapi.SubmitTransaction"
+    pub async fn submit(&self) -> io::Result<H256> {
+        let (max_fee_per_gas_value, max_priority_fee_per_gas_value) = self.fees().await?;
+
+        let max_priority_fee_per_gas = if let Some(v) = max_priority_fee_per_gas_value {
+            format!("{} GWEI", super::wei_to_gwei(v))
         } else {
-            log::warn!("transaction not found in get_transaction");
-        }
+            "default".to_string()
+        };
+        let max_fee_per_gas = if let Some(v) = max_fee_per_gas_value {
+            format!("{} GWEI", super::wei_to_gwei(v))
+        } else {
+            "default".to_string()
+        };
+
+        log::info!(
+            "submitting transaction [chain Id {}, value {:?}, from {}, recipient {:?}, chain RPC URL {}, max_priority_fee_per_gas {max_priority_fee_per_gas}, max_fee_per_gas {max_fee_per_gas}, gas_limit {:?}]",
+            self.inner.chain_id,
+            self.value,
+            self.inner.inner.h160_address,
+            self.recipient,
+            self.inner.chain_rpc_url,
+            self.gas_limit,
+        );
+
+        let signer_nonce = self
+            .inner
+            .next_nonce(self.signer_nonce, self.managed_nonce)
+            .await?;
+        let tx_request = self.tx_request(
+            signer_nonce,
+            max_fee_per_gas_value,
+            max_priority_fee_per_gas_value,
+        );
+
+        let acceptance = if self.check_acceptance {
+            Some(evm::AcceptancePolling {
+                initial_wait: self.poll_initial_wait,
+                interval: self.poll_interval,
+                timeout: self.poll_timeout,
+            })
+        } else {
+            None
+        };
+        self.inner
+            .issue_transaction(
+                tx_request.into(),
+                self.simulate,
+                self.managed_nonce && self.signer_nonce.is_none(),
+                acceptance,
+            )
+            .await
+    }
+}
+
+/// Scales the fee by the multiplier, with the precision of 1/1000.
+pub fn scale_fee(fee: U256, multiplier: f64) -> io::Result<U256> {
+    if !multiplier.is_finite() || multiplier <= 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid fee multiplier {}", multiplier),
+        ));
+    }
+    let per_mille = U256::from((multiplier * 1000.0).round() as u64);
+    let scaled = fee.checked_mul(per_mille).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("fee {} overflows with multiplier {}", fee, multiplier),
+        )
+    })?;
+    Ok(scaled / U256::from(1000))
+}
+
+/// Scales the suggested "(max_fee_per_gas, max_priority_fee_per_gas)".
+/// The max fee is raised to the priority fee if lower, since the node rejects
+/// the transaction whose tip exceeds the max fee.
+pub fn apply_fee_multipliers(
+    suggested: (U256, U256),
+    gas_multiplier: f64,
+    priority_multiplier: f64,
+) -> io::Result<(U256, U256)> {
+    let (max_fee, tip) = suggested;
+    let max_fee = scale_fee(max_fee, gas_multiplier)?;
+    let tip = scale_fee(tip, priority_multiplier)?;
+    Ok((std::cmp::max(max_fee, tip), tip))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::eip1559::test_apply_fee_multipliers --exact --show-output
+#[test]
+fn test_apply_fee_multipliers() {
+    use ethers_core::{types::transaction::eip2718::TypedTransaction, utils::rlp};
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
 
-        if !self.check_acceptance {
-            log::debug!("skipping checking acceptance...");
-            return Ok(tx_hash);
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let gwei = *evm::GWEI;
+    assert_eq!(
+        scale_fee(U256::from(40).mul(gwei), 1.25).unwrap(),
+        U256::from(50).mul(gwei)
+    );
+    assert_eq!(scale_fee(U256::from(3), 1.0).unwrap(), U256::from(3));
+    assert!(scale_fee(U256::from(3), 0.0).is_err());
+    assert!(scale_fee(U256::from(3), f64::NAN).is_err());
+    assert!(scale_fee(U256::MAX, 2.0).is_err());
+
+    // 2 * 24 + 2 GWEI suggested max fee, 2 GWEI suggested tip
+    let suggested = (U256::from(50).mul(gwei), U256::from(2).mul(gwei));
+    let (max_fee, tip) = apply_fee_multipliers(suggested, 1.25, 2.0).unwrap();
+    assert_eq!(max_fee, U256::from_dec_str("62500000000").unwrap());
+    assert_eq!(tip, U256::from(4).mul(gwei));
+
+    // max fee never falls below the tip
+    let (max_fee, tip) = apply_fee_multipliers(suggested, 0.05, 2.0).unwrap();
+    assert_eq!(max_fee, tip);
+
+    // the scaled fees end up in the signed transaction
+    let (max_fee, tip) = apply_fee_multipliers(suggested, 1.25, 2.0).unwrap();
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let signer: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
+    let signer = signer.with_chain_id(43114_u64);
+    let tx: TypedTransaction = Eip1559TransactionRequest::new()
+        .chain_id(43114_u64)
+        .nonce(0_u64)
+        .to(ethers::prelude::H160::random())
+        .gas(21000)
+        .max_fee_per_gas(ethers::prelude::U256::from(max_fee.as_u128()))
+        .max_priority_fee_per_gas(ethers::prelude::U256::from(tip.as_u128()))
+        .into();
+    let sig = ab!(signer.sign_transaction(&tx)).unwrap();
+    let signed = tx.rlp_signed(&sig);
+
+    let (decoded, decoded_sig) =
+        TypedTransaction::decode_signed(&rlp::Rlp::new(signed.as_ref())).unwrap();
+    assert_eq!(decoded_sig, sig);
+    match decoded {
+        TypedTransaction::Eip1559(inner) => {
+            assert_eq!(
+                inner.max_fee_per_gas.unwrap(),
+                ethers::prelude::U256::from(62_500_000_000_u64)
+            );
+            assert_eq!(
+                inner.max_priority_fee_per_gas.unwrap(),
+                ethers::prelude::U256::from(4_000_000_000_u64)
+            );
         }
+        _ => panic!("unexpected transaction type"),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::eip1559::test_build_signed_offline --exact --show-output
+#[test]
+fn test_build_signed_offline() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{ids, wallet::fee};
+    use ethers_core::utils::rlp;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
 
-        Ok(tx_hash)
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
     }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let key_info = k.to_info(1u32).unwrap();
+
+    // no RPC call is made
+    let w = wallet::Wallet {
+        key_type: key::secp256k1::KeyType::Hot,
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+        base_http_urls: vec!["http://localhost:9650".to_string()],
+        base_http_url_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: "mainnet".to_string(),
+        x_address: key_info.addresses.get(&1).unwrap().x.clone(),
+        p_address: key_info.addresses.get(&1).unwrap().p.clone(),
+        short_address: key_info.short_address,
+        eth_address: key_info.eth_address.clone(),
+        h160_address: key_info.h160_address,
+        blockchain_id_x: ids::Id::empty(),
+        blockchain_id_p: ids::Id::empty(),
+        blockchain_id_c: ids::Id::empty(),
+        avax_asset_id: ids::Id::empty(),
+        fee_cache: fee::Cache::new(),
+        evm_provider: None,
+        evm_nonce_managers: Arc::new(Mutex::new(std::collections::HashMap::new())),
+    };
+
+    let chain_id = 43114_u64;
+    let eth_signer: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
+    let ev = w
+        .evm(
+            &eth_signer,
+            "http://localhost:9650/ext/bc/C/rpc",
+            U256::from(chain_id),
+        )
+        .unwrap();
+
+    let to = H160::random();
+    let tx = ev
+        .eip1559()
+        .recipient(to)
+        .value(U256::from(1_000_000_u64))
+        .signer_nonce(U256::from(5))
+        .max_fee_per_gas(U256::from(50).mul(*evm::GWEI))
+        .max_priority_fee_per_gas(U256::from(2).mul(*evm::GWEI));
+
+    // missing gas limit
+    assert!(ab!(tx.build_signed_offline()).is_err());
+
+    let tx = tx.gas_limit(U256::from(21000));
+    let offline = ab!(tx.build_signed_offline()).unwrap();
+
+    // same transaction and signer as the "submit" middleware
+    let from = ethers::prelude::H160::from(key_info.h160_address.as_fixed_bytes());
+    let online_tx: TypedTransaction = Eip1559TransactionRequest::new()
+        .from(from)
+        .chain_id(chain_id)
+        .nonce(5_u64)
+        .to(ethers::prelude::H160::from(to.as_fixed_bytes()))
+        .value(1_000_000_u64)
+        .max_fee_per_gas(50_000_000_000_u64)
+        .max_priority_fee_per_gas(2_000_000_000_u64)
+        .gas(21000_u64)
+        .into();
+    let sig = ab!(ev.middleware.sign_transaction(&online_tx, from)).unwrap();
+    let online = online_tx.rlp_signed(&sig);
+    assert_eq!(offline, online);
+
+    let (decoded, decoded_sig) =
+        TypedTransaction::decode_signed(&rlp::Rlp::new(offline.as_ref())).unwrap();
+    assert_eq!(decoded_sig.recover(decoded.sighash()).unwrap(), from);
+    assert_eq!(decoded.nonce(), online_tx.nonce());
+    assert_eq!(decoded.gas(), online_tx.gas());
+    assert_eq!(decoded.chain_id(), Some(chain_id.into()));
 }
@@ -0,0 +1,239 @@
+//! ERC-20 token transfers and balance queries on the EVM wallet.
+//! ref. <https://eips.ethereum.org/EIPS/eip-20>
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    evm::{abi, revert as evm_revert},
+    jsonrpc::client::evm as jsonrpc_client_evm,
+    key,
+    wallet::evm::{self, eip1559},
+};
+use ethers::prelude::{Eip1559TransactionRequest, H160 as EthH160};
+use ethers_core::{
+    abi::{Contract, Function, Token},
+    types::transaction::eip2718::TypedTransaction,
+};
+use ethers_providers::Middleware;
+use lazy_static::lazy_static;
+use primitive_types::{H160, H256, U256};
+
+/// Canonical ERC-20 ABI with the standard functions and events.
+/// ref. <https://eips.ethereum.org/EIPS/eip-20>
+pub const ERC20_ABI: &str = r#"[
+    {"type":"function","name":"name","inputs":[],"outputs":[{"name":"","type":"string"}],"stateMutability":"view"},
+    {"type":"function","name":"symbol","inputs":[],"outputs":[{"name":"","type":"string"}],"stateMutability":"view"},
+    {"type":"function","name":"decimals","inputs":[],"outputs":[{"name":"","type":"uint8"}],"stateMutability":"view"},
+    {"type":"function","name":"totalSupply","inputs":[],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"},
+    {"type":"function","name":"balanceOf","inputs":[{"name":"_owner","type":"address"}],"outputs":[{"name":"balance","type":"uint256"}],"stateMutability":"view"},
+    {"type":"function","name":"transfer","inputs":[{"name":"_to","type":"address"},{"name":"_value","type":"uint256"}],"outputs":[{"name":"success","type":"bool"}],"stateMutability":"nonpayable"},
+    {"type":"function","name":"transferFrom","inputs":[{"name":"_from","type":"address"},{"name":"_to","type":"address"},{"name":"_value","type":"uint256"}],"outputs":[{"name":"success","type":"bool"}],"stateMutability":"nonpayable"},
+    {"type":"function","name":"approve","inputs":[{"name":"_spender","type":"address"},{"name":"_value","type":"uint256"}],"outputs":[{"name":"success","type":"bool"}],"stateMutability":"nonpayable"},
+    {"type":"function","name":"allowance","inputs":[{"name":"_owner","type":"address"},{"name":"_spender","type":"address"}],"outputs":[{"name":"remaining","type":"uint256"}],"stateMutability":"view"},
+    {"type":"event","name":"Transfer","inputs":[{"name":"_from","type":"address","indexed":true},{"name":"_to","type":"address","indexed":true},{"name":"_value","type":"uint256","indexed":false}],"anonymous":false},
+    {"type":"event","name":"Approval","inputs":[{"name":"_owner","type":"address","indexed":true},{"name":"_spender","type":"address","indexed":true},{"name":"_value","type":"uint256","indexed":false}],"anonymous":false}
+]"#;
+
+/// Selector of "transfer(address,uint256)".
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+/// Selector of "balanceOf(address)".
+pub const BALANCE_OF_SELECTOR: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+
+lazy_static! {
+    pub static ref ERC20: Contract = abi::load_contract(ERC20_ABI).unwrap();
+}
+
+fn erc20_function(sig: &str) -> io::Result<Function> {
+    abi::function_by_signature(&ERC20, sig).cloned()
+}
+
+/// Encodes the "transfer(address,uint256)" calldata.
+pub fn encode_transfer(to: H160, amount: U256) -> io::Result<Vec<u8>> {
+    abi::encode_calldata_checked(
+        erc20_function("transfer(address,uint256)")?,
+        &[Token::Address(to), Token::Uint(amount)],
+    )
+}
+
+/// Decodes the "transfer(address,uint256)" return data.
+/// Returns true on the empty output, since some tokens return no value
+/// (e.g., USDT on Ethereum mainnet).
+pub fn decode_transfer(data: &[u8]) -> io::Result<bool> {
+    if data.is_empty() {
+        return Ok(true);
+    }
+    let mut tokens = abi::decode_output(&erc20_function("transfer(address,uint256)")?, data)?;
+    match tokens.pop() {
+        Some(Token::Bool(success)) if tokens.is_empty() => Ok(success),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            "unexpected transfer output",
+        )),
+    }
+}
+
+/// Encodes the "balanceOf(address)" calldata.
+pub fn encode_balance_of(owner: H160) -> io::Result<Vec<u8>> {
+    abi::encode_calldata_checked(
+        erc20_function("balanceOf(address)")?,
+        &[Token::Address(owner)],
+    )
+}
+
+/// Decodes the "balanceOf(address)" return data.
+pub fn decode_balance_of(data: &[u8]) -> io::Result<U256> {
+    let mut tokens = abi::decode_output(&erc20_function("balanceOf(address)")?, data)?;
+    match tokens.pop() {
+        Some(Token::Uint(balance)) if tokens.is_empty() => Ok(balance),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            "unexpected balanceOf output",
+        )),
+    }
+}
+
+impl<'a, T, S> evm::Evm<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    #[must_use]
+    pub fn erc20(&self, token_addr: impl Into<H160>) -> Erc20<'a, T, S> {
+        Erc20 {
+            inner: self.clone(),
+            token_addr: token_addr.into(),
+        }
+    }
+}
+
+/// Represents an ERC-20 token contract for the wallet.
+#[derive(Clone, Debug)]
+pub struct Erc20<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    pub inner: evm::Evm<'a, T, S>,
+    pub token_addr: H160,
+}
+
+impl<'a, T, S> Erc20<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    /// Returns the EIP-1559 transaction of "transfer(to, amount)" to the token contract,
+    /// to customize the fees or polling before "submit".
+    pub fn transfer_tx(
+        &self,
+        to: impl Into<H160>,
+        amount: impl Into<U256>,
+    ) -> io::Result<eip1559::Tx<'a, T, S>> {
+        let calldata = encode_transfer(to.into(), amount.into())?;
+        Ok(self
+            .inner
+            .eip1559()
+            .recipient(self.token_addr)
+            .data(calldata))
+    }
+
+    /// Transfers the "amount" of tokens (in the smallest unit) to "to",
+    /// simulating first to fail with the revert reason (e.g., insufficient balance)
+    /// or if the token returns false, and waits for its acceptance.
+    /// Returns the transaction Id.
+    pub async fn transfer(&self, to: impl Into<H160>, amount: impl Into<U256>) -> io::Result<H256> {
+        let (to, amount) = (to.into(), amount.into());
+
+        let sim_tx: TypedTransaction = Eip1559TransactionRequest::new()
+            .from(EthH160::from(self.inner.inner.h160_address.0))
+            .to(EthH160::from(self.token_addr.0))
+            .data(encode_transfer(to, amount)?)
+            .into();
+        let output = self.inner.provider.call(&sim_tx, None).await.map_err(|e| {
+            match evm_revert::from_provider_error(&e) {
+                Some(reason) => Error::new(
+                    ErrorKind::Other,
+                    format!("simulated transfer reverted '{}'", reason),
+                ),
+                None => Error::new(
+                    ErrorKind::Other,
+                    format!("failed to simulate transfer '{}'", e),
+                ),
+            }
+        })?;
+        if !decode_transfer(&output)? {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "simulated transfer returned false",
+            ));
+        }
+
+        self.transfer_tx(to, amount)?
+            .check_acceptance(true)
+            .submit()
+            .await
+    }
+
+    /// Returns the token balance of the "owner" via "eth_call".
+    pub async fn balance_of(&self, owner: impl Into<H160>) -> io::Result<U256> {
+        let calldata = encode_balance_of(owner.into())?;
+        let output =
+            jsonrpc_client_evm::call(&self.inner.chain_rpc_url, self.token_addr, calldata, None)
+                .await?;
+        decode_balance_of(&output)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::erc20::test_erc20_calldata --exact --show-output
+#[test]
+fn test_erc20_calldata() {
+    use std::str::FromStr;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    assert_eq!(ERC20.functions().count(), 9);
+    assert_eq!(ERC20.events().count(), 2);
+    assert_eq!(
+        abi::selector(&erc20_function("transfer").unwrap()),
+        TRANSFER_SELECTOR
+    );
+    assert_eq!(
+        abi::selector(&erc20_function("balanceOf").unwrap()),
+        BALANCE_OF_SELECTOR
+    );
+
+    let to = H160::from_str("0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC").unwrap();
+    let calldata = encode_transfer(to, U256::from(1000)).unwrap();
+    assert_eq!(
+        hex::encode(&calldata),
+        "a9059cbb0000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc00000000000000000000000000000000000000000000000000000000000003e8"
+    );
+
+    let calldata = encode_balance_of(to).unwrap();
+    assert_eq!(
+        hex::encode(&calldata),
+        "70a082310000000000000000000000008db97c7cece249c2b98bdc0226cc4c2a57bf52fc"
+    );
+
+    let output =
+        hex::decode("00000000000000000000000000000000000000000000000000000000000003e8").unwrap();
+    assert_eq!(decode_balance_of(&output).unwrap(), U256::from(1000));
+
+    // e.g., no contract code at the token address
+    assert!(decode_balance_of(&[]).is_err());
+
+    let output =
+        hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+    assert!(decode_transfer(&output).unwrap());
+    let output =
+        hex::decode("0000000000000000000000000000000000000000000000000000000000000000").unwrap();
+    assert!(!decode_transfer(&output).unwrap());
+    // e.g., USDT returns no value
+    assert!(decode_transfer(&[]).unwrap());
+    assert!(decode_transfer(&[0x01]).is_err());
+}
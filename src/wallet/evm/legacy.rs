@@ -0,0 +1,374 @@
+//! Legacy (pre-EIP-1559) transactions with "gasPrice", replay-protected with EIP-155.
+//!
+//! Use "eip1559()" on chains with the London upgrade activated (e.g., C-chain
+//! since Apricot Phase 3, and subnet-evm by default), since legacy transactions
+//! pay the full "gasPrice" regardless of the base fee.
+//! Use "legacy()" only on chains that have not activated London
+//! (e.g., "londonBlock" unset in the genesis chain config), which reject
+//! the type-2 transactions.
+//! With "access_list", the transaction is issued as the EIP-2930 (type 1)
+//! transaction, which uses the same "gasPrice" but the y-parity "v".
+//! ref. <https://eips.ethereum.org/EIPS/eip-155>
+//! ref. <https://eips.ethereum.org/EIPS/eip-2930>
+//! ref. <https://ethereum.org/en/history/#london>
+use std::io;
+
+use crate::{
+    key,
+    wallet::{self, evm},
+};
+use ethers::prelude::TransactionRequest;
+use ethers_core::types::transaction::{eip2718::TypedTransaction, eip2930::AccessList};
+use primitive_types::{H160, H256, U256};
+use tokio::time::Duration;
+
+impl<'a, T, S> evm::Evm<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    #[must_use]
+    pub fn legacy(&self) -> Tx<'a, T, S> {
+        Tx::new(self)
+    }
+}
+
+/// Represents a legacy Ethereum transaction (legacy transaction in coreth/subnet-evm).
+/// ref. <https://ethereum.org/en/developers/docs/transactions>
+/// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-core/src/types/transaction/request.rs>
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_sendrawtransaction>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/subnet-evm/core/types#LegacyTx>
+#[derive(Clone, Debug)]
+pub struct Tx<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    pub inner: wallet::evm::Evm<'a, T, S>,
+
+    /// Sequence number originated from this account to prevent message replay attack.
+    /// None for automatically fetching the next available nonce.
+    pub signer_nonce: Option<U256>,
+
+    /// Set "true" to use the nonce tracked by the wallet's "nonce_manager".
+    /// Ignored if "signer_nonce" is set.
+    pub managed_nonce: bool,
+
+    /// Price per unit of gas, paid in full regardless of the base fee.
+    /// None for the node's suggested gas price ("eth_gasPrice").
+    /// Maps to subnet-evm LegacyTx "GasPrice".
+    pub gas_price: Option<U256>,
+
+    /// Maximum amount of gas that can be consumed by this transaction.
+    /// None to estimate.
+    pub gas_limit: Option<U256>,
+
+    /// If the recipient is None, the transaction is for contract creation.
+    pub recipient: Option<H160>,
+
+    /// Transfer amount value.
+    pub value: Option<U256>,
+
+    /// Arbitrary data.
+    pub data: Option<Vec<u8>>,
+
+    /// Set "true" to poll the receipt after issuance with "poll_interval",
+    /// and fail with "ErrorKind::TimedOut" after "poll_timeout".
+    /// If "false" (default), "submit" still waits for the receipt,
+    /// but via the provider's pending transaction without a timeout.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling, after which "submit" fails with
+    /// "ErrorKind::TimedOut" and the pending transaction hash.
+    pub poll_timeout: Duration,
+
+    /// Set "true" to simulate the transaction with "eth_call" before issuance,
+    /// and abort with the revert reason (if any) without spending gas.
+    pub simulate: bool,
+
+    /// EIP-2930 access list of the accounts and storage keys to pre-declare,
+    /// which makes this a type-1 transaction.
+    /// See "wallet::evm::to_access_list" for when this reduces the cost.
+    pub access_list: Option<AccessList>,
+}
+
+impl<'a, T, S> Tx<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    pub fn new(ev: &wallet::evm::Evm<'a, T, S>) -> Self {
+        Self {
+            inner: ev.clone(),
+
+            signer_nonce: None,
+            managed_nonce: false,
+
+            gas_price: None,
+            gas_limit: None,
+
+            recipient: None,
+            value: None,
+            data: None,
+
+            check_acceptance: false,
+
+            poll_initial_wait: evm::DEFAULT_POLL_INITIAL_WAIT,
+            poll_interval: evm::DEFAULT_POLL_INTERVAL,
+            poll_timeout: evm::DEFAULT_POLL_TIMEOUT,
+
+            simulate: false,
+
+            access_list: None,
+        }
+    }
+
+    #[must_use]
+    pub fn signer_nonce(mut self, signer_nonce: impl Into<U256>) -> Self {
+        self.signer_nonce = Some(signer_nonce.into());
+        self
+    }
+
+    /// Sets the managed nonce boolean flag.
+    #[must_use]
+    pub fn managed_nonce(mut self, managed_nonce: bool) -> Self {
+        self.managed_nonce = managed_nonce;
+        self
+    }
+
+    /// Same as "GasPrice" in subnet-evm.
+    #[must_use]
+    pub fn gas_price(mut self, gas_price: impl Into<U256>) -> Self {
+        self.gas_price = Some(gas_price.into());
+        self
+    }
+
+    #[must_use]
+    pub fn gas_limit(mut self, gas_limit: impl Into<U256>) -> Self {
+        self.gas_limit = Some(gas_limit.into());
+        self
+    }
+
+    #[must_use]
+    pub fn recipient(mut self, to: impl Into<H160>) -> Self {
+        self.recipient = Some(to.into());
+        self
+    }
+
+    #[must_use]
+    pub fn value(mut self, value: impl Into<U256>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    /// Either way, "submit" returns after the transaction receipt is available;
+    /// "true" bounds the wait by "poll_timeout".
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the maximum duration to wait for acceptance (same as "poll_timeout").
+    #[must_use]
+    pub fn acceptance_timeout(self, acceptance_timeout: Duration) -> Self {
+        self.poll_timeout(acceptance_timeout)
+    }
+
+    /// Sets the simulate boolean flag.
+    #[must_use]
+    pub fn simulate(mut self, simulate: bool) -> Self {
+        self.simulate = simulate;
+        self
+    }
+
+    /// Sets the EIP-2930 access list of (address, storage keys) pairs,
+    /// to issue the type-1 transaction instead of the legacy one.
+    /// See "wallet::evm::to_access_list" for when this reduces the cost.
+    #[must_use]
+    pub fn access_list(mut self, access_list: Vec<(H160, Vec<H256>)>) -> Self {
+        self.access_list = Some(evm::to_access_list(access_list));
+        self
+    }
+
+    /// Signs the transaction with the EIP-155 chain Id, issues it
+    /// via "eth_sendRawTransaction", and returns the transaction Id.
+    pub async fn submit(&self) -> io::Result<H256> {
+        let gas_price = if let Some(v) = self.gas_price {
+            format!("{} GWEI", super::wei_to_gwei(v))
+        } else {
+            "default".to_string()
+        };
+
+        log::info!(
+            "submitting legacy transaction [chain Id {}, value {:?}, from {}, recipient {:?}, chain RPC URL {}, gas_price {gas_price}, gas_limit {:?}]",
+            self.inner.chain_id,
+            self.value,
+            self.inner.inner.h160_address,
+            self.recipient,
+            self.inner.chain_rpc_url,
+            self.gas_limit,
+        );
+
+        let signer_addr = self.inner.inner.h160_address;
+        let signer_nonce = self
+            .inner
+            .next_nonce(self.signer_nonce, self.managed_nonce)
+            .await?;
+
+        // the chain Id is included in the signature (EIP-155)
+        let mut tx_request = TransactionRequest::new()
+            .from(ethers::prelude::H160::from(signer_addr.as_fixed_bytes()))
+            .chain_id(ethers::prelude::U64::from(self.inner.chain_id.as_u64()))
+            .nonce(ethers::prelude::U256::from(signer_nonce.as_u128()));
+
+        if let Some(to) = &self.recipient {
+            tx_request = tx_request.to(ethers::prelude::H160::from(to.as_fixed_bytes()));
+        }
+
+        if let Some(value) = &self.value {
+            let converted: ethers::prelude::U256 = value.into();
+            tx_request = tx_request.value(converted);
+        }
+
+        if let Some(gas_price) = &self.gas_price {
+            let converted: ethers::prelude::U256 = gas_price.into();
+            tx_request = tx_request.gas_price(converted);
+        }
+
+        if let Some(gas_limit) = &self.gas_limit {
+            let converted: ethers::prelude::U256 = gas_limit.into();
+            tx_request = tx_request.gas(converted);
+        }
+
+        if let Some(data) = &self.data {
+            tx_request = tx_request.data(data.clone());
+        }
+
+        let acceptance = if self.check_acceptance {
+            Some(evm::AcceptancePolling {
+                initial_wait: self.poll_initial_wait,
+                interval: self.poll_interval,
+                timeout: self.poll_timeout,
+            })
+        } else {
+            None
+        };
+        self.inner
+            .issue_transaction(
+                typed_tx(tx_request, self.access_list.clone()),
+                self.simulate,
+                self.managed_nonce && self.signer_nonce.is_none(),
+                acceptance,
+            )
+            .await
+    }
+}
+
+/// Returns the EIP-2930 transaction if the access list is set,
+/// or the legacy transaction otherwise.
+pub fn typed_tx(
+    tx_request: TransactionRequest,
+    access_list: Option<AccessList>,
+) -> TypedTransaction {
+    match access_list {
+        Some(access_list) => TypedTransaction::Eip2930(tx_request.with_access_list(access_list)),
+        None => tx_request.into(),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::legacy::test_typed_tx_access_list --exact --show-output
+#[test]
+fn test_typed_tx_access_list() {
+    use ethers_core::utils::rlp;
+    use ethers_signers::Signer;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let chain_id = 43114_u64;
+    let signer = key::secp256k1::eth_signer::LocalSigner::new(k, U256::from(chain_id)).unwrap();
+
+    let contract = H160::random();
+    let slot = H256::from_low_u64_be(1);
+    let access_list = evm::to_access_list(vec![(contract, vec![slot])]);
+    assert_eq!(access_list.0.len(), 1);
+    assert_eq!(access_list.0[0].address.as_bytes(), contract.as_bytes());
+    assert_eq!(access_list.0[0].storage_keys[0].as_bytes(), slot.as_bytes());
+
+    let tx_request = TransactionRequest::new()
+        .from(signer.address())
+        .to(ethers::prelude::H160::random())
+        .chain_id(chain_id)
+        .nonce(3_u64)
+        .gas(50000_u64)
+        .gas_price(25_000_000_000_u64);
+    assert!(matches!(
+        typed_tx(tx_request.clone(), None),
+        TypedTransaction::Legacy(_)
+    ));
+
+    let tx = typed_tx(tx_request, Some(access_list.clone()));
+    assert!(matches!(tx, TypedTransaction::Eip2930(_)));
+
+    let sig = ab!(signer.sign_transaction(&tx)).unwrap();
+    // y-parity without EIP-155
+    assert!(sig.v == 0 || sig.v == 1);
+
+    let signed = tx.rlp_signed(&sig);
+    // EIP-2718 envelope for the type-1 transaction
+    assert_eq!(signed[0], 0x01);
+
+    let (decoded, decoded_sig) =
+        TypedTransaction::decode_signed(&rlp::Rlp::new(signed.as_ref())).unwrap();
+    assert_eq!(decoded_sig, sig);
+    assert_eq!(decoded.access_list(), Some(&access_list));
+    assert_eq!(
+        decoded_sig.recover(decoded.sighash()).unwrap(),
+        signer.address()
+    );
+}
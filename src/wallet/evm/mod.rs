@@ -1,13 +1,17 @@
 pub mod eip1559;
+pub mod erc20;
+pub mod legacy;
+pub mod nonce_manager;
+pub mod replace;
 
 use std::{
     io::{self, Error, ErrorKind},
     ops::Div,
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-use crate::{jsonrpc::client::evm as jsonrpc_client_evm, key, wallet};
+use crate::{evm::revert as evm_revert, jsonrpc::client::evm as jsonrpc_client_evm, key, wallet};
 use ethers::{
     prelude::{
         gas_escalator::{Frequency, GasEscalatorMiddleware, GeometricGasPrice},
@@ -15,9 +19,11 @@ use ethers::{
     },
     utils::Units::Gwei,
 };
-use ethers_providers::{Http, Provider};
+use ethers_core::types::{transaction::eip2718::TypedTransaction, TransactionReceipt};
+use ethers_providers::{Http, Middleware, Provider};
 use lazy_static::lazy_static;
-use primitive_types::U256;
+use primitive_types::{H256, U256};
+use tokio::time::sleep;
 
 lazy_static! {
     pub static ref GWEI: U256 = U256::from(10).checked_pow(Gwei.as_num().into()).unwrap();
@@ -42,14 +48,20 @@ where
         S::Error: 'static,
     {
         // do not create multiple providers for the ease of nonce management
-        let provider = Provider::<Http>::try_from(chain_rpc_url)
-            .map_err(|e| {
+        let provider = match &self.evm_provider {
+            // cloned provider shares the same HTTP client (and its connection pool)
+            Some(p) if same_rpc_url(p.url().as_str(), chain_rpc_url) => {
+                log::info!("reusing the wallet provider for {}", chain_rpc_url);
+                p.clone()
+            }
+            _ => Provider::<Http>::try_from(chain_rpc_url).map_err(|e| {
                 Error::new(
                     ErrorKind::Other,
                     format!("failed to create provider '{}'", e),
                 )
-            })?
-            .interval(Duration::from_millis(2000u64));
+            })?,
+        }
+        .interval(Duration::from_millis(2000u64));
 
         // TODO: make this configurable
         let escalator = GeometricGasPrice::new(5.0, 10u64, None::<u64>);
@@ -62,6 +74,16 @@ where
         let nonce_middleware = NonceManagerMiddleware::new(signer_middleware, eth_signer.address());
         let middleware = Arc::new(nonce_middleware);
 
+        // shared with the other "evm" wallets on the same chain, so that
+        // "managed_nonce" transactions from each do not reuse the nonce
+        let nonce_manager = self
+            .evm_nonce_managers
+            .lock()
+            .unwrap()
+            .entry(chain_id)
+            .or_default()
+            .clone();
+
         Ok(Evm::<'a, T, S> {
             inner: self.clone(),
             eth_signer,
@@ -71,10 +93,17 @@ where
             middleware,
 
             chain_id,
+
+            nonce_manager,
         })
     }
 }
 
+/// Returns true if both URLs point to the same endpoint, ignoring the trailing slash.
+fn same_rpc_url(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
 #[derive(Clone, Debug)]
 pub struct Evm<'a, T, S>
 where
@@ -101,6 +130,11 @@ where
     >,
 
     pub chain_id: U256,
+
+    /// Tracks the signer nonce for "managed_nonce" transactions.
+    /// Shared with all "Wallet::evm" wallets of the same chain Id
+    /// (see "wallet::Wallet::evm_nonce_managers").
+    pub nonce_manager: nonce_manager::NonceManager,
 }
 
 impl<'a, T, S> Evm<'a, T, S>
@@ -112,9 +146,253 @@ where
     /// Fetches the current balance of the wallet owner.
     pub async fn balance(&self) -> io::Result<U256> {
         let cur_balance =
-            jsonrpc_client_evm::get_balance(&self.chain_rpc_url, self.inner.h160_address).await?;
+            jsonrpc_client_evm::get_balance(&self.chain_rpc_url, self.inner.h160_address, None)
+                .await?;
         Ok(cur_balance)
     }
+
+    /// Re-reads the next nonce (including pending transactions) of the signer
+    /// from the node, and resets the managed nonce to it.
+    pub async fn refresh_nonce(&self) -> io::Result<U256> {
+        let nonce = jsonrpc_client_evm::get_transaction_count(
+            &self.chain_rpc_url,
+            self.inner.h160_address,
+            true,
+        )
+        .await?;
+        log::info!("refreshed nonce {} for {}", nonce, self.inner.h160_address);
+        self.nonce_manager.set(self.inner.h160_address, nonce);
+        Ok(nonce)
+    }
+
+    /// Returns the nonce for the next transaction: "signer_nonce" if specified,
+    /// the managed nonce if "managed_nonce" is true, or the latest nonce otherwise.
+    pub(crate) async fn next_nonce(
+        &self,
+        signer_nonce: Option<U256>,
+        managed_nonce: bool,
+    ) -> io::Result<U256> {
+        let signer_addr = self.inner.h160_address;
+        let signer_nonce = if let Some(signer_nonce) = signer_nonce {
+            signer_nonce
+        } else if managed_nonce {
+            match self.nonce_manager.next(&signer_addr) {
+                Some(nonce) => nonce,
+                None => {
+                    log::info!("managed nonce not initialized -- fetching pending");
+                    self.refresh_nonce().await?;
+                    self.nonce_manager.next(&signer_addr).ok_or_else(|| {
+                        Error::new(ErrorKind::Other, "managed nonce not found after refresh")
+                    })?
+                }
+            }
+        } else {
+            log::info!("nonce not specified -- fetching latest");
+            self.middleware.initialize_nonce(None).await.map_err(|e| {
+                Error::new(ErrorKind::Other, format!("failed initialize_nonce '{}'", e))
+            })?
+        };
+        log::info!("latest signer nonce {}", signer_nonce);
+        Ok(signer_nonce)
+    }
+
+    /// Simulates the transaction with "eth_call" (if "simulate" is true),
+    /// signs and sends the transaction, and waits for its receipt.
+    /// If "acceptance" is not None, polls for the receipt with its interval
+    /// and fails with "ErrorKind::TimedOut" after its timeout. Otherwise,
+    /// waits on the provider's pending transaction without a timeout.
+    /// Fails if the receipt status is not successful (e.g., reverted on execution).
+    /// Set "managed_nonce" to true if the nonce was taken from the "nonce_manager",
+    /// so that the nonce is re-read on the next transaction if this one is not sent.
+    pub(crate) async fn issue_transaction(
+        &self,
+        tx: TypedTransaction,
+        simulate: bool,
+        managed_nonce: bool,
+        acceptance: Option<AcceptancePolling>,
+    ) -> io::Result<H256> {
+        let signer_addr = self.inner.h160_address;
+
+        if simulate {
+            if let Err(e) = self.provider.call(&tx, None).await {
+                if managed_nonce {
+                    self.nonce_manager.reset(&signer_addr);
+                }
+                return Err(match evm_revert::from_provider_error(&e) {
+                    Some(reason) => Error::new(
+                        ErrorKind::Other,
+                        format!("simulated transaction reverted '{}'", reason),
+                    ),
+                    None => Error::new(
+                        ErrorKind::Other,
+                        format!("failed to simulate transaction '{}'", e),
+                    ),
+                });
+            }
+            log::info!("simulated transaction successfully");
+        }
+
+        let pending_tx = self
+            .middleware
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| {
+                if managed_nonce {
+                    // the nonce was not consumed, so re-read on next submit
+                    self.nonce_manager.reset(&signer_addr);
+                }
+                Error::new(
+                    ErrorKind::Other,
+                    format!("failed to send_transaction '{}'", e),
+                )
+            })?;
+        let pending_tx_hash = *pending_tx;
+        let tx_hash = H256(pending_tx_hash.0);
+        log::info!("sent transaction '0x{:x}'", tx_hash);
+
+        let tx_receipt = match acceptance {
+            Some(acceptance) => self.poll_receipt(tx_hash, acceptance).await?,
+            None => {
+                log::info!("waiting for transaction '0x{:x}' receipt", tx_hash);
+                let tx_receipt = pending_tx.await.map_err(|e| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("failed to wait for pending tx '{}'", e),
+                    )
+                })?;
+                tx_receipt.ok_or_else(|| Error::new(ErrorKind::Other, "tx dropped from mempool"))?
+            }
+        };
+        check_receipt_status(&tx_receipt)?;
+
+        let tx = self
+            .middleware
+            .get_transaction(tx_receipt.transaction_hash)
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e)))?;
+
+        // serde_json::to_string(&tx).unwrap()
+        if let Some(inner) = &tx {
+            assert_eq!(inner.hash(), tx_receipt.transaction_hash);
+            log::info!("successfully issued transaction '0x{:x}'", inner.hash());
+        } else {
+            log::warn!("transaction not found in get_transaction");
+        }
+
+        Ok(tx_hash)
+    }
+
+    /// Polls for the transaction receipt until "acceptance.timeout" elapses.
+    async fn poll_receipt(
+        &self,
+        tx_hash: H256,
+        acceptance: AcceptancePolling,
+    ) -> io::Result<TransactionReceipt> {
+        log::info!(
+            "polling transaction '0x{:x}' for acceptance (initial wait {:?}, interval {:?}, timeout {:?})",
+            tx_hash,
+            acceptance.initial_wait,
+            acceptance.interval,
+            acceptance.timeout
+        );
+        let start = Instant::now();
+        sleep(acceptance.initial_wait).await;
+        loop {
+            let tx_receipt = self
+                .provider
+                .get_transaction_receipt(tx_hash)
+                .await
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("failed get_transaction_receipt '{}'", e),
+                    )
+                })?;
+            if let Some(tx_receipt) = tx_receipt {
+                return Ok(tx_receipt);
+            }
+
+            if start.elapsed() >= acceptance.timeout {
+                return Err(Error::new(
+                    ErrorKind::TimedOut,
+                    format!(
+                        "transaction '0x{:x}' still pending after {:?}",
+                        tx_hash, acceptance.timeout
+                    ),
+                ));
+            }
+            sleep(acceptance.interval).await;
+        }
+    }
+}
+
+/// Fails if the receipt status is not "1" (success).
+/// The mined transaction still consumed its nonce and gas.
+/// ref. <https://eips.ethereum.org/EIPS/eip-658>
+fn check_receipt_status(tx_receipt: &TransactionReceipt) -> io::Result<()> {
+    match tx_receipt.status {
+        Some(status) if status.as_u64() == 1 => Ok(()),
+        status => Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "transaction '0x{:x}' failed with receipt status {:?}",
+                tx_receipt.transaction_hash, status
+            ),
+        )),
+    }
+}
+
+/// Default acceptance polling parameters (e.g., "check_acceptance(true)").
+pub const DEFAULT_POLL_INITIAL_WAIT: Duration = Duration::from_millis(500);
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+pub const DEFAULT_POLL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Acceptance polling parameters for the issued transaction.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AcceptancePolling {
+    pub initial_wait: Duration,
+    pub interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for AcceptancePolling {
+    fn default() -> Self {
+        Self {
+            initial_wait: DEFAULT_POLL_INITIAL_WAIT,
+            interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_POLL_TIMEOUT,
+        }
+    }
+}
+
+/// Converts the (address, storage keys) pairs to the EIP-2930 access list.
+///
+/// Since EIP-2929 (Berlin, Apricot Phase 2 on C-chain), the first access to an
+/// account costs 2,600 gas and to a storage slot 2,100 gas ("cold"), whereas
+/// each pre-declared account costs 2,400 gas and each storage key 1,900 gas,
+/// and then 100 gas ("warm") on access. The access list only reduces the cost
+/// when every listed account and slot is actually accessed by the transaction
+/// (e.g., contract calls reading other contracts' storage). Do not list the
+/// sender, the recipient, or the precompiles, which are already warm.
+/// ref. <https://eips.ethereum.org/EIPS/eip-2930>
+/// ref. <https://eips.ethereum.org/EIPS/eip-2929>
+pub fn to_access_list(
+    access_list: Vec<(primitive_types::H160, Vec<H256>)>,
+) -> ethers_core::types::transaction::eip2930::AccessList {
+    use ethers_core::types::transaction::eip2930::{AccessList, AccessListItem};
+
+    AccessList(
+        access_list
+            .into_iter()
+            .map(|(address, storage_keys)| AccessListItem {
+                address: ethers::prelude::H160::from(address.as_fixed_bytes()),
+                storage_keys: storage_keys
+                    .into_iter()
+                    .map(|k| ethers::prelude::H256::from(k.as_fixed_bytes()))
+                    .collect(),
+            })
+            .collect(),
+    )
 }
 
 /// Converts WEI to GWEI.
@@ -126,3 +404,246 @@ pub fn wei_to_gwei(wei: impl Into<U256>) -> U256 {
         wei.div(*GWEI)
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::test_builder_provider --exact --show-output
+#[test]
+fn test_builder_provider() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let chain_rpc_url = "http://localhost:9650/ext/bc/C/rpc";
+    let provider = Provider::<Http>::try_from(chain_rpc_url).unwrap();
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let builder = wallet::Builder::new(&k).provider(provider);
+    assert_eq!(
+        builder.base_http_urls,
+        vec!["http://localhost:9650".to_string()]
+    );
+    assert!(same_rpc_url(
+        builder.provider.as_ref().unwrap().url().as_str(),
+        chain_rpc_url
+    ));
+
+    // explicit base HTTP URL is not overwritten
+    let builder = wallet::Builder::new(&k)
+        .base_http_url("http://127.0.0.1:9650".to_string())
+        .provider(Provider::<Http>::try_from(chain_rpc_url).unwrap());
+    assert_eq!(
+        builder.base_http_urls,
+        vec!["http://127.0.0.1:9650".to_string()]
+    );
+
+    assert!(same_rpc_url(
+        "http://localhost:9650/",
+        "http://localhost:9650"
+    ));
+    assert!(!same_rpc_url(
+        chain_rpc_url,
+        "http://localhost:9650/ext/bc/X/rpc"
+    ));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::test_check_receipt_status --exact --show-output
+#[test]
+fn test_check_receipt_status() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let mut tx_receipt = TransactionReceipt {
+        status: Some(1u64.into()),
+        ..Default::default()
+    };
+    assert!(check_receipt_status(&tx_receipt).is_ok());
+
+    // e.g., reverted on execution
+    tx_receipt.status = Some(0u64.into());
+    assert!(check_receipt_status(&tx_receipt).is_err());
+
+    // e.g., pre-Byzantium receipt without the status
+    tx_receipt.status = None;
+    assert!(check_receipt_status(&tx_receipt).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::test_submit_managed_nonce --exact --show-output
+#[test]
+fn test_submit_managed_nonce() {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use crate::{ids, wallet::fee};
+    use ethers_core::{
+        types::Transaction,
+        utils::{keccak256, rlp},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let key_info = k.to_info(1u32).unwrap();
+    let eth_signer: ethers_signers::LocalWallet = k.to_ethers_core_signing_key().into();
+
+    ab!(async move {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let http_rpc = format!("http://{}", listener.local_addr().unwrap());
+        let chain_rpc_url = format!("{http_rpc}/ext/bc/C/rpc");
+
+        // records the JSON-RPC methods and the nonces of the sent transactions
+        let methods = Arc::new(Mutex::new(Vec::<String>::new()));
+        let sent_nonces = Arc::new(Mutex::new(Vec::<u64>::new()));
+        let (server_methods, server_nonces) = (methods.clone(), sent_nonces.clone());
+        let signer_addr = key_info.h160_address;
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 8192];
+                let mut n = 0;
+                let body = loop {
+                    n += stream.read(&mut buf[n..]).await.unwrap();
+                    let req = String::from_utf8_lossy(&buf[..n]).to_string();
+                    if let Some(pos) = req.find("\r\n\r\n") {
+                        let content_length = req
+                            .lines()
+                            .find_map(|l| {
+                                l.to_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if n >= pos + 4 + content_length {
+                            break req[pos + 4..].to_string();
+                        }
+                    }
+                };
+
+                let req: serde_json::Value = serde_json::from_str(&body).unwrap();
+                let method = req["method"].as_str().unwrap().to_string();
+                server_methods.lock().unwrap().push(method.clone());
+                let result = match method.as_str() {
+                    "eth_getTransactionCount" => serde_json::json!("0x7"),
+                    "eth_sendRawTransaction" => {
+                        let raw = req["params"][0].as_str().unwrap();
+                        let raw = hex::decode(raw.trim_start_matches("0x")).unwrap();
+                        let tx: Transaction = rlp::decode(&raw).unwrap();
+                        server_nonces.lock().unwrap().push(tx.nonce.as_u64());
+                        serde_json::json!(format!("0x{}", hex::encode(keccak256(&raw))))
+                    }
+                    "eth_getTransactionReceipt" => serde_json::json!({
+                        "transactionHash": req["params"][0],
+                        "transactionIndex": "0x0",
+                        "blockHash": format!("0x{}", "11".repeat(32)),
+                        "blockNumber": "0x1",
+                        "from": format!("0x{:x}", signer_addr),
+                        "to": null,
+                        "cumulativeGasUsed": "0x5208",
+                        "gasUsed": "0x5208",
+                        "contractAddress": null,
+                        "logs": [],
+                        "logsBloom": format!("0x{}", "00".repeat(256)),
+                        "status": "0x1",
+                    }),
+                    "eth_newBlockFilter" => serde_json::json!("0x1"),
+                    "eth_getFilterChanges" => serde_json::json!([]),
+                    _ => serde_json::Value::Null,
+                };
+                let body = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": req["id"],
+                    "result": result,
+                })
+                .to_string();
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(resp.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        // no wallet RPC call is made
+        let w = wallet::Wallet {
+            key_type: key::secp256k1::KeyType::Hot,
+            keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+            base_http_urls: vec![http_rpc.clone()],
+            base_http_url_cursor: Arc::new(Mutex::new(0)),
+            network_id: 1,
+            network_name: "mainnet".to_string(),
+            x_address: key_info.addresses.get(&1).unwrap().x.clone(),
+            p_address: key_info.addresses.get(&1).unwrap().p.clone(),
+            short_address: key_info.short_address,
+            eth_address: key_info.eth_address.clone(),
+            h160_address: key_info.h160_address,
+            blockchain_id_x: ids::Id::empty(),
+            blockchain_id_p: ids::Id::empty(),
+            blockchain_id_c: ids::Id::empty(),
+            avax_asset_id: ids::Id::empty(),
+            fee_cache: fee::Cache::new(),
+            evm_provider: None,
+            evm_nonce_managers: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        // each transaction is issued from a new "evm" wallet on the same chain
+        let chain_id = U256::from(43114_u64);
+        let mut tx_hashes = Vec::new();
+        for _ in 0..3 {
+            let ev = w.evm(&eth_signer, &chain_rpc_url, chain_id).unwrap();
+            let tx_hash = ev
+                .legacy()
+                .recipient(primitive_types::H160::random())
+                .value(U256::from(1_000_000_u64))
+                .gas_price(U256::from(25_000_000_000_u64))
+                .gas_limit(U256::from(21000))
+                .managed_nonce(true)
+                .check_acceptance(true)
+                .poll_initial_wait(Duration::from_millis(10))
+                .poll_interval(Duration::from_millis(10))
+                .submit()
+                .await
+                .unwrap();
+            tx_hashes.push(tx_hash);
+        }
+
+        // the pending nonce is fetched once, then tracked by the shared manager
+        assert_eq!(*sent_nonces.lock().unwrap(), vec![7, 8, 9]);
+        assert_eq!(
+            methods
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.as_str() == "eth_getTransactionCount")
+                .count(),
+            1
+        );
+        assert_ne!(tx_hashes[0], tx_hashes[1]);
+        assert_ne!(tx_hashes[1], tx_hashes[2]);
+
+        // other chains are tracked separately
+        let ev = w
+            .evm(&eth_signer, &chain_rpc_url, U256::from(43113_u64))
+            .unwrap();
+        assert_eq!(ev.nonce_manager.next(&key_info.h160_address), None);
+        let ev = w.evm(&eth_signer, &chain_rpc_url, chain_id).unwrap();
+        assert_eq!(
+            ev.nonce_manager.next(&key_info.h160_address),
+            Some(U256::from(10))
+        );
+    });
+}
@@ -0,0 +1,68 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use primitive_types::{H160, U256};
+
+/// Tracks the next nonce per signer address locally, so that sequential
+/// transactions from the same address do not reuse the nonce before the
+/// previous ones are reflected in the node's transaction count.
+/// Cloned managers share the same nonces.
+#[derive(Clone, Debug, Default)]
+pub struct NonceManager {
+    nonces: Arc<Mutex<HashMap<H160, U256>>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nonce to use for the next transaction from the address,
+    /// and increments the tracked nonce.
+    /// Returns None if the address is not tracked (e.g., not yet fetched from the node).
+    pub fn next(&self, addr: &H160) -> Option<U256> {
+        let mut nonces = self.nonces.lock().unwrap();
+        let nonce = nonces.get_mut(addr)?;
+        let cur = *nonce;
+        *nonce = cur + 1;
+        Some(cur)
+    }
+
+    /// Overwrites the next nonce for the address (e.g., the pending transaction count).
+    pub fn set(&self, addr: H160, next_nonce: U256) {
+        self.nonces.lock().unwrap().insert(addr, next_nonce);
+    }
+
+    /// Stops tracking the address, so the next transaction re-reads from the node.
+    pub fn reset(&self, addr: &H160) {
+        self.nonces.lock().unwrap().remove(addr);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::nonce_manager::test_nonce_manager --exact --show-output
+#[test]
+fn test_nonce_manager() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let addr = H160::repeat_byte(0x01);
+    let mgr = NonceManager::new();
+    assert_eq!(mgr.next(&addr), None);
+
+    // three sequential submits after fetching the pending count once
+    mgr.set(addr, U256::from(7));
+    let shared = mgr.clone();
+    assert_eq!(mgr.next(&addr), Some(U256::from(7)));
+    assert_eq!(shared.next(&addr), Some(U256::from(8)));
+    assert_eq!(mgr.next(&addr), Some(U256::from(9)));
+
+    // other addresses are tracked separately
+    assert_eq!(mgr.next(&H160::repeat_byte(0x02)), None);
+
+    mgr.reset(&addr);
+    assert_eq!(shared.next(&addr), None);
+}
@@ -0,0 +1,290 @@
+//! Replacement (speed-up) of the pending transactions.
+//!
+//! The node accepts a transaction with the same nonce as a pending one
+//! only if both its fee cap and tip are bumped by at least 10%
+//! (e.g., "txpool.pricebump" in coreth/subnet-evm), and drops the original.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/subnet-evm/core/txpool#DefaultConfig>
+use std::io::{self, Error, ErrorKind};
+
+use crate::{key, wallet::evm};
+use ethers::prelude::{
+    Eip1559TransactionRequest, Transaction, TransactionRequest, H160 as EthH160, U256 as EthU256,
+    U64 as EthU64,
+};
+use ethers_core::types::transaction::{
+    eip2718::TypedTransaction, eip2930::Eip2930TransactionRequest,
+};
+use ethers_providers::Middleware;
+use primitive_types::H256;
+
+/// Minimum fee bump in percent for the node to accept the replacement.
+pub const MIN_FEE_BUMP_PERCENT: u64 = 10;
+
+impl<'a, T, S> evm::Evm<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    /// Re-signs and issues the pending transaction with the same nonce
+    /// and the fees bumped by "MIN_FEE_BUMP_PERCENT", and returns the
+    /// replacement transaction Id.
+    /// Fails if the original transaction is not found or already mined.
+    /// Polls for the replacement receipt with the "check_acceptance" defaults,
+    /// and fails with "ErrorKind::TimedOut" after "evm::DEFAULT_POLL_TIMEOUT"
+    /// (e.g., the replacement is still underpriced).
+    pub async fn replace(&self, tx_hash: H256) -> io::Result<H256> {
+        self.replace_with_fee_bump(tx_hash, MIN_FEE_BUMP_PERCENT)
+            .await
+    }
+
+    /// Same as "replace" but with the custom fee bump in percent,
+    /// which must be at least "MIN_FEE_BUMP_PERCENT".
+    pub async fn replace_with_fee_bump(
+        &self,
+        tx_hash: H256,
+        fee_bump_percent: u64,
+    ) -> io::Result<H256> {
+        log::info!(
+            "replacing transaction '0x{:x}' with {}% fee bump via {}",
+            tx_hash,
+            fee_bump_percent,
+            self.chain_rpc_url
+        );
+
+        let original = self
+            .provider
+            .get_transaction(ethers::prelude::H256::from(tx_hash.as_fixed_bytes()))
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e)))?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("transaction '0x{:x}' not found", tx_hash),
+                )
+            })?;
+
+        let signer_addr = EthH160::from(self.inner.h160_address.as_fixed_bytes());
+        if original.from != signer_addr {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "transaction '0x{:x}' was sent by {:?}, not by the wallet {:?}",
+                    tx_hash, original.from, signer_addr
+                ),
+            ));
+        }
+
+        let tx = replacement_tx(&original, self.chain_id.as_u64(), fee_bump_percent)?;
+        self.issue_transaction(tx, false, false, Some(evm::AcceptancePolling::default()))
+            .await
+    }
+}
+
+/// Bumps the fee by the percent, rounding up so that the bump
+/// is never less than the percent (e.g., 10% of 1 wei is 1 wei).
+pub fn bump_fee(fee: EthU256, fee_bump_percent: u64) -> io::Result<EthU256> {
+    let bumped = fee
+        .checked_mul(EthU256::from(100 + fee_bump_percent))
+        .and_then(|v| v.checked_add(EthU256::from(99)))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("fee {} overflows with {}% bump", fee, fee_bump_percent),
+            )
+        })?;
+    Ok(bumped / EthU256::from(100))
+}
+
+/// Builds the replacement of the pending transaction with the same nonce,
+/// recipient, value, data, gas limit, and access list, and the bumped fees.
+/// Legacy and EIP-2930 transactions bump the gas price, and EIP-1559
+/// transactions bump both the fee cap and tip.
+pub fn replacement_tx(
+    original: &Transaction,
+    chain_id: u64,
+    fee_bump_percent: u64,
+) -> io::Result<TypedTransaction> {
+    if let Some(block_number) = original.block_number {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "transaction '0x{:x}' already mined in block {}",
+                original.hash, block_number
+            ),
+        ));
+    }
+    if fee_bump_percent < MIN_FEE_BUMP_PERCENT {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "fee bump {}% is less than the minimum {}%",
+                fee_bump_percent, MIN_FEE_BUMP_PERCENT
+            ),
+        ));
+    }
+
+    let tx: TypedTransaction = match original.transaction_type.map(|t| t.as_u64()) {
+        // dynamic fee transaction
+        Some(2) => {
+            let (max_fee_per_gas, max_priority_fee_per_gas) =
+                match (original.max_fee_per_gas, original.max_priority_fee_per_gas) {
+                    (Some(max_fee), Some(tip)) => (max_fee, tip),
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("transaction '0x{:x}' has no EIP-1559 fees", original.hash),
+                        ))
+                    }
+                };
+
+            let mut tx_request = Eip1559TransactionRequest::new()
+                .from(original.from)
+                .chain_id(EthU64::from(chain_id))
+                .nonce(original.nonce)
+                .value(original.value)
+                .gas(original.gas)
+                .data(original.input.clone())
+                .max_fee_per_gas(bump_fee(max_fee_per_gas, fee_bump_percent)?)
+                .max_priority_fee_per_gas(bump_fee(max_priority_fee_per_gas, fee_bump_percent)?);
+            if let Some(access_list) = &original.access_list {
+                tx_request = tx_request.access_list(access_list.clone());
+            }
+            if let Some(to) = original.to {
+                tx_request = tx_request.to(to);
+            }
+            tx_request.into()
+        }
+
+        // legacy or access list (EIP-2930) transaction
+        None | Some(0) | Some(1) => {
+            let gas_price = original.gas_price.ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("transaction '0x{:x}' has no gas price", original.hash),
+                )
+            })?;
+
+            let mut tx_request = TransactionRequest::new()
+                .from(original.from)
+                .chain_id(EthU64::from(chain_id))
+                .nonce(original.nonce)
+                .value(original.value)
+                .gas(original.gas)
+                .data(original.input.clone())
+                .gas_price(bump_fee(gas_price, fee_bump_percent)?);
+            if let Some(to) = original.to {
+                tx_request = tx_request.to(to);
+            }
+            if original.transaction_type == Some(EthU64::from(1)) {
+                Eip2930TransactionRequest::new(
+                    tx_request,
+                    original.access_list.clone().unwrap_or_default(),
+                )
+                .into()
+            } else {
+                tx_request.into()
+            }
+        }
+
+        Some(t) => {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported transaction type {}", t),
+            ))
+        }
+    };
+
+    log::info!(
+        "replacing nonce {} with the bumped fee {:?}",
+        original.nonce,
+        tx.gas_price()
+    );
+    Ok(tx)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet,wallet_evm" -- wallet::evm::replace::test_replacement_tx --exact --show-output
+#[test]
+fn test_replacement_tx() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    assert_eq!(
+        bump_fee(EthU256::from(100), 10).unwrap(),
+        EthU256::from(110)
+    );
+    assert_eq!(bump_fee(EthU256::from(1), 10).unwrap(), EthU256::from(2));
+    assert_eq!(bump_fee(EthU256::from(25), 10).unwrap(), EthU256::from(28));
+    assert!(bump_fee(EthU256::MAX, 10).is_err());
+
+    let to = EthH160::random();
+    let underpriced = Transaction {
+        hash: ethers::prelude::H256::random(),
+        nonce: EthU256::from(7),
+        from: EthH160::random(),
+        to: Some(to),
+        value: EthU256::from(1),
+        gas: EthU256::from(21000),
+        transaction_type: Some(EthU64::from(2)),
+        max_fee_per_gas: Some(EthU256::from(1_000_000_000_u64)),
+        max_priority_fee_per_gas: Some(EthU256::from(1)),
+        ..Default::default()
+    };
+
+    let tx = replacement_tx(&underpriced, 43114, MIN_FEE_BUMP_PERCENT).unwrap();
+    assert_eq!(tx.nonce(), Some(&EthU256::from(7)));
+    assert_eq!(tx.from(), Some(&underpriced.from));
+    assert_eq!(tx.to_addr(), Some(&to));
+    assert_eq!(tx.gas(), Some(&EthU256::from(21000)));
+    assert_eq!(tx.chain_id(), Some(EthU64::from(43114)));
+    match &tx {
+        TypedTransaction::Eip1559(inner) => {
+            assert_eq!(
+                inner.max_fee_per_gas,
+                Some(EthU256::from(1_100_000_000_u64))
+            );
+            assert_eq!(inner.max_priority_fee_per_gas, Some(EthU256::from(2)));
+        }
+        _ => panic!("unexpected transaction type"),
+    }
+
+    let legacy = Transaction {
+        transaction_type: None,
+        gas_price: Some(EthU256::from(25_000_000_000_u64)),
+        ..underpriced.clone()
+    };
+    let tx = replacement_tx(&legacy, 43114, 20).unwrap();
+    assert!(matches!(tx, TypedTransaction::Legacy(_)));
+    assert_eq!(tx.gas_price(), Some(EthU256::from(30_000_000_000_u64)));
+
+    let access_list = ethers::types::transaction::eip2930::AccessList(vec![
+        ethers::types::transaction::eip2930::AccessListItem {
+            address: to,
+            storage_keys: vec![ethers::prelude::H256::random()],
+        },
+    ]);
+    let eip2930 = Transaction {
+        transaction_type: Some(EthU64::from(1)),
+        access_list: Some(access_list.clone()),
+        ..legacy.clone()
+    };
+    let tx = replacement_tx(&eip2930, 43114, 20).unwrap();
+    assert_eq!(tx.gas_price(), Some(EthU256::from(30_000_000_000_u64)));
+    assert_eq!(tx.nonce(), Some(&EthU256::from(7)));
+    match &tx {
+        TypedTransaction::Eip2930(inner) => assert_eq!(inner.access_list, access_list),
+        _ => panic!("unexpected transaction type"),
+    }
+
+    // less than the minimum bump
+    assert!(replacement_tx(&legacy, 43114, 5).is_err());
+
+    // already mined
+    let mined = Transaction {
+        block_number: Some(EthU64::from(100)),
+        ..underpriced
+    };
+    assert!(replacement_tx(&mined, 43114, MIN_FEE_BUMP_PERCENT).is_err());
+}
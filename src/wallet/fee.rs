@@ -0,0 +1,138 @@
+use std::{
+    io::{self, Error, ErrorKind},
+    sync::{Arc, Mutex},
+};
+
+use crate::jsonrpc::info::GetTxFeeResult;
+
+/// Represents the X/P-chain transaction types with distinct fees.
+/// ref. <https://docs.avax.network/build/avalanchego-apis/info/#infogettxfee>
+/// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TxKind {
+    /// Non-state creating transactions (e.g., transfer, export, import),
+    /// on both X and P-chain.
+    Base,
+    /// X-chain "CreateAssetTx".
+    CreateAsset,
+    /// P-chain "CreateSubnetTx".
+    CreateSubnet,
+    /// P-chain "TransformSubnetTx".
+    TransformSubnet,
+    /// P-chain "CreateChainTx".
+    CreateBlockchain,
+    /// P-chain "AddValidatorTx", or "AddPermissionlessValidatorTx" for the primary network.
+    AddPrimaryNetworkValidator,
+    /// P-chain "AddDelegatorTx".
+    AddPrimaryNetworkDelegator,
+    /// P-chain "AddSubnetValidatorTx".
+    AddSubnetValidator,
+    /// P-chain "AddPermissionlessDelegatorTx" for subnets.
+    AddSubnetDelegator,
+}
+
+impl TxKind {
+    /// Returns the fee of this transaction type from the fee schedule.
+    pub fn fee(&self, fees: &GetTxFeeResult) -> u64 {
+        match self {
+            TxKind::Base => fees.tx_fee,
+            TxKind::CreateAsset => fees.create_asset_tx_fee,
+            TxKind::CreateSubnet => fees.create_subnet_tx_fee,
+            TxKind::TransformSubnet => fees.transform_subnet_tx_fee,
+            TxKind::CreateBlockchain => fees.create_blockchain_tx_fee,
+            TxKind::AddPrimaryNetworkValidator => fees.add_primary_network_validator_fee,
+            TxKind::AddPrimaryNetworkDelegator => fees.add_primary_network_delegator_fee,
+            TxKind::AddSubnetValidator => fees.add_subnet_validator_fee,
+            TxKind::AddSubnetDelegator => fees.add_subnet_delegator_fee,
+        }
+    }
+
+    /// Returns true if the transaction type can be issued on X-chain.
+    pub fn is_x(&self) -> bool {
+        matches!(self, TxKind::Base | TxKind::CreateAsset)
+    }
+
+    /// Returns true if the transaction type can be issued on P-chain.
+    pub fn is_p(&self) -> bool {
+        !matches!(self, TxKind::CreateAsset)
+    }
+
+    /// Fails if the transaction type cannot be issued on the chain (e.g., "X").
+    pub(crate) fn check_chain(&self, chain_alias: &str) -> io::Result<()> {
+        let valid = match chain_alias {
+            "X" => self.is_x(),
+            "P" => self.is_p(),
+            _ => false,
+        };
+        if !valid {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{:?} is not a {}-chain transaction", self, chain_alias),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Caches the fee schedule for the wallet's lifetime.
+/// Cloned wallets share the same cache.
+#[derive(Clone, Debug, Default)]
+pub struct Cache {
+    fees: Arc<Mutex<Option<GetTxFeeResult>>>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached fee schedule, or None if not yet fetched.
+    pub fn get(&self) -> Option<GetTxFeeResult> {
+        self.fees.lock().unwrap().clone()
+    }
+
+    /// Overwrites the cached fee schedule.
+    pub fn set(&self, fees: GetTxFeeResult) {
+        self.fees.lock().unwrap().replace(fees);
+    }
+
+    /// Clears the cached fee schedule, so the next estimate re-reads from the node.
+    pub fn reset(&self) {
+        self.fees.lock().unwrap().take();
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client,wallet" -- wallet::fee::test_fee --exact --show-output
+#[test]
+fn test_fee() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let fees = GetTxFeeResult {
+        tx_fee: 1_000_000,
+        create_asset_tx_fee: 10_000_000,
+        create_subnet_tx_fee: 1_000_000_000,
+        add_subnet_validator_fee: 1_000_000,
+        ..GetTxFeeResult::default()
+    };
+
+    assert_eq!(TxKind::Base.fee(&fees), 1_000_000);
+    assert_eq!(TxKind::CreateAsset.fee(&fees), 10_000_000);
+    assert_eq!(TxKind::CreateSubnet.fee(&fees), 1_000_000_000);
+    assert_eq!(TxKind::AddPrimaryNetworkValidator.fee(&fees), 0);
+
+    assert!(TxKind::Base.check_chain("X").is_ok());
+    assert!(TxKind::Base.check_chain("P").is_ok());
+    assert!(TxKind::CreateAsset.check_chain("P").is_err());
+    assert!(TxKind::CreateSubnet.check_chain("X").is_err());
+
+    let cache = Cache::new();
+    assert!(cache.get().is_none());
+    let shared = cache.clone();
+    cache.set(fees.clone());
+    assert_eq!(shared.get(), Some(fees));
+    shared.reset();
+    assert!(cache.get().is_none());
+}
@@ -1,19 +1,33 @@
+pub mod fee;
 pub mod p;
 pub mod x;
 
+#[cfg(feature = "wallet_evm")]
+pub mod c;
 #[cfg(feature = "wallet_evm")]
 pub mod evm;
 
 use std::{
-    fmt, io,
+    fmt,
+    io::{self, Error, ErrorKind},
     sync::{Arc, Mutex},
 };
 
 use crate::{
     ids::{self, short},
-    jsonrpc::client::{info as api_info, x as api_x},
+    jsonrpc::{
+        self,
+        client::{evm as api_evm, info as api_info, x as api_x},
+    },
     key, utils,
 };
+use primitive_types::U256;
+
+#[cfg(feature = "wallet_evm")]
+use std::collections::HashMap;
+
+#[cfg(feature = "wallet_evm")]
+use ethers_providers::{Http, Provider};
 
 #[derive(Debug, Clone)]
 pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone> {
@@ -35,17 +49,24 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
 
     pub blockchain_id_x: ids::Id,
     pub blockchain_id_p: ids::Id,
+    pub blockchain_id_c: ids::Id,
 
     pub avax_asset_id: ids::Id,
 
-    /// Fee that is burned by every non-state creating transaction.
-    pub tx_fee: u64,
-    /// Transaction fee for adding a primary network validator.
-    pub add_primary_network_validator_fee: u64,
-    /// Transaction fee to create a new subnet.
-    pub create_subnet_tx_fee: u64,
-    /// Transaction fee to create a new blockchain.
-    pub create_blockchain_tx_fee: u64,
+    /// Fee schedule for all transaction types, shared by the cloned wallets.
+    /// Fetched via "info.getTxFee" on the first "estimate_fee" call,
+    /// unless set by "Builder::tx_fees" or "Wallet::set_tx_fees".
+    pub fee_cache: fee::Cache,
+
+    /// Chain RPC provider passed via "Builder::provider", reused by "evm"
+    /// for the same chain RPC URL (shares the connection pool).
+    #[cfg(feature = "wallet_evm")]
+    pub evm_provider: Option<Provider<Http>>,
+
+    /// Managed nonces per EVM chain Id, shared by the cloned wallets,
+    /// so that all "evm" wallets on the same chain use the same nonce manager.
+    #[cfg(feature = "wallet_evm")]
+    pub evm_nonce_managers: Arc<Mutex<HashMap<U256, evm::nonce_manager::NonceManager>>>,
 }
 
 /// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
@@ -69,21 +90,14 @@ where
 
         write!(f, "blockchain_id_x: {}\n", self.blockchain_id_x)?;
         write!(f, "blockchain_id_p: {}\n", self.blockchain_id_p)?;
+        write!(f, "blockchain_id_c: {}\n", self.blockchain_id_c)?;
 
         write!(f, "avax_asset_id: {}\n", self.avax_asset_id)?;
 
-        write!(f, "tx_fee: {}\n", self.tx_fee)?;
-        write!(
-            f,
-            "add_primary_network_validator_fee: {}\n",
-            self.add_primary_network_validator_fee
-        )?;
-        write!(f, "create_subnet_tx_fee: {}\n", self.create_subnet_tx_fee)?;
-        write!(
-            f,
-            "create_blockchain_tx_fee: {}\n",
-            self.create_blockchain_tx_fee
-        )
+        match self.fee_cache.get() {
+            Some(fees) => write!(f, "tx_fees: {:?}\n", fees),
+            None => write!(f, "tx_fees: (not fetched yet)\n"),
+        }
     }
 }
 
@@ -103,12 +117,89 @@ where
         log::debug!("picked base http URL {http_rpc} at index {picked}");
         (picked, http_rpc)
     }
+
+    /// Returns the fee of the transaction type from the cached fee schedule.
+    /// Fetches "info.getTxFee" on the first call, and caches the result
+    /// for the wallet's lifetime.
+    pub async fn estimate_fee(&self, tx_kind: fee::TxKind) -> io::Result<u64> {
+        if let Some(fees) = self.fee_cache.get() {
+            return Ok(tx_kind.fee(&fees));
+        }
+
+        let resp = api_info::get_tx_fee(&self.pick_base_http_url().1).await?;
+        let fees = resp
+            .result
+            .ok_or_else(|| Error::new(ErrorKind::Other, "unexpected None GetTxFeeResult"))?;
+        self.fee_cache.set(fees.clone());
+        Ok(tx_kind.fee(&fees))
+    }
+
+    /// Overrides the cached fee schedule (e.g., for offline signing),
+    /// so that "estimate_fee" no longer queries the node.
+    /// All X and P-chain transaction builders read the fees from this schedule.
+    pub fn set_tx_fees(&self, fees: jsonrpc::info::GetTxFeeResult) {
+        self.fee_cache.set(fees);
+    }
+
+    /// Fetches the balances of the wallet owner on X, P, and C-chain
+    /// concurrently from one endpoint.
+    /// Fails only if all three chains fail, so that one unreachable chain
+    /// can be told apart in the per-chain results.
+    pub async fn balances(&self) -> io::Result<Balances> {
+        let http_rpc = self.pick_base_http_url().1;
+        let c_chain_rpc = format!("{http_rpc}/ext/bc/C/rpc");
+
+        let x = self.x();
+        let p = self.p();
+        let (x, p, c) = tokio::join!(
+            x.balance_with_endpoint(&http_rpc),
+            p.balance_with_endpoint(&http_rpc),
+            api_evm::get_balance(&c_chain_rpc, self.h160_address, None),
+        );
+
+        match (&x, &p, &c) {
+            (Err(x_err), Err(p_err), Err(c_err)) => Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "failed to fetch balances from {http_rpc} (X: {}, P: {}, C: {})",
+                    x_err, p_err, c_err
+                ),
+            )),
+            _ => Ok(Balances { x, p, c }),
+        }
+    }
+}
+
+/// Balances of the wallet owner across the primary network chains.
+/// Each chain holds its own result, in case some chains are unreachable.
+#[derive(Debug)]
+pub struct Balances {
+    /// X-chain balance in nAVAX.
+    pub x: io::Result<u64>,
+    /// P-chain balance in nAVAX.
+    pub p: io::Result<u64>,
+    /// C-chain balance in wei.
+    pub c: io::Result<U256>,
+}
+
+impl Balances {
+    /// Returns true if all three chains returned the balance.
+    pub fn is_complete(&self) -> bool {
+        self.x.is_ok() && self.p.is_ok() && self.c.is_ok()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Builder<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone> {
     pub key: T,
     pub base_http_urls: Vec<String>,
+
+    /// Fee schedule to use instead of fetching "info.getTxFee".
+    pub tx_fees: Option<jsonrpc::info::GetTxFeeResult>,
+
+    /// Pre-built chain RPC provider to share with the wallet.
+    #[cfg(feature = "wallet_evm")]
+    pub provider: Option<Provider<Http>>,
 }
 
 impl<T> Builder<T>
@@ -119,9 +210,20 @@ where
         Self {
             key: key.clone(),
             base_http_urls: Vec::new(),
+            tx_fees: None,
+            #[cfg(feature = "wallet_evm")]
+            provider: None,
         }
     }
 
+    /// Sets the fee schedule, overriding the one from "info.getTxFee"
+    /// (e.g., for offline signing).
+    #[must_use]
+    pub fn tx_fees(mut self, fees: jsonrpc::info::GetTxFeeResult) -> Self {
+        self.tx_fees = Some(fees);
+        self
+    }
+
     /// Adds an HTTP rpc endpoint to the `http_rpcs` field in the Builder.
     /// If URL path is specified, it strips the URL path.
     #[must_use]
@@ -148,6 +250,20 @@ where
         self
     }
 
+    /// Sets the chain RPC provider (e.g., "{base_http_url}/ext/bc/C/rpc"),
+    /// so that "Wallet::evm" reuses the same connection-pooled client
+    /// instead of creating a new one from the URL.
+    /// If no base HTTP URL has been set, it is derived from the provider URL.
+    #[cfg(feature = "wallet_evm")]
+    #[must_use]
+    pub fn provider(mut self, provider: Provider<Http>) -> Self {
+        if self.base_http_urls.is_empty() {
+            self = self.base_http_url(provider.url().to_string());
+        }
+        self.provider = Some(provider);
+        self
+    }
+
     /// Overwrites the HTTP rpc endpoints to the `urls` field in the Builder.
     /// If URL path is specified, it strips the URL path.
     #[must_use]
@@ -183,8 +299,7 @@ where
         let keychain = key::secp256k1::keychain::Keychain::new(vec![self.key.clone()]);
         let h160_address = keychain.keys[0].h160_address();
 
-        let resp = api_info::get_network_id(&self.base_http_urls[0]).await?;
-        let network_id = resp.result.unwrap().network_id;
+        let network_id = api_info::get_network_id(&self.base_http_urls[0]).await?;
         let resp = api_info::get_network_name(&self.base_http_urls[0]).await?;
         let network_name = resp.result.unwrap().network_name;
 
@@ -194,17 +309,21 @@ where
         let resp = api_info::get_blockchain_id(&self.base_http_urls[0], "P").await?;
         let blockchain_id_p = resp.result.unwrap().blockchain_id;
 
+        let resp = api_info::get_blockchain_id(&self.base_http_urls[0], "C").await?;
+        let blockchain_id_c = resp.result.unwrap().blockchain_id;
+
         let resp = api_x::get_asset_description(&self.base_http_urls[0], "AVAX").await?;
         let resp = resp
             .result
             .expect("unexpected None GetAssetDescriptionResult");
         let avax_asset_id = resp.asset_id;
 
-        let resp = api_info::get_tx_fee(&self.base_http_urls[0]).await?;
-        let get_tx_fee_result = resp.result.unwrap();
-        let tx_fee = get_tx_fee_result.tx_fee;
-        let create_subnet_tx_fee = get_tx_fee_result.create_subnet_tx_fee;
-        let create_blockchain_tx_fee = get_tx_fee_result.create_blockchain_tx_fee;
+        // fetched lazily by "estimate_fee" unless overridden
+        let fee_cache = fee::Cache::new();
+        if let Some(fees) = &self.tx_fees {
+            log::info!("using the fee schedule override");
+            fee_cache.set(fees.clone());
+        }
 
         let w = Wallet {
             key_type: self.key.key_type(),
@@ -224,13 +343,16 @@ where
 
             blockchain_id_x,
             blockchain_id_p,
+            blockchain_id_c,
 
             avax_asset_id,
 
-            tx_fee,
-            add_primary_network_validator_fee: ADD_PRIMARY_NETWORK_VALIDATOR_FEE,
-            create_subnet_tx_fee,
-            create_blockchain_tx_fee,
+            fee_cache,
+
+            #[cfg(feature = "wallet_evm")]
+            evm_provider: self.provider.clone(),
+            #[cfg(feature = "wallet_evm")]
+            evm_nonce_managers: Arc::new(Mutex::new(HashMap::new())),
         };
         log::info!("initiated the wallet:\n{}", w);
 
@@ -240,3 +362,6 @@ where
 
 /// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
 pub const ADD_PRIMARY_NETWORK_VALIDATOR_FEE: u64 = 0;
+
+/// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
+pub const ADD_PRIMARY_NETWORK_DELEGATOR_FEE: u64 = 0;
@@ -7,7 +7,7 @@ use crate::{
     formatting,
     ids::{self, node},
     jsonrpc::client::p as client_p,
-    key, platformvm, txs,
+    key, platformvm, txs, wallet,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
 use tokio::time::{sleep, Duration, Instant};
@@ -175,11 +175,15 @@ where
             return Ok((ids::Id::empty(), false));
         }
 
+        let fee = self
+            .inner
+            .estimate_fee(wallet::fee::TxKind::AddSubnetValidator)
+            .await?;
         let cur_balance_p = self.inner.balance().await?;
-        if cur_balance_p < self.inner.inner.tx_fee {
+        if cur_balance_p < fee {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.inner.inner.tx_fee),
+                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, fee),
              ));
         };
         log::info!(
@@ -188,7 +192,7 @@ where
             cur_balance_p
         );
 
-        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, fee).await?;
         let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
 
         let mut tx = platformvm::txs::add_subnet_validator::Tx {
@@ -5,9 +5,9 @@ use std::{
 
 use crate::{
     formatting,
-    ids::{self, node},
+    ids::{self, node, short},
     jsonrpc::client::p as client_p,
-    key, platformvm, txs, units,
+    key, platformvm, txs, units, wallet,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
 use tokio::time::{sleep, Duration, Instant};
@@ -37,6 +37,10 @@ where
     /// Validate reward fee in percent.
     pub reward_fee_percent: u32,
 
+    /// Address to receive the validation rewards.
+    /// None to use the wallet key's short address.
+    pub reward_owner: Option<short::Id>,
+
     /// Set "true" to poll transaction status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -79,6 +83,7 @@ where
             start_time,
             end_time,
             reward_fee_percent: 2,
+            reward_owner: None,
             check_acceptance: false,
             poll_initial_wait: Duration::from_secs(62), // enough to elapse validate start time
             poll_interval: Duration::from_secs(1),
@@ -122,6 +127,13 @@ where
         self
     }
 
+    /// Sets the reward owner address (e.g., "short_address" of another key).
+    #[must_use]
+    pub fn reward_owner(mut self, reward_owner: short::Id) -> Self {
+        self.reward_owner = Some(reward_owner);
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -171,6 +183,12 @@ where
             picked_http_rpc.1
         );
 
+        platformvm::validate_staking_duration(
+            self.inner.inner.network_id,
+            self.start_time.timestamp() as u64,
+            self.end_time.timestamp() as u64,
+        )?;
+
         let already_validator = self
             .inner
             .is_primary_network_validator(&self.node_id)
@@ -183,11 +201,15 @@ where
             return Ok((ids::Id::empty(), false));
         }
 
+        let fee = self
+            .inner
+            .estimate_fee(wallet::fee::TxKind::AddPrimaryNetworkValidator)
+            .await?;
         let cur_balance_p = self.inner.balance().await?;
-        if cur_balance_p < self.stake_amount + self.inner.inner.add_primary_network_validator_fee {
+        if cur_balance_p < self.stake_amount + fee {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.stake_amount + self.inner.inner.add_primary_network_validator_fee),
+                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.stake_amount + fee),
              ));
         };
         log::info!(
@@ -196,13 +218,8 @@ where
             cur_balance_p
         );
 
-        let (ins, unstaked_outs, staked_outs, signers) = self
-            .inner
-            .spend(
-                self.stake_amount,
-                self.inner.inner.add_primary_network_validator_fee,
-            )
-            .await?;
+        let (ins, unstaked_outs, staked_outs, signers) =
+            self.inner.spend(self.stake_amount, fee).await?;
 
         let mut tx = platformvm::txs::add_validator::Tx {
             base_tx: txs::Tx {
@@ -222,7 +239,10 @@ where
             rewards_owner: key::secp256k1::txs::OutputOwners {
                 locktime: 0,
                 threshold: 1,
-                addresses: vec![self.inner.inner.short_address.clone()],
+                addresses: vec![self
+                    .reward_owner
+                    .clone()
+                    .unwrap_or_else(|| self.inner.inner.short_address.clone())],
             },
             shares: self.reward_fee_percent * 10000,
             ..Default::default()
@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs};
+use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs, wallet};
 use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "CreateChain" transaction.
@@ -125,10 +125,11 @@ where
             picked_http_rpc.1
         );
 
-        let (ins, unstaked_outs, _, signers) = self
+        let fee = self
             .inner
-            .spend(0, self.inner.inner.create_blockchain_tx_fee)
+            .estimate_fee(wallet::fee::TxKind::CreateBlockchain)
             .await?;
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, fee).await?;
         let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
 
         let mut tx = platformvm::txs::create_chain::Tx {
@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs};
+use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs, wallet};
 use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "CreateSubnet" transaction.
@@ -82,10 +82,11 @@ where
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
         log::info!("creating a new subnet via {}", picked_http_rpc.1);
 
-        let (ins, unstaked_outs, _, signers) = self
+        let fee = self
             .inner
-            .spend(0, self.inner.inner.create_subnet_tx_fee)
+            .estimate_fee(wallet::fee::TxKind::CreateSubnet)
             .await?;
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, fee).await?;
 
         let mut tx = platformvm::txs::create_subnet::Tx {
             base_tx: txs::Tx {
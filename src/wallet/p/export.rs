@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs};
+use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs, wallet};
 use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "Export" transaction.
@@ -110,7 +110,10 @@ where
             picked_http_rpc.1
         );
 
-        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
+        // the exported amount is burned on P-chain along with the tx fee
+        // ref. "avalanchego/wallet/chain/p#builder.NewExportTx" "toBurn"
+        let tx_fee = self.inner.estimate_fee(wallet::fee::TxKind::Base).await?;
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.amount + tx_fee).await?;
 
         let mut tx = platformvm::txs::export::Tx {
             base_tx: txs::Tx {
@@ -3,7 +3,7 @@ use std::{
     time::SystemTime,
 };
 
-use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs};
+use crate::{formatting, ids, jsonrpc::client::p as client_p, key, platformvm, txs, wallet};
 use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "Import" transaction.
@@ -91,9 +91,10 @@ where
         self
     }
 
-    /// Issues the import transaction and returns the transaction Id.
+    /// Issues the import transaction that consumes all the atomic UTXOs
+    /// exported from the source blockchain, and returns the transaction Id.
+    /// The tx fee is deducted from the imported amount.
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewImportTx"
-    /// TODO: not working... cache exported Utxos
     pub async fn issue(&self) -> io::Result<ids::Id> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
         log::info!(
@@ -102,7 +103,12 @@ where
             picked_http_rpc.1
         );
 
-        let utxos = client_p::get_utxos(&picked_http_rpc.1, &self.inner.inner.p_address).await?;
+        let utxos = client_p::get_atomic_utxos(
+            &picked_http_rpc.1,
+            &self.inner.inner.p_address,
+            &self.source_blockchain_id.to_string(),
+        )
+        .await?;
         let utxos_result = utxos.result.unwrap();
         let utxos = utxos_result.utxos.unwrap();
         log::debug!(
@@ -156,13 +162,22 @@ where
             ));
         }
 
-        // TODO: check import amount with tx fee
+        let tx_fee = self.inner.estimate_fee(wallet::fee::TxKind::Base).await?;
         log::info!(
             "importing total {} AVAX with tx fee {}",
             import_amount,
-            self.inner.inner.tx_fee
+            tx_fee
         );
-        import_amount -= self.inner.inner.tx_fee;
+        if import_amount <= tx_fee {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "import amount {} does not cover the tx fee {}",
+                    import_amount, tx_fee
+                ),
+            ));
+        }
+        import_amount -= tx_fee;
 
         let outputs: Vec<txs::transferable::Output> = vec![
             // receiver
@@ -1,3 +1,5 @@
+pub mod add_delegator;
+pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
 pub mod create_chain;
@@ -81,6 +83,14 @@ where
         Ok(utxos)
     }
 
+    /// Returns the fee of the P-chain transaction type from the wallet's
+    /// fee schedule, fetched once via "info.getTxFee".
+    /// Use "Wallet::set_tx_fees" to override it.
+    pub async fn estimate_fee(&self, tx_kind: wallet::fee::TxKind) -> io::Result<u64> {
+        tx_kind.check_chain("P")?;
+        self.inner.estimate_fee(tx_kind).await
+    }
+
     /// Returns "true" if the node_id is a current primary network validator.
     pub async fn is_primary_network_validator(&self, node_id: &node::Id) -> io::Result<bool> {
         let resp =
@@ -412,6 +422,20 @@ where
         add_validator::Tx::new(self)
     }
 
+    /// Delegates stake to a primary network validator.
+    #[must_use]
+    pub fn add_delegator(&self) -> add_delegator::Tx<T> {
+        add_delegator::Tx::new(self)
+    }
+
+    /// Adds a primary network validator with its BLS key (post-Banff).
+    /// e.g., "add_permissionless_validator().node_id(..).stake_amount(..).start_time(..)
+    /// .end_time(..).delegation_fee_percent(..).reward_owner(..).proof_of_possession(..).issue()".
+    #[must_use]
+    pub fn add_permissionless_validator(&self) -> add_permissionless_validator::Tx<T> {
+        add_permissionless_validator::Tx::new(self)
+    }
+
     /// Once subnet is created, the avalanche node must whitelist the subnet Id
     /// (the returned/confirmed transaction Id).
     #[must_use]
@@ -442,4 +466,86 @@ where
     pub fn import(&self) -> import::Tx<T> {
         import::Tx::new(self)
     }
+
+    /// Imports all the atomic UTXOs exported from X-chain,
+    /// and waits for its acceptance.
+    pub async fn import_from_x(&self) -> io::Result<ids::Id> {
+        self.import()
+            .source_blockchain_id(self.inner.blockchain_id_x.clone())
+            .check_acceptance(true)
+            .issue()
+            .await
+    }
+
+    /// Moves "amount" AVAX from P-chain to X-chain: issues the export
+    /// transaction, waits for its acceptance, and imports on X-chain.
+    /// Exports the X-chain import tx fee on top of the amount, so that
+    /// exactly "amount" is credited on X-chain.
+    /// Returns the export and import transaction Ids.
+    pub async fn export_to_x(&self, amount: u64) -> io::Result<(ids::Id, ids::Id)> {
+        let import_tx_fee = self
+            .inner
+            .x()
+            .estimate_fee(wallet::fee::TxKind::Base)
+            .await?;
+        let export_amount = amount.checked_add(import_tx_fee).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "amount {} overflows with the import tx fee {}",
+                    amount, import_tx_fee
+                ),
+            )
+        })?;
+        let export_tx_id = self
+            .export()
+            .destination_blockchain_id(self.inner.blockchain_id_x.clone())
+            .amount(export_amount)
+            .check_acceptance(true)
+            .issue()
+            .await?;
+
+        let import_tx_id = self.inner.x().import_from_p().await?;
+        Ok((export_tx_id, import_tx_id))
+    }
+
+    /// Imports all the atomic UTXOs exported from C-chain,
+    /// and waits for its acceptance.
+    #[cfg(feature = "wallet_evm")]
+    pub async fn import_from_c(&self) -> io::Result<ids::Id> {
+        self.import()
+            .source_blockchain_id(self.inner.blockchain_id_c)
+            .check_acceptance(true)
+            .issue()
+            .await
+    }
+
+    /// Moves "amount" AVAX from P-chain to C-chain: issues the export
+    /// transaction, waits for its acceptance, and imports on C-chain.
+    /// Exports the C-chain import fee at the current base fee on top of the amount,
+    /// so that "amount" is credited on C-chain unless the base fee rises in between.
+    /// Returns the export and import transaction Ids.
+    #[cfg(feature = "wallet_evm")]
+    pub async fn export_to_c(&self, amount: u64) -> io::Result<(ids::Id, ids::Id)> {
+        let import_tx_fee = self.inner.c().estimate_import_fee(1).await?;
+        let export_amount = amount.checked_add(import_tx_fee).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "amount {} overflows with the import tx fee {}",
+                    amount, import_tx_fee
+                ),
+            )
+        })?;
+        let export_tx_id = self
+            .export()
+            .destination_blockchain_id(self.inner.blockchain_id_c)
+            .amount(export_amount)
+            .check_acceptance(true)
+            .issue()
+            .await?;
+
+        let import_tx_id = self.inner.c().import_from_p().await?;
+        Ok((export_tx_id, import_tx_id))
+    }
 }
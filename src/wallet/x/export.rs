@@ -5,7 +5,7 @@ use std::{
 };
 
 use crate::{
-    avm, choices::status::Status, formatting, ids, jsonrpc::client::x as client_x, key, txs,
+    avm, choices::status::Status, formatting, ids, jsonrpc::client::x as client_x, key, txs, wallet,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -115,8 +115,12 @@ where
             picked_http_rpc.1
         );
 
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
+        let utxos = client_x::get_utxos(
+            &picked_http_rpc.1,
+            &[self.inner.inner.x_address.clone()],
+            client_x::MAX_UTXOS_LIMIT,
+        )
+        .await?;
         let utxos_result = utxos.result.unwrap();
         let utxos = utxos_result.utxos.unwrap();
         log::debug!(
@@ -147,7 +151,8 @@ where
 
         // ref. "avalanchego/wallet/chain/x"
         // "math.Add64(toBurn[assetID], out.Out.Amount())"
-        let mut remaining_amount_to_burn = self.amount + self.inner.inner.tx_fee;
+        let tx_fee = self.inner.estimate_fee(wallet::fee::TxKind::Base).await?;
+        let mut remaining_amount_to_burn = self.amount + tx_fee;
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
         let now_unix = SystemTime::now()
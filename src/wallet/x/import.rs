@@ -4,7 +4,7 @@ use std::{
 };
 
 use crate::{
-    avm, choices::status::Status, formatting, ids, jsonrpc::client::x as client_x, key, txs,
+    avm, choices::status::Status, formatting, ids, jsonrpc::client::x as client_x, key, txs, wallet,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -92,7 +92,9 @@ where
         self
     }
 
-    /// Issues the import transaction and returns the transaction Id.
+    /// Issues the import transaction that consumes all the atomic UTXOs
+    /// exported from the source blockchain, and returns the transaction Id.
+    /// The tx fee is deducted from the imported amount.
     pub async fn issue(&self) -> io::Result<ids::Id> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
         log::info!(
@@ -101,8 +103,13 @@ where
             picked_http_rpc.1
         );
 
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
+        let utxos = client_x::get_atomic_utxos(
+            &picked_http_rpc.1,
+            &[self.inner.inner.x_address.clone()],
+            client_x::MAX_UTXOS_LIMIT,
+            &self.source_blockchain_id.to_string(),
+        )
+        .await?;
         let utxos_result = utxos.result.unwrap();
         let utxos = utxos_result.utxos.unwrap();
         log::debug!(
@@ -156,13 +163,22 @@ where
             ));
         }
 
-        // TODO: check import amount with tx fee
+        let tx_fee = self.inner.estimate_fee(wallet::fee::TxKind::Base).await?;
         log::info!(
             "importing total {} AVAX with tx fee {}",
             import_amount,
-            self.inner.inner.tx_fee
+            tx_fee
         );
-        import_amount -= self.inner.inner.tx_fee;
+        if import_amount <= tx_fee {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "import amount {} does not cover the tx fee {}",
+                    import_amount, tx_fee
+                ),
+            ));
+        }
+        import_amount -= tx_fee;
 
         let outputs: Vec<txs::transferable::Output> = vec![
             // receiver
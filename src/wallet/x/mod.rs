@@ -2,9 +2,9 @@ pub mod export;
 pub mod import;
 pub mod transfer;
 
-use std::io;
+use std::io::{self, Error, ErrorKind};
 
-use crate::{jsonrpc::client::x as client_x, key, txs, wallet};
+use crate::{ids, jsonrpc::client::x as client_x, key, txs, wallet};
 
 impl<T> wallet::Wallet<T>
 where
@@ -57,6 +57,14 @@ where
             .await
     }
 
+    /// Returns the fee of the X-chain transaction type from the wallet's
+    /// fee schedule, fetched once via "info.getTxFee".
+    /// Use "Wallet::set_tx_fees" to override it.
+    pub async fn estimate_fee(&self, tx_kind: wallet::fee::TxKind) -> io::Result<u64> {
+        tx_kind.check_chain("X")?;
+        self.inner.estimate_fee(tx_kind).await
+    }
+
     /// Fetches UTXOs for "X" chain.
     /// TODO: cache this like avalanchego
     pub async fn utxos(&self) -> io::Result<Vec<txs::utxo::Utxo>> {
@@ -65,8 +73,12 @@ where
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/spend.go#L39 "stake"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
-        let resp =
-            client_x::get_utxos(&self.inner.pick_base_http_url().1, &self.inner.p_address).await?;
+        let resp = client_x::get_utxos(
+            &self.inner.pick_base_http_url().1,
+            &[self.inner.p_address.clone()],
+            client_x::MAX_UTXOS_LIMIT,
+        )
+        .await?;
         let utxos = resp
             .result
             .expect("unexpected None GetUtxosResult")
@@ -89,4 +101,46 @@ where
     pub fn import(&self) -> import::Tx<T> {
         import::Tx::new(self)
     }
+
+    /// Moves "amount" AVAX from X-chain to P-chain: issues the export
+    /// transaction, waits for its acceptance, and imports on P-chain.
+    /// Exports the P-chain import tx fee on top of the amount, so that
+    /// exactly "amount" is credited on P-chain.
+    /// Returns the export and import transaction Ids.
+    pub async fn export_to_p(&self, amount: u64) -> io::Result<(ids::Id, ids::Id)> {
+        let import_tx_fee = self
+            .inner
+            .p()
+            .estimate_fee(wallet::fee::TxKind::Base)
+            .await?;
+        let export_amount = amount.checked_add(import_tx_fee).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "amount {} overflows with the import tx fee {}",
+                    amount, import_tx_fee
+                ),
+            )
+        })?;
+        let export_tx_id = self
+            .export()
+            .destination_blockchain_id(self.inner.blockchain_id_p.clone())
+            .amount(export_amount)
+            .check_acceptance(true)
+            .issue()
+            .await?;
+
+        let import_tx_id = self.inner.p().import_from_x().await?;
+        Ok((export_tx_id, import_tx_id))
+    }
+
+    /// Imports all the atomic UTXOs exported from P-chain,
+    /// and waits for its acceptance.
+    pub async fn import_from_p(&self) -> io::Result<ids::Id> {
+        self.import()
+            .source_blockchain_id(self.inner.blockchain_id_p.clone())
+            .check_acceptance(true)
+            .issue()
+            .await
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
     formatting,
     ids::{self, short},
     jsonrpc::client::x as client_x,
-    key, txs,
+    key, txs, wallet,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -123,8 +123,12 @@ where
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/spend.go#L39 "stake"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
+        let utxos = client_x::get_utxos(
+            &picked_http_rpc.1,
+            &[self.inner.inner.x_address.clone()],
+            client_x::MAX_UTXOS_LIMIT,
+        )
+        .await?;
         let utxos_result = utxos.result.unwrap();
         let utxos = utxos_result.utxos.unwrap();
         log::debug!(
@@ -153,7 +157,8 @@ where
 
         // ref. "avalanchego/wallet/chain/x"
         // "math.Add64(toBurn[assetID], out.Out.Amount())"
-        let mut remaining_amount_to_burn = self.amount + self.inner.inner.tx_fee;
+        let tx_fee = self.inner.estimate_fee(wallet::fee::TxKind::Base).await?;
+        let mut remaining_amount_to_burn = self.amount + tx_fee;
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
         let now_unix = SystemTime::now()
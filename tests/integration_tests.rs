@@ -1,2 +1,8 @@
 #[cfg(any(test, feature = "subnet"))]
 mod rpc;
+
+#[cfg(feature = "jsonrpc_client")]
+mod jsonrpc_client_evm;
+
+#[cfg(all(feature = "jsonrpc_client", feature = "wallet"))]
+mod wallet_cross_chain;
@@ -0,0 +1,120 @@
+//! Runs against a local node, skipped unless "EVM_RPC_ENDPOINT" is set.
+//!
+//! EVM_RPC_ENDPOINT=http://127.0.0.1:9650/ext/bc/C/rpc cargo test --features="jsonrpc_client" --test integration_tests -- jsonrpc_client_evm --show-output
+//!
+//! "test_call" also requires "COUNTER_CONTRACT_ADDRESS" of the deployed counter contract
+//! with "getNumber()" (as used in the "evm_contract_counter_*" examples).
+
+use std::{env, str::FromStr};
+
+use avalanche_types::jsonrpc::client::evm;
+use ethers_core::types::{BlockId, BlockNumber};
+use primitive_types::H160;
+
+/// Pre-funded "ewoq" key address on the local network.
+const EWOQ_ADDRESS: &str = "0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC";
+
+fn rpc_endpoint() -> Option<String> {
+    match env::var("EVM_RPC_ENDPOINT") {
+        Ok(ep) if !ep.is_empty() => Some(ep),
+        _ => {
+            log::warn!("EVM_RPC_ENDPOINT not set, skipping");
+            None
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_get_balance() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let Some(rpc_ep) = rpc_endpoint() else {
+        return;
+    };
+    let addr = H160::from_str(EWOQ_ADDRESS.trim_start_matches("0x")).unwrap();
+
+    let latest = evm::get_balance(&rpc_ep, addr, None).await.unwrap();
+    log::info!("latest balance {latest}");
+
+    let pending = evm::get_balance(&rpc_ep, addr, Some(BlockNumber::Pending.into()))
+        .await
+        .unwrap();
+    log::info!("pending balance {pending}");
+
+    let genesis = evm::get_balance(
+        &rpc_ep,
+        addr,
+        Some(BlockId::Number(BlockNumber::Number(0.into()))),
+    )
+    .await
+    .unwrap();
+    log::info!("genesis balance {genesis}");
+    assert!(!genesis.is_zero());
+}
+
+#[tokio::test]
+async fn test_get_transaction_count() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let Some(rpc_ep) = rpc_endpoint() else {
+        return;
+    };
+    let addr = H160::from_str(EWOQ_ADDRESS.trim_start_matches("0x")).unwrap();
+
+    let latest = evm::get_transaction_count(&rpc_ep, addr, false)
+        .await
+        .unwrap();
+    let pending = evm::get_transaction_count(&rpc_ep, addr, true)
+        .await
+        .unwrap();
+    log::info!("latest nonce {latest}, pending nonce {pending}");
+    assert!(pending >= latest);
+
+    // never-used address
+    let nonce = evm::get_transaction_count(&rpc_ep, H160::random(), true)
+        .await
+        .unwrap();
+    assert!(nonce.is_zero());
+}
+
+#[tokio::test]
+async fn test_call() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let Some(rpc_ep) = rpc_endpoint() else {
+        return;
+    };
+    let counter_addr = match env::var("COUNTER_CONTRACT_ADDRESS") {
+        Ok(addr) if !addr.is_empty() => H160::from_str(addr.trim_start_matches("0x")).unwrap(),
+        _ => {
+            log::warn!("COUNTER_CONTRACT_ADDRESS not set, skipping");
+            return;
+        }
+    };
+
+    // keccak256("getNumber()")[..4]
+    let output = evm::call(&rpc_ep, counter_addr, vec![0xf2, 0xc9, 0xec, 0xd8], None)
+        .await
+        .unwrap();
+    assert_eq!(output.len(), 32);
+    log::info!(
+        "getNumber: {}",
+        primitive_types::U256::from_big_endian(&output)
+    );
+
+    // unknown selector without fallback reverts
+    assert!(
+        evm::call(&rpc_ep, counter_addr, vec![0xde, 0xad, 0xbe, 0xef], None)
+            .await
+            .is_err()
+    );
+}